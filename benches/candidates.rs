@@ -0,0 +1,31 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use uuid::Uuid;
+
+use wordle::game::GameService;
+use wordle::game::models::Game;
+
+/// Compare indexed candidate filtering against a full word list scan
+fn bench_remaining_candidates(c: &mut Criterion) {
+    let service = GameService::default();
+
+    // Build a game with a couple of guesses to filter against
+    let mut game = Game::new("tight".to_string(), 6, Uuid::new_v4());
+    for word in ["about", "sheet"] {
+        service
+            .make_guess(&mut game, word)
+            .expect("benchmark guesses should be valid");
+    }
+
+    let mut group = c.benchmark_group("remaining_candidates");
+    group.bench_function("indexed", |b| {
+        b.iter(|| service.remaining_candidates(black_box(&game.guesses)))
+    });
+    group.bench_function("scan", |b| {
+        b.iter(|| service.remaining_candidates_scan(black_box(&game.guesses)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_remaining_candidates);
+criterion_main!(benches);
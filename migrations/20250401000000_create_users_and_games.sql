@@ -0,0 +1,23 @@
+-- Users table
+CREATE TABLE IF NOT EXISTS users (
+    id UUID PRIMARY KEY,
+    username TEXT NOT NULL,
+    current_game_id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Games table
+CREATE TABLE IF NOT EXISTS games (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    word TEXT NOT NULL,
+    max_attempts SMALLINT NOT NULL,
+    guesses JSONB NOT NULL DEFAULT '[]'::jsonb,
+    completed BOOLEAN NOT NULL DEFAULT FALSE,
+    won BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_games_user_id ON games (user_id);
@@ -0,0 +1 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS hard_mode BOOLEAN NOT NULL DEFAULT FALSE;
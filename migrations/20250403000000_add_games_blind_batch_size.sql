@@ -0,0 +1 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS blind_batch_size SMALLINT;
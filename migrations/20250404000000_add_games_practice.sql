@@ -0,0 +1 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS practice BOOLEAN NOT NULL DEFAULT FALSE;
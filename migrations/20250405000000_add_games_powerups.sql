@@ -0,0 +1,2 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS powerups_used SMALLINT NOT NULL DEFAULT 0;
+ALTER TABLE games ADD COLUMN IF NOT EXISTS revealed_positions JSONB NOT NULL DEFAULT '[]'::jsonb;
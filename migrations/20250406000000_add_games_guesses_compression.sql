@@ -0,0 +1,2 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS guesses_gz BYTEA;
+ALTER TABLE games ADD COLUMN IF NOT EXISTS guesses_compressed BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+ALTER TABLE users ADD COLUMN IF NOT EXISTS current_game_ids JSONB NOT NULL DEFAULT '{}'::jsonb;
+ALTER TABLE users DROP COLUMN IF EXISTS current_game_id;
@@ -0,0 +1,2 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS guess_count SMALLINT NOT NULL DEFAULT 0;
+UPDATE games SET guess_count = jsonb_array_length(guesses) WHERE NOT guesses_compressed;
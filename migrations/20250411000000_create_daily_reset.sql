@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS daily_reset (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    last_date DATE NOT NULL
+);
@@ -0,0 +1,3 @@
+CREATE UNIQUE INDEX IF NOT EXISTS idx_games_daily_per_user
+    ON games (user_id, word)
+    WHERE NOT practice AND seed IS NULL;
@@ -0,0 +1,7 @@
+ALTER TABLE games ADD COLUMN IF NOT EXISTS replay_count INTEGER NOT NULL DEFAULT 0;
+
+-- Each replay of a daily game is unique per user, word and replay number
+DROP INDEX IF EXISTS idx_games_daily_per_user;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_games_daily_per_user
+    ON games (user_id, word, replay_count)
+    WHERE NOT practice AND seed IS NULL;
@@ -0,0 +1,2 @@
+-- Usernames are validated to at most 64 characters; truncate any stored before
+ALTER TABLE users ALTER COLUMN username TYPE VARCHAR(64) USING left(username, 64);
@@ -0,0 +1,41 @@
+-- Users table
+CREATE TABLE IF NOT EXISTS users (
+    id BLOB PRIMARY KEY,
+    username TEXT NOT NULL,
+    current_game_ids TEXT NOT NULL DEFAULT '{}',
+    password_hash TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Games table
+CREATE TABLE IF NOT EXISTS games (
+    id BLOB PRIMARY KEY,
+    user_id BLOB NOT NULL,
+    word TEXT NOT NULL,
+    max_attempts INTEGER NOT NULL,
+    guesses TEXT NOT NULL DEFAULT '[]',
+    completed INTEGER NOT NULL DEFAULT 0,
+    won INTEGER NOT NULL DEFAULT 0,
+    hard_mode INTEGER NOT NULL DEFAULT 0,
+    blind_batch_size INTEGER,
+    practice INTEGER NOT NULL DEFAULT 0,
+    seed INTEGER,
+    scoring TEXT NOT NULL DEFAULT 'wordle',
+    powerups_used INTEGER NOT NULL DEFAULT 0,
+    revealed_positions TEXT NOT NULL DEFAULT '[]',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_games_user_id ON games (user_id);
+CREATE INDEX IF NOT EXISTS idx_games_created_at ON games (created_at);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_games_daily_per_user
+    ON games (user_id, word)
+    WHERE practice = 0 AND seed IS NULL;
+
+-- Date of the last daily reset, in a single row
+CREATE TABLE IF NOT EXISTS daily_reset (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    last_date TEXT NOT NULL
+);
@@ -0,0 +1 @@
+ALTER TABLE games ADD COLUMN surrendered INTEGER NOT NULL DEFAULT 0;
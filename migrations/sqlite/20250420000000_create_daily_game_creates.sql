@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS daily_game_creates (
+    user_id BLOB PRIMARY KEY,
+    count INTEGER NOT NULL DEFAULT 0
+);
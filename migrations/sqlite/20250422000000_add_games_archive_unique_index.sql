@@ -0,0 +1,4 @@
+-- Each user has at most one archive game per date
+CREATE UNIQUE INDEX IF NOT EXISTS idx_games_archive_per_user
+    ON games (user_id, archive_date)
+    WHERE archive_date IS NOT NULL;
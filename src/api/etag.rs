@@ -0,0 +1,70 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderValue, StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+};
+
+use crate::game::models::Game;
+
+/// Entity tags listed in an `If-None-Match` request header (RFC 9110)
+#[derive(Debug, Clone, Default)]
+pub struct IfNoneMatch(Vec<String>);
+
+impl IfNoneMatch {
+    /// Check whether a tag matches, using the weak comparison GET requires
+    fn matches(&self, etag: &str) -> bool {
+        let etag = etag.trim_start_matches("W/");
+        self.0
+            .iter()
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+    }
+
+    /// Build the response for a read, answering `304 Not Modified` if the
+    /// client's copy is current
+    pub fn respond(&self, etag: &str, representation: impl IntoResponse) -> Response {
+        let Ok(value) = HeaderValue::from_str(etag) else {
+            return representation.into_response();
+        };
+
+        let mut response = if self.matches(etag) {
+            StatusCode::NOT_MODIFIED.into_response()
+        } else {
+            representation.into_response()
+        };
+        response.headers_mut().insert(header::ETAG, value);
+        response
+    }
+}
+
+impl<S> FromRequestParts<S> for IfNoneMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Unreadable values never match, so the full response is sent
+        Ok(Self(
+            parts
+                .headers
+                .get_all(header::IF_NONE_MATCH)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+        ))
+    }
+}
+
+/// Weak entity tag of a game's state, changing whenever it is updated
+pub fn game_etag(game: &Game) -> String {
+    format!(
+        "W/\"{}-{}\"",
+        game.updated_at.timestamp_micros(),
+        game.guesses.len()
+    )
+}
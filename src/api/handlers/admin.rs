@@ -0,0 +1,318 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use axum_macros::debug_handler;
+use uuid::Uuid;
+
+use crate::api::AppState;
+use crate::api::models::{
+    AdminDeleteUserResponse, AdminGamesResponse, AdminResetResponse, DictionaryResponse, PageQuery,
+    ScheduleQuery, SignedSchedule,
+};
+use crate::auth::RequireRole;
+use crate::game::error::GameError;
+
+/// Default number of games per page
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Maximum number of games per page
+const MAX_PAGE_SIZE: usize = 200;
+
+/// List a page of all games ordered by creation time
+#[debug_handler]
+pub async fn list_games(
+    State(state): State<AppState>,
+    RequireRole(_): RequireRole,
+    Query(query): Query<PageQuery>,
+) -> Result<Json<AdminGamesResponse>, GameError> {
+    let offset = match query.offset {
+        Some(offset) => usize::try_from(offset)
+            .map_err(|_| GameError::BadRequest("offset must not be negative".into()))?,
+        None => 0,
+    };
+    let limit = match query.limit {
+        Some(limit) => usize::try_from(limit)
+            .map_err(|_| GameError::BadRequest("limit must not be negative".into()))?
+            .min(MAX_PAGE_SIZE),
+        None => DEFAULT_PAGE_SIZE,
+    };
+
+    let (games, total) = state.game.list_games_paginated(limit, offset).await?;
+
+    Ok(Json(AdminGamesResponse {
+        games,
+        total,
+        limit,
+        offset,
+    }))
+}
+
+/// Publish the signed schedule of daily words over a range of past dates
+#[debug_handler]
+pub async fn word_schedule(
+    State(state): State<AppState>,
+    RequireRole(role): RequireRole,
+    Query(query): Query<ScheduleQuery>,
+) -> Result<Json<SignedSchedule>, GameError> {
+    let signer = state
+        .game
+        .result_signer()
+        .ok_or_else(|| GameError::BadRequest("Result signing is not enabled".into()))?;
+
+    let schedule = state
+        .game
+        .game_service()
+        .word_schedule(query.from, query.to)?;
+    let signature = signer.sign_schedule(&schedule);
+
+    tracing::info!(
+        "Admin ({}) published the word schedule from {} to {}",
+        role,
+        query.from,
+        query.to
+    );
+
+    Ok(Json(SignedSchedule {
+        schedule,
+        signature,
+    }))
+}
+
+/// Clear all games and every user's current game
+#[debug_handler]
+pub async fn reset(
+    State(state): State<AppState>,
+    RequireRole(role): RequireRole,
+) -> Result<Json<AdminResetResponse>, GameError> {
+    let games_cleared = state.game.clear_all_games().await?;
+    let users_reset = state
+        .auth
+        .user_repository()
+        .reset_all_users_current_game()
+        .await?;
+    state.auth.user_repository().reset_daily_creates().await?;
+
+    tracing::info!(
+        "Admin reset ({}) cleared {} games and {} users' current games",
+        role,
+        games_cleared,
+        users_reset
+    );
+
+    Ok(Json(AdminResetResponse {
+        games_cleared,
+        users_reset,
+    }))
+}
+
+/// Delete a user and all of their games
+#[debug_handler]
+pub async fn delete_user(
+    State(state): State<AppState>,
+    RequireRole(role): RequireRole,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<AdminDeleteUserResponse>, GameError> {
+    // Delete games first so a failure never leaves games without their user
+    let games_deleted = state.game.delete_games_for_user(&user_id).await?;
+    let user_deleted = state.auth.delete_user(&user_id).await?;
+
+    tracing::warn!(
+        "Admin ({}) deleted user {} (existed: {}) and {} games",
+        role,
+        user_id,
+        user_deleted,
+        games_deleted
+    );
+
+    Ok(Json(AdminDeleteUserResponse {
+        user_deleted,
+        games_deleted,
+    }))
+}
+
+/// Replace the dictionary with the words in the request body
+///
+/// The body is either a JSON array of words or whitespace-separated text.
+#[debug_handler]
+pub async fn replace_dictionary(
+    State(state): State<AppState>,
+    RequireRole(role): RequireRole,
+    body: String,
+) -> Result<Json<DictionaryResponse>, GameError> {
+    let words: Vec<String> = match serde_json::from_str(&body) {
+        Ok(words) => words,
+        Err(_) => body.split_whitespace().map(String::from).collect(),
+    };
+
+    let game_service = state.game.replace_dictionary(&words)?;
+
+    tracing::info!(
+        "Admin ({}) replaced dictionary with {} words (version {})",
+        role,
+        game_service.word_count(),
+        game_service.version()
+    );
+
+    Ok(Json(DictionaryResponse {
+        words: game_service.word_count(),
+        version: game_service.version(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::game::MIN_DICTIONARY_WORDS;
+    use crate::repository::error::RepositoryError;
+    use crate::test_support::{self, send};
+
+    fn admin_token() -> String {
+        let mut claims = test_support::claims(Uuid::new_v4());
+        claims.roles = Some(vec!["admin".to_string()]);
+        test_support::token(&claims)
+    }
+
+    #[tokio::test]
+    async fn dictionary_swap_changes_validation() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let token = admin_token();
+
+        let words: Vec<String> = ('a'..='z')
+            .take(MIN_DICTIONARY_WORDS)
+            .map(|c| format!("zzzz{}", c))
+            .collect();
+        let (status, body) = send(
+            &router,
+            Method::PUT,
+            "/api/v1/admin/dictionary",
+            Some(&token),
+            Some(json!(words)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["words"], MIN_DICTIONARY_WORDS);
+
+        let service = state.game.game_service();
+        assert!(service.is_valid_word("zzzza"));
+        assert!(!service.is_valid_word("plant"));
+    }
+
+    #[tokio::test]
+    async fn too_small_dictionary_is_rejected() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let version = state.game.game_service().version();
+
+        let (status, _) = send(
+            &router,
+            Method::PUT,
+            "/api/v1/admin/dictionary",
+            Some(&admin_token()),
+            Some(json!(["zzzza", "zzzzb", "zzzzc"])),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let service = state.game.game_service();
+        assert_eq!(service.version(), version);
+        assert!(service.is_valid_word("plant"));
+    }
+
+    #[tokio::test]
+    async fn games_are_listed_a_page_at_a_time() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let token = admin_token();
+
+        let mut ids = HashSet::new();
+        for _ in 0..3 {
+            let game = test_support::played_game(Uuid::new_v4(), "plant", &[]);
+            ids.insert(game.id.to_string());
+            state.game.save_game(game).await.unwrap();
+        }
+
+        let mut listed = HashSet::new();
+        for (offset, expected) in [(0, 2), (2, 1)] {
+            let uri = format!("/api/v1/admin/games?limit=2&offset={}", offset);
+            let (status, body) = send(&router, Method::GET, &uri, Some(&token), None).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["total"], 3);
+            assert_eq!(body["limit"], 2);
+            assert_eq!(body["offset"], offset);
+
+            let games = body["games"].as_array().unwrap();
+            assert_eq!(games.len(), expected);
+            listed.extend(
+                games
+                    .iter()
+                    .map(|game| game["id"].as_str().unwrap().to_string()),
+            );
+        }
+        assert_eq!(listed, ids);
+
+        let (status, _) = send(
+            &router,
+            Method::GET,
+            "/api/v1/admin/games?offset=-1",
+            Some(&token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn purging_a_user_leaves_other_users_untouched() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+
+        let (target, other) = (Uuid::new_v4(), Uuid::new_v4());
+        for user_id in [target, other] {
+            let token = test_support::token(&test_support::claims(user_id));
+            let (status, _) = send(
+                &router,
+                Method::POST,
+                "/api/v1/game/new",
+                Some(&token),
+                Some(json!({})),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let uri = format!("/api/v1/admin/users/{}", target);
+        let user_token = test_support::token(&test_support::claims(Uuid::new_v4()));
+        let (status, _) = send(&router, Method::DELETE, &uri, Some(&user_token), None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send(&router, Method::DELETE, &uri, Some(&admin_token()), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["user_deleted"], true);
+        assert_eq!(body["games_deleted"], 1);
+
+        assert!(matches!(
+            state.auth.get_user(&target).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(
+            state
+                .game
+                .list_games_for_user(&target)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(state.auth.get_user(&other).await.is_ok());
+        assert_eq!(
+            state.game.list_games_for_user(&other).await.unwrap().len(),
+            1
+        );
+    }
+}
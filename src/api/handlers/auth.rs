@@ -0,0 +1,90 @@
+#[cfg(feature = "local_auth")]
+use axum::http::StatusCode;
+use axum::{Json, extract::State};
+use axum_macros::debug_handler;
+use uuid::Uuid;
+
+use crate::api::AppState;
+#[cfg(feature = "local_auth")]
+use crate::api::models::CredentialsRequest;
+use crate::api::models::{LoginRequest, LoginResponse};
+use crate::auth::AuthError;
+use crate::auth::models::User;
+#[cfg(feature = "local_auth")]
+use crate::auth::password::MIN_PASSWORD_LENGTH;
+
+/// Issue a token signed by this server for a new user
+#[debug_handler]
+pub async fn login(
+    State(state): State<AppState>,
+    Json(request): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>, AuthError> {
+    let username = request.username.trim();
+    User::validate_username(username)?;
+
+    // Generate a new user ID and sign a token for it
+    let user_id = Uuid::new_v4();
+    let token = state.auth.jwt_auth().issue(user_id, username)?;
+    tracing::info!("Issued token for user: {} ({})", username, user_id);
+
+    Ok(Json(LoginResponse {
+        token,
+        user_id,
+        username: username.to_string(),
+    }))
+}
+
+/// Register a local account and issue a token for it
+#[cfg(feature = "local_auth")]
+#[debug_handler]
+pub async fn register(
+    State(state): State<AppState>,
+    Json(request): Json<CredentialsRequest>,
+) -> Result<(StatusCode, Json<LoginResponse>), AuthError> {
+    let username = request.username.trim();
+    User::validate_username(username)?;
+    if request.password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(AuthError::BadRequest(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        )));
+    }
+
+    let user = state.auth.register(username, &request.password).await?;
+    let token = state.auth.jwt_auth().issue(user.id, &user.username)?;
+    tracing::info!("Registered local account: {} ({})", user.username, user.id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(LoginResponse {
+            token,
+            user_id: user.id,
+            username: user.username,
+        }),
+    ))
+}
+
+/// Check a local account's password and issue a token for it
+#[cfg(feature = "local_auth")]
+#[debug_handler]
+pub async fn password_login(
+    State(state): State<AppState>,
+    Json(request): Json<CredentialsRequest>,
+) -> Result<Json<LoginResponse>, AuthError> {
+    let user = state
+        .auth
+        .authenticate(request.username.trim(), &request.password)
+        .await?;
+    let token = state.auth.jwt_auth().issue(user.id, &user.username)?;
+    tracing::info!(
+        "Issued token for local account: {} ({})",
+        user.username,
+        user.id
+    );
+
+    Ok(Json(LoginResponse {
+        token,
+        user_id: user.id,
+        username: user.username,
+    }))
+}
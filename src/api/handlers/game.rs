@@ -1,22 +1,96 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    response::Response,
 };
 use axum_macros::debug_handler;
+use chrono::NaiveDate;
 use uuid::Uuid;
 
 use crate::api::AppState;
-use crate::api::models::{CreateGameRequest, GameResponse, GuessRequest};
-use crate::auth::{Auth, AuthUserId};
+use crate::api::etag::{IfNoneMatch, game_etag};
+use crate::api::language::AcceptLanguage;
+use crate::api::models::{
+    BatchGuessRequest, BatchGuessResponse, CalendarQuery, CalendarResponse, CandidatesResponse,
+    CreateGameRequest, GameResponse, GuessRequest, HistoryEntryResponse, HistoryQuery,
+    HistoryResponse, LiveStatsResponse, PuzzleResultResponse, RejectedGuessResponse,
+    SeededGameRequest, ShareResponse, StatsResponse, TodayPuzzleResponse,
+};
+use crate::api::prefer::ReturnPreference;
+use crate::auth::models::User;
+use crate::auth::{Auth, AuthUserId, RequireRole};
 use crate::game::error::GameError;
 use crate::game::models::Game;
+use crate::game::stats::{GameStats, daily_summary, month_calendar};
+use crate::game::{puzzle_date, puzzle_number};
+use crate::repository::error::RepositoryError;
+use crate::server::metrics;
+
+/// Default number of games in the history
+const DEFAULT_HISTORY_SIZE: usize = 20;
+
+/// Maximum number of games in the history
+const MAX_HISTORY_SIZE: usize = 200;
+
+/// Times a guess is applied and saved before a conflicting update is reported
+const MAX_GUESS_SAVE_ATTEMPTS: u32 = 3;
+
+/// Build the response for a game, attaching its puzzle index, score and result signature
+pub(crate) fn game_response(state: &AppState, game: Game) -> Json<GameResponse> {
+    let result_signature = state.game.sign_result(&game);
+    let suspicious = state.game.is_suspicious(&game);
+    let score = game.score(&state.game.config().scoring);
+    let puzzle_index = game
+        .is_daily()
+        .then(|| state.game.game_service().puzzle_at(game.created_at).1);
+    let reveal_word = state.game.config().reveal_answer_on_complete;
+
+    Json(GameResponse {
+        puzzle_index,
+        suspicious,
+        score,
+        result_signature,
+        ..GameResponse::from_game(game, reveal_word)
+    })
+}
+
+/// Count a game the user is about to create against their daily limit
+async fn count_daily_create(state: &AppState, user_id: &Uuid) -> Result<(), GameError> {
+    let limit = state.game.config().max_daily_games_per_user;
+    if limit == 0 {
+        return Ok(());
+    }
+
+    // Counts are cleared by the daily reset, so make sure today's has happened
+    state.check_and_update_date().await?;
+    let created = state.auth.increment_and_get_daily_creates(user_id).await?;
+    if created > limit {
+        tracing::warn!("User {} reached the daily game limit", user_id);
+        return Err(GameError::DailyGameLimit(limit));
+    }
+
+    Ok(())
+}
 
 /// Create a new game
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/game/new",
+    tag = "game",
+    request_body = CreateGameRequest,
+    responses(
+        (status = 200, description = "The new game, or today's game if already started", body = GameResponse),
+        (status = 400, description = "Invalid game options"),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer" = [])),
+))]
 #[debug_handler]
 pub async fn create_game(
     State(state): State<AppState>,
     auth: Auth,
-    Json(_request): Json<CreateGameRequest>,
+    accept_language: AcceptLanguage,
+    Json(request): Json<CreateGameRequest>,
 ) -> Result<Json<GameResponse>, GameError> {
     tracing::info!(
         "Creating new game for user: {} ({})",
@@ -26,35 +100,60 @@ pub async fn create_game(
 
     // Check if a new day started
     tracing::info!("Checking and updating date");
-    state.game.check_and_update_date().await?;
+    state.check_and_update_date().await?;
+    state.game.mark_active(auth.user_id);
+
+    // Get the game service for today's word length
+    let game_service = state.game.game_service();
+    let word_length = game_service.word_length();
 
-    // Check if user already has an existing game for today
+    // Check if user already has an existing game of this length for today
     tracing::info!("Checking if user has an existing game");
-    let existing_game_id = state.auth.get_current_user_game_id(&auth.user_id).await?;
+    let existing_game_id = state
+        .auth
+        .get_current_user_game_id(&auth.user_id, word_length)
+        .await?;
 
     // Only process existing game if found
+    let mut replayed = None;
     if let Some(game_id) = existing_game_id {
         tracing::info!("Found existing game: {}", game_id);
         let game = state.game.get_game(&game_id).await?;
-        return Ok(Json(GameResponse::from(game)));
-    }
 
-    tracing::info!("No existing game found");
+        // Completed games are started again with the same word when replays are allowed
+        if !(game.completed && state.game.config().allow_daily_replay) {
+            return Ok(game_response(&state, game));
+        }
+        count_daily_create(&state, &auth.user_id).await?;
+        tracing::info!("Replaying completed game: {}", game_id);
+        replayed = Some(game);
+    } else {
+        tracing::info!("No existing game found");
+    }
 
-    // Get the game service and select today's word
-    let game_service = state.game.game_service();
-    let word = game_service.select_daily_word();
+    // Select today's word in the preferred language, or keep the replayed game's
+    let (word, language) = match &replayed {
+        Some(previous) => (previous.word.clone(), previous.language.clone()),
+        None => {
+            let language = accept_language.select(&game_service);
+            (
+                game_service.select_daily_word_in(language.as_deref()),
+                language,
+            )
+        }
+    };
     tracing::debug!("Selected daily word for new game");
 
     // Create game with the user's ID
-    let game = Game::new(word, 6, auth.user_id);
+    let mut game = Game::new(word, game_service.max_attempts(), auth.user_id);
+    game.language = language;
+    game.replay_count = replayed.map_or(0, |previous| previous.replay_count + 1);
+    game.hard_mode = request.hard_mode.unwrap_or(false);
+    game.blind_batch_size = request.blind_batch_size.filter(|&n| n > 1);
+    game.scoring = request.scoring.unwrap_or_default();
+    game.validate_options()?;
     tracing::info!("New game created: {}", game.id);
 
-    // Save the game in our state
-    tracing::info!("Saving game to repository");
-    state.game.save_game(game.clone()).await?;
-    tracing::info!("Game saved successfully");
-
     // Create a new user record if not exists
     let user_exists = state.auth.get_user(&auth.user_id).await.is_ok();
     if !user_exists {
@@ -64,7 +163,8 @@ pub async fn create_game(
             auth.claims.username,
             auth.user_id
         );
-        let user = crate::auth::models::User::new(auth.user_id, auth.claims.username.clone());
+        let user = User::try_new(auth.user_id, auth.claims.username.clone())
+            .map_err(|e| GameError::BadRequest(e.to_string()))?;
 
         // Save user
         tracing::info!("Saving new user");
@@ -73,24 +173,175 @@ pub async fn create_game(
         tracing::info!("User already exists");
     }
 
+    // Save the game unless a concurrent request already created today's game
+    tracing::info!("Saving game to repository");
+    let new_game_id = game.id;
+    let game = state.game.create_game_if_absent_for_user(game).await?;
+    if game.id != new_game_id {
+        tracing::info!("Concurrent request already created game: {}", game.id);
+        return Ok(game_response(&state, game));
+    }
+    tracing::info!("Game saved successfully");
+
     // Update the user's current game reference
     tracing::info!("Updating user's current game reference");
-    state.auth.update_user_game(&auth.user_id, game.id).await?;
+    state
+        .auth
+        .update_user_game(&auth.user_id, word_length, game.id)
+        .await?;
+    metrics::record_game_created();
 
     // Return the game response
     tracing::info!("Returning game response");
-    Ok(Json(GameResponse::from(game)))
+    Ok(game_response(&state, game))
+}
+
+/// Create a practice game with a random word
+///
+/// Practice games never replace the user's daily game and do not count toward stats.
+#[debug_handler]
+pub async fn create_practice_game(
+    State(state): State<AppState>,
+    auth: Auth,
+    Json(request): Json<CreateGameRequest>,
+) -> Result<Json<GameResponse>, GameError> {
+    let game_service = state.game.game_service();
+    let word = game_service.select_random_word();
+
+    let mut game = Game::new(word, game_service.max_attempts(), auth.user_id);
+    game.hard_mode = request.hard_mode.unwrap_or(false);
+    game.blind_batch_size = request.blind_batch_size.filter(|&n| n > 1);
+    game.scoring = request.scoring.unwrap_or_default();
+    game.validate_options()?;
+    game.practice = true;
+    count_daily_create(&state, &auth.user_id).await?;
+    tracing::info!(
+        "New practice game created: {} for user {}",
+        game.id,
+        auth.user_id
+    );
+
+    state.game.save_game(game.clone()).await?;
+    metrics::record_game_created();
+
+    Ok(game_response(&state, game))
+}
+
+/// Create a game whose word is chosen reproducibly from a seed
+///
+/// Seeded games are independent of the daily word and, like practice games,
+/// do not count toward stats.
+#[debug_handler]
+pub async fn create_seeded_game(
+    State(state): State<AppState>,
+    auth: Auth,
+    Json(request): Json<SeededGameRequest>,
+) -> Result<Json<GameResponse>, GameError> {
+    let game_service = state.game.game_service();
+    let word = game_service.word_from_seed(request.seed);
+
+    let mut game = Game::new(word, game_service.max_attempts(), auth.user_id);
+    game.hard_mode = request.hard_mode.unwrap_or(false);
+    game.blind_batch_size = request.blind_batch_size.filter(|&n| n > 1);
+    game.scoring = request.scoring.unwrap_or_default();
+    game.validate_options()?;
+    game.practice = true;
+    game.seed = Some(request.seed);
+    count_daily_create(&state, &auth.user_id).await?;
+    tracing::info!(
+        "New seeded game created: {} for user {} (seed {})",
+        game.id,
+        auth.user_id,
+        request.seed
+    );
+
+    state.game.save_game(game.clone()).await?;
+    metrics::record_game_created();
+
+    Ok(game_response(&state, game))
+}
+
+/// Get or create the user's archive game replaying a past day's word
+///
+/// Archive games are practice games: they leave the current daily game alone
+/// and do not count toward stats. Each date has one archive game per user,
+/// claimed atomically so concurrent requests share the same game.
+#[debug_handler]
+pub async fn get_archive_game(
+    State(state): State<AppState>,
+    auth: Auth,
+    Path(date): Path<NaiveDate>,
+) -> Result<Json<GameResponse>, GameError> {
+    let game_service = state.game.game_service();
+
+    // Only days that had a puzzle and are already over can be replayed
+    if date >= game_service.today() {
+        return Err(GameError::BadRequest(
+            "Archive date must be before today".into(),
+        ));
+    }
+    if puzzle_number(date) < 0 {
+        return Err(GameError::BadRequest(format!(
+            "There is no puzzle for {}",
+            date
+        )));
+    }
+
+    // Return the archive game for this date if the user already started one
+    let existing = state
+        .game
+        .list_games_for_user(&auth.user_id)
+        .await?
+        .into_iter()
+        .find(|game| game.archive_date == Some(date));
+    if let Some(game) = existing {
+        return Ok(game_response(&state, game));
+    }
+
+    count_daily_create(&state, &auth.user_id).await?;
+    let word = game_service.word_for_date(date);
+    let mut game = Game::new(word, game_service.max_attempts(), auth.user_id);
+    game.practice = true;
+    game.archive_date = Some(date);
+    tracing::info!(
+        "New archive game created: {} for user {} ({})",
+        game.id,
+        auth.user_id,
+        date
+    );
+
+    let new_game_id = game.id;
+    let game = state.game.create_game_if_absent_for_user(game).await?;
+    if game.id == new_game_id {
+        metrics::record_game_created();
+    }
+
+    Ok(game_response(&state, game))
 }
 
 /// Get user's current game state
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/api/v1/game/{id}",
+    tag = "game",
+    params(("id" = Uuid, Path, description = "Game ID")),
+    responses(
+        (status = 200, description = "The game", body = GameResponse),
+        (status = 304, description = "The game is unchanged since the `If-None-Match` ETag"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "No such game for this user"),
+    ),
+    security(("bearer" = [])),
+))]
 #[debug_handler]
 pub async fn get_game(
     State(state): State<AppState>,
     auth_user_id: AuthUserId,
     Path(game_id): Path<Uuid>,
-) -> Result<Json<GameResponse>, GameError> {
+    if_none_match: IfNoneMatch,
+) -> Result<Response, GameError> {
     // Check for day change
-    state.game.check_and_update_date().await?;
+    state.check_and_update_date().await?;
 
     // Get game
     let game = state.game.get_game(&game_id).await?;
@@ -100,17 +351,226 @@ pub async fn get_game(
         return Err(GameError::GameNotFound);
     }
 
-    // Return game response
-    Ok(Json(GameResponse::from(game)))
+    // Return game response, or 304 if the client already has this state
+    let etag = game_etag(&game);
+    Ok(if_none_match.respond(&etag, game_response(&state, game)))
+}
+
+/// Get the user's game for today, if one was started
+#[debug_handler]
+pub async fn get_current_game(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    if_none_match: IfNoneMatch,
+) -> Result<Response, GameError> {
+    // Check for day change
+    state.check_and_update_date().await?;
+
+    // Look up today's game for the current word length
+    let word_length = state.game.game_service().word_length();
+    let game_id = state
+        .auth
+        .get_current_user_game_id(&auth_user_id.0, word_length)
+        .await?
+        .ok_or(GameError::NoCurrentGame)?;
+
+    let game = state.game.get_game(&game_id).await?;
+
+    let etag = game_etag(&game);
+    Ok(if_none_match.respond(&etag, game_response(&state, game)))
 }
 
 /// Make a guess for the current game
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/game/{id}/guess",
+    tag = "game",
+    params(("id" = Uuid, Path, description = "Game ID")),
+    request_body = GuessRequest,
+    responses(
+        (status = 200, description = "The game after the guess", body = GameResponse),
+        (status = 204, description = "Guess accepted with `Prefer: return=minimal`"),
+        (status = 400, description = "Guess rejected, with a `code` giving the reason"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "No such game for this user"),
+    ),
+    security(("bearer" = [])),
+))]
 #[debug_handler]
 pub async fn make_guess(
     State(state): State<AppState>,
     auth_user_id: AuthUserId,
     Path(game_id): Path<Uuid>,
+    preference: ReturnPreference,
     Json(request): Json<GuessRequest>,
+) -> Result<Response, GameError> {
+    let game = submit_guess(&state, auth_user_id.0, game_id, &request.word).await?;
+
+    // Return the updated game response unless a minimal response was preferred
+    Ok(preference.respond(game_response(&state, game)))
+}
+
+/// Make several queued guesses at once, e.g. after playing offline
+///
+/// Guesses are applied in order, stopping at the first rejected one or once
+/// the game is completed. Guesses applied before a rejection are kept, and
+/// the rejection is reported with its index alongside the game.
+#[debug_handler]
+pub async fn make_guesses(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
+    Json(request): Json<BatchGuessRequest>,
+) -> Result<Json<BatchGuessResponse>, GameError> {
+    if request.words.is_empty() {
+        return Err(GameError::BadRequest("words must not be empty".into()));
+    }
+
+    let batch = submit_guesses(&state, auth_user_id.0, game_id, &request.words).await?;
+    let rejected = batch.rejected.map(|(index, error)| {
+        let (_, body) = error.status_and_body();
+        RejectedGuessResponse { index, error: body }
+    });
+
+    Ok(Json(BatchGuessResponse {
+        applied: batch.applied,
+        rejected,
+        game: game_response(&state, batch.game).0,
+    }))
+}
+
+/// Apply a guess to a user's game, save it and record its side effects
+pub(crate) async fn submit_guess(
+    state: &AppState,
+    user_id: Uuid,
+    game_id: Uuid,
+    word: &str,
+) -> Result<Game, GameError> {
+    let batch = submit_guesses(state, user_id, game_id, &[word]).await?;
+
+    match batch.rejected {
+        Some((_, error)) => Err(error),
+        None => Ok(batch.game),
+    }
+}
+
+/// Outcome of applying a batch of guesses to a game
+struct GuessBatch {
+    /// The game after the applied guesses
+    game: Game,
+
+    /// Number of guesses applied
+    applied: usize,
+
+    /// Index and reason of the guess that stopped the batch, if one was rejected
+    rejected: Option<(usize, GameError)>,
+}
+
+/// Apply guesses in order to a user's game, save it and record their side effects
+///
+/// Stops at the first rejected guess or once the game is completed. A save
+/// that races another update of the game is retried against the fresh copy,
+/// so a double submission can't silently drop a guess.
+async fn submit_guesses(
+    state: &AppState,
+    user_id: Uuid,
+    game_id: Uuid,
+    words: &[impl AsRef<str>],
+) -> Result<GuessBatch, GameError> {
+    let mut attempt = 1;
+    let batch = loop {
+        // Get game
+        let mut game = state.game.get_game(&game_id).await?;
+
+        // Verify game ownership
+        if game.user_id != user_id {
+            return Err(GameError::GameNotFound);
+        }
+
+        // Make the guesses
+        let game_service = state.game.game_service();
+        let mut applied = 0;
+        let mut rejected = None;
+        for (index, word) in words.iter().enumerate() {
+            if let Err(e) = game_service.make_guess(&mut game, word.as_ref()) {
+                rejected = Some((index, e));
+                break;
+            }
+            applied += 1;
+            if game.is_completed() {
+                break;
+            }
+        }
+
+        // Nothing to save if the first guess was rejected
+        let batch = GuessBatch {
+            game,
+            applied,
+            rejected,
+        };
+        if batch.applied == 0 {
+            break batch;
+        }
+
+        // Save the updated game unless it changed since it was read
+        match state.game.save_game_if_version(batch.game.clone()).await {
+            Ok(()) => break batch,
+            Err(RepositoryError::Conflict) if attempt < MAX_GUESS_SAVE_ATTEMPTS => {
+                tracing::debug!("Guess on game {} raced another update, retrying", game_id);
+                attempt += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    };
+
+    if batch.applied > 0 {
+        let game = &batch.game;
+        for _ in 0..batch.applied {
+            metrics::record_guess();
+        }
+        if !game.practice {
+            state.game.mark_active(game.user_id);
+        }
+        if game.is_completed() {
+            state.game.notify_completed(game).await;
+        }
+    }
+
+    Ok(batch)
+}
+
+/// Use a power-up to reveal one correct letter position
+#[debug_handler]
+pub async fn reveal_green(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GameResponse>, GameError> {
+    // Get game
+    let mut game = state.game.get_game(&game_id).await?;
+
+    // Verify game ownership
+    if game.user_id != auth_user_id.0 {
+        return Err(GameError::GameNotFound);
+    }
+
+    // Reveal a letter
+    let position = state.game.game_service().reveal_green(&mut game)?;
+    tracing::info!("Revealed position {} in game {}", position, game.id);
+
+    // Save the game unless a concurrent request changed it, so a power-up is
+    // never lost or charged twice
+    state.game.save_game_if_version(game.clone()).await?;
+
+    Ok(game_response(&state, game))
+}
+
+/// Give up a game, revealing its word
+#[debug_handler]
+pub async fn surrender_game(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
 ) -> Result<Json<GameResponse>, GameError> {
     // Get game
     let mut game = state.game.get_game(&game_id).await?;
@@ -120,15 +580,877 @@ pub async fn make_guess(
         return Err(GameError::GameNotFound);
     }
 
-    // Get the game service
+    game.surrender()?;
+    tracing::info!("User {} surrendered game {}", game.user_id, game.id);
+
+    // Save the game unless a guess completed it first
+    state.game.save_game_if_version(game.clone()).await?;
+    state.game.notify_completed(&game).await;
+
+    Ok(game_response(&state, game))
+}
+
+/// List the possible answers still consistent with a game's revealed guesses
+///
+/// Restricted to admins, as it would otherwise solve the daily puzzle.
+#[debug_handler]
+pub async fn get_candidates(
+    State(state): State<AppState>,
+    RequireRole(role): RequireRole,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<CandidatesResponse>, GameError> {
+    let game = state.game.get_game(&game_id).await?;
+
+    let guesses = &game.guesses[..game.revealed_guess_count()];
+    let candidates = state
+        .game
+        .game_service()
+        .filter_candidates_in(guesses, game.language.as_deref());
+    tracing::info!(
+        "Admin ({}) listed {} candidates for game {}",
+        role,
+        candidates.len(),
+        game.id
+    );
+
+    Ok(Json(CandidatesResponse {
+        count: candidates.len(),
+        candidates,
+    }))
+}
+
+/// Get the shareable result text for a completed game
+#[debug_handler]
+pub async fn share_game(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<ShareResponse>, GameError> {
+    // Get game
+    let game = state.game.get_game(&game_id).await?;
+
+    // Verify game ownership
+    if game.user_id != auth_user_id.0 {
+        return Err(GameError::GameNotFound);
+    }
+
+    // Only completed games can be shared
+    if !game.is_completed() {
+        return Err(GameError::GameInProgress);
+    }
+
+    Ok(Json(ShareResponse {
+        text: game.share_grid(),
+    }))
+}
+
+/// Abandon a game
+///
+/// Responds with the abandoned game unless a minimal response is preferred.
+#[debug_handler]
+pub async fn delete_game(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
+    preference: ReturnPreference,
+) -> Result<Response, GameError> {
+    // Get game
+    let game = state.game.get_game(&game_id).await?;
+
+    // Verify game ownership
+    if game.user_id != auth_user_id.0 {
+        return Err(GameError::GameNotFound);
+    }
+
+    // Delete the game
+    state.game.delete_game(&game_id).await?;
+
+    // Clear the user's current game reference if it pointed to this game
+    let word_length = game.word.chars().count();
+    let current_game_id = state
+        .auth
+        .get_current_user_game_id(&auth_user_id.0, word_length)
+        .await?;
+    if current_game_id == Some(game_id) {
+        state
+            .auth
+            .clear_user_game(&auth_user_id.0, word_length)
+            .await?;
+    }
+
+    Ok(preference.respond(game_response(&state, game)))
+}
+
+/// Get statistics aggregated over the user's game history
+#[debug_handler]
+pub async fn get_stats(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+) -> Result<Json<StatsResponse>, GameError> {
+    // Get summaries of all of the user's games
+    let games = state.game.list_game_summaries(&auth_user_id.0).await?;
+
+    // Aggregate the statistics by day in the daily reset timezone
     let game_service = state.game.game_service();
+    let stats = GameStats::from_summaries(&games, game_service.today(), game_service.timezone());
+
+    Ok(Json(StatsResponse::from(stats)))
+}
 
-    // Make the guess
-    game_service.make_guess(&mut game, &request.word)?;
+/// Get the number of players active today
+#[debug_handler]
+pub async fn get_live_stats(
+    State(state): State<AppState>,
+) -> Result<Json<LiveStatsResponse>, GameError> {
+    // Check for day change so yesterday's players aren't counted
+    state.check_and_update_date().await?;
 
-    // Save the updated game
-    state.game.save_game(game.clone()).await?;
+    Ok(Json(LiveStatsResponse {
+        active_today: state.game.active_today(),
+    }))
+}
+
+/// Get the number and date of today's puzzle, without its word
+///
+/// The date is the current day in the daily reset timezone.
+#[debug_handler]
+pub async fn get_today_puzzle(State(state): State<AppState>) -> Json<TodayPuzzleResponse> {
+    let game_service = state.game.game_service();
+    let (date, index) = game_service.current_puzzle();
+
+    Json(TodayPuzzleResponse {
+        puzzle_number: puzzle_number(date),
+        date,
+        word_length: game_service.word_length(),
+        puzzle_index: (game_service.puzzles_per_day() > 1).then_some(index),
+    })
+}
+
+/// Get the user's result for a puzzle number
+#[debug_handler]
+pub async fn get_puzzle_result(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(number): Path<i64>,
+) -> Result<Json<PuzzleResultResponse>, GameError> {
+    let game_service = state.game.game_service();
+
+    // Only puzzles up to today exist
+    let today = game_service.today();
+    let date = puzzle_date(number)
+        .filter(|&date| date <= today)
+        .ok_or_else(|| {
+            GameError::BadRequest(format!(
+                "Puzzle number must be between 0 and {}",
+                puzzle_number(today)
+            ))
+        })?;
+
+    // Find the user's daily games from that day
+    let start = game_service.day_start(date);
+    let end = game_service.day_start(date.succ_opt().unwrap_or(date));
+    let games = state.game.list_games_for_user(&auth_user_id.0).await?;
+    let reveal_word = state.game.config().reveal_answer_on_complete;
+    let day_games: Vec<&Game> = games
+        .iter()
+        .filter(|g| !g.practice && !g.is_replay() && g.created_at >= start && g.created_at < end)
+        .collect();
+
+    // Prefer a winning game, otherwise the most recent one
+    let game = day_games
+        .iter()
+        .find(|g| g.won)
+        .or_else(|| day_games.iter().max_by_key(|g| g.created_at))
+        .ok_or(GameError::GameNotFound)?;
+
+    Ok(Json(PuzzleResultResponse {
+        puzzle: number,
+        date,
+        completed: game.is_completed(),
+        won: game.won,
+        guess_count: game.guesses.len(),
+        grid: game.emoji_grid(),
+        word: (reveal_word && game.is_completed()).then(|| game.word.clone()),
+    }))
+}
+
+/// Get the user's results calendar for a month
+#[debug_handler]
+pub async fn get_calendar(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Query(query): Query<CalendarQuery>,
+) -> Result<Json<CalendarResponse>, GameError> {
+    // Parse the month in YYYY-MM format
+    let month_start = (query.month.len() == 7)
+        .then(|| NaiveDate::parse_from_str(&format!("{}-01", query.month), "%Y-%m-%d").ok())
+        .flatten()
+        .ok_or_else(|| GameError::BadRequest("Month must be in YYYY-MM format".into()))?;
+
+    // Get summaries of all of the user's games
+    let games = state.game.list_game_summaries(&auth_user_id.0).await?;
+
+    Ok(Json(CalendarResponse {
+        month: query.month,
+        days: month_calendar(&games, month_start, state.game.game_service().timezone()),
+    }))
+}
+
+/// List the user's past games, most recent first
+#[debug_handler]
+pub async fn get_history(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryResponse>, GameError> {
+    let limit = match query.limit {
+        Some(limit) => usize::try_from(limit)
+            .map_err(|_| GameError::BadRequest("limit must not be negative".into()))?
+            .min(MAX_HISTORY_SIZE),
+        None => DEFAULT_HISTORY_SIZE,
+    };
+
+    // List the user's games, skipping in-progress ones unless requested
+    let mut games = state.game.list_games_for_user(&auth_user_id.0).await?;
+    games.retain(|game| query.include_active || game.is_completed());
+    games.sort_by_key(|game| std::cmp::Reverse(game.created_at));
+    games.truncate(limit);
+
+    let reveal_word = state.game.config().reveal_answer_on_complete;
+    let game_service = state.game.game_service();
+
+    let games = games
+        .into_iter()
+        .map(|game| HistoryEntryResponse {
+            id: game.id,
+            date: game_service.date_of(game.created_at),
+            completed: game.is_completed(),
+            won: game.won,
+            guess_count: game.guesses.len(),
+            // Only reveal the word of completed games
+            word: (reveal_word && game.is_completed()).then(|| game.word.clone()),
+            // Built like the game view so hidden results stay hidden
+            guesses: GameResponse::from(game).guesses,
+        })
+        .collect();
+
+    Ok(Json(HistoryResponse { games }))
+}
+
+/// Get a shareable summary of the user's play today across daily and practice games
+#[debug_handler]
+pub async fn get_today_share(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+) -> Result<Json<ShareResponse>, GameError> {
+    let game_service = state.game.game_service();
+    let day_start = game_service.day_start(game_service.today());
+
+    // Get the user's games from today
+    let mut games = state.game.list_games_for_user(&auth_user_id.0).await?;
+    games.retain(|game| game.created_at >= day_start);
+
+    Ok(Json(ShareResponse {
+        text: daily_summary(&games),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{HeaderValue, Method, Request, StatusCode, header};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::api::AppState;
+    use crate::auth::AuthState;
+    use crate::common::config::Config;
+    use crate::game::{GameState, puzzle_number};
+    use crate::repository::memory::{InMemoryGameRepository, InMemoryUserRepository};
+    use crate::test_support::{self, send};
+
+    /// Router over empty repositories, called by a signed-in user
+    struct Client {
+        state: AppState,
+        router: Router,
+        user_id: Uuid,
+        token: String,
+    }
+
+    impl Client {
+        fn new() -> Self {
+            Self::with_config(test_support::config())
+        }
+
+        fn with_config(config: Config) -> Self {
+            let state = test_support::app_state(config);
+            let router = test_support::router(&state);
+            let user_id = Uuid::new_v4();
+            let token = test_support::token(&test_support::claims(user_id));
+
+            Self {
+                state,
+                router,
+                user_id,
+                token,
+            }
+        }
+
+        async fn send(
+            &self,
+            method: Method,
+            uri: &str,
+            body: Option<Value>,
+        ) -> (StatusCode, Value) {
+            send(&self.router, method, uri, Some(&self.token), body).await
+        }
+    }
 
-    // Return the updated game response
-    Ok(Json(GameResponse::from(game)))
+    #[tokio::test]
+    async fn games_of_different_lengths_are_kept_apart() {
+        let games = Arc::new(InMemoryGameRepository::new());
+        let auth = Arc::new(
+            AuthState::new(
+                Arc::new(InMemoryUserRepository::new()),
+                &test_support::config().jwt,
+            )
+            .unwrap(),
+        );
+        let user_id = Uuid::new_v4();
+        let token = test_support::token(&test_support::claims(user_id));
+
+        let mut ids = Vec::new();
+        for word_length in [5, 6] {
+            let mut config = test_support::config();
+            config.game.word_length = word_length;
+            let game = GameState::new(games.clone(), &config.game).unwrap();
+            let state = AppState::new(Arc::new(game), auth.clone(), Arc::new(config));
+            let router = test_support::router(&state);
+
+            let (status, body) = send(
+                &router,
+                Method::POST,
+                "/api/v1/game/new",
+                Some(&token),
+                Some(json!({})),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["word_length"], word_length);
+            ids.push(body["id"].as_str().unwrap().parse::<Uuid>().unwrap());
+        }
+
+        assert_ne!(ids[0], ids[1]);
+        for (word_length, id) in [(5, ids[0]), (6, ids[1])] {
+            assert_eq!(
+                auth.get_current_user_game_id(&user_id, word_length)
+                    .await
+                    .unwrap(),
+                Some(id)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn practice_games_get_independent_words() {
+        let client = Client::new();
+
+        let mut ids = HashSet::new();
+        for _ in 0..10 {
+            let (status, body) = client
+                .send(Method::POST, "/api/v1/game/practice", Some(json!({})))
+                .await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["practice"], true);
+            ids.insert(body["id"].as_str().unwrap().to_string());
+        }
+        assert_eq!(ids.len(), 10);
+
+        let games = client
+            .state
+            .game
+            .list_games_for_user(&client.user_id)
+            .await
+            .unwrap();
+        let words: HashSet<&str> = games.iter().map(|game| game.word.as_str()).collect();
+        assert!(words.len() > 1, "every practice game got {:?}", words);
+    }
+
+    #[tokio::test]
+    async fn concurrent_archive_requests_share_one_game() {
+        let client = Client::new();
+        let uri = "/api/v1/game/archive/2025-04-01";
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..8 {
+            let (router, token) = (client.router.clone(), client.token.clone());
+            requests
+                .spawn(async move { send(&router, Method::GET, uri, Some(&token), None).await });
+        }
+
+        let mut ids = Vec::new();
+        while let Some(response) = requests.join_next().await {
+            let (status, body) = response.unwrap();
+            assert_eq!(status, StatusCode::OK);
+            ids.push(body["id"].clone());
+        }
+        assert!(ids.iter().all(|id| *id == ids[0]));
+
+        let games = client
+            .state
+            .game
+            .list_games_for_user(&client.user_id)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(
+            games[0].word,
+            client
+                .state
+                .game
+                .game_service()
+                .word_for_date("2025-04-01".parse().unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn archive_games_are_returned_again_for_the_same_date() {
+        let client = Client::new();
+
+        let (status, first) = client
+            .send(Method::GET, "/api/v1/game/archive/2025-04-01", None)
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, again) = client
+            .send(Method::GET, "/api/v1/game/archive/2025-04-01", None)
+            .await;
+        assert_eq!(again["id"], first["id"]);
+
+        let (status, other) = client
+            .send(Method::GET, "/api/v1/game/archive/2025-04-02", None)
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_ne!(other["id"], first["id"]);
+    }
+
+    #[tokio::test]
+    async fn current_game_is_found_once_created() {
+        let client = Client::new();
+
+        let (status, body) = client.send(Method::GET, "/api/v1/game/current", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body["error"].is_string());
+
+        let (_, created) = client
+            .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+            .await;
+        let (status, body) = client.send(Method::GET, "/api/v1/game/current", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], created["id"]);
+    }
+
+    #[tokio::test]
+    async fn puzzle_results_cover_played_unplayed_and_out_of_range_numbers() {
+        let client = Client::new();
+        let today = puzzle_number(client.state.game.game_service().today());
+
+        let (_, created) = client
+            .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+            .await;
+        let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+        let word = client.state.game.get_game(&id).await.unwrap().word;
+        client
+            .send(
+                Method::POST,
+                &format!("/api/v1/game/{}/guess", id),
+                Some(json!({ "word": word })),
+            )
+            .await;
+
+        let (status, body) = client
+            .send(
+                Method::GET,
+                &format!("/api/v1/stats/puzzle/{}", today),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["won"], true);
+        assert_eq!(body["guess_count"], 1);
+
+        let (status, _) = client
+            .send(
+                Method::GET,
+                &format!("/api/v1/stats/puzzle/{}", today - 1),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        for number in [-1, today + 1] {
+            let (status, _) = client
+                .send(
+                    Method::GET,
+                    &format!("/api/v1/stats/puzzle/{}", number),
+                    None,
+                )
+                .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "puzzle {}", number);
+        }
+    }
+
+    #[tokio::test]
+    async fn games_end_after_the_configured_attempts() {
+        let mut config = test_support::config();
+        config.game.max_attempts = 3;
+        let client = Client::with_config(config);
+
+        let (_, created) = client
+            .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+            .await;
+        assert_eq!(created["attempts_remaining"], 3);
+        let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+        let word = client.state.game.get_game(&id).await.unwrap().word;
+
+        let uri = format!("/api/v1/game/{}/guess", id);
+        let wrong = ["plant", "house", "apple", "mouse"]
+            .into_iter()
+            .filter(|guess| *guess != word)
+            .take(3);
+        let mut last = Value::Null;
+        for guess in wrong {
+            let (status, body) = client
+                .send(Method::POST, &uri, Some(json!({ "word": guess })))
+                .await;
+            assert_eq!(status, StatusCode::OK);
+            last = body;
+        }
+        assert_eq!(last["completed"], true);
+        assert_eq!(last["won"], false);
+        assert_eq!(last["attempts_remaining"], 0);
+    }
+
+    #[tokio::test]
+    async fn guesses_honour_the_return_preference() {
+        let client = Client::new();
+        let (_, created) = client
+            .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+            .await;
+        let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+        let uri = format!("/api/v1/game/{}/guess", id);
+        let word = client.state.game.get_game(&id).await.unwrap().word;
+        let guess = if word == "trust" { "house" } else { "trust" };
+
+        for (preference, expected, guess_count) in [
+            ("return=minimal", StatusCode::NO_CONTENT, 1),
+            ("return=representation", StatusCode::OK, 2),
+        ] {
+            let request = Request::post(&uri)
+                .header(header::AUTHORIZATION, format!("Bearer {}", client.token))
+                .header(header::CONTENT_TYPE, "application/json")
+                .header("prefer", preference)
+                .body(Body::from(json!({ "word": guess }).to_string()))
+                .unwrap();
+            let response = client.router.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), expected, "{}", preference);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            match expected {
+                StatusCode::NO_CONTENT => assert!(body.is_empty()),
+                _ => {
+                    let game: Value = serde_json::from_slice(&body).unwrap();
+                    assert_eq!(game["guesses"].as_array().unwrap().len(), guess_count);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_creates_share_one_game() {
+        let client = Client::new();
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..2 {
+            let (router, token) = (client.router.clone(), client.token.clone());
+            requests.spawn(async move {
+                send(
+                    &router,
+                    Method::POST,
+                    "/api/v1/game/new",
+                    Some(&token),
+                    Some(json!({})),
+                )
+                .await
+            });
+        }
+
+        let mut ids = Vec::new();
+        while let Some(response) = requests.join_next().await {
+            let (status, body) = response.unwrap();
+            assert_eq!(status, StatusCode::OK);
+            ids.push(body["id"].clone());
+        }
+        assert_eq!(ids[0], ids[1]);
+
+        let games = client
+            .state
+            .game
+            .list_games_for_user(&client.user_id)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn history_skips_active_games_unless_asked() {
+        let client = Client::new();
+        for guesses in [&["plant"][..], &["house", "plant"], &["house"]] {
+            let game = test_support::played_game(client.user_id, "plant", guesses);
+            client.state.game.save_game(game).await.unwrap();
+        }
+
+        for (uri, expected) in [
+            ("/api/v1/game/history", 2),
+            ("/api/v1/game/history?include_active=true", 3),
+        ] {
+            let (status, body) = client.send(Method::GET, uri, None).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["games"].as_array().unwrap().len(), expected, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn unchanged_games_are_not_modified() {
+        let client = Client::new();
+        let (_, created) = client
+            .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+            .await;
+        let id = created["id"].as_str().unwrap().to_string();
+
+        let fetch = |uri: String, etag: Option<HeaderValue>| {
+            let mut request =
+                Request::get(uri).header(header::AUTHORIZATION, format!("Bearer {}", client.token));
+            if let Some(etag) = etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            client
+                .router
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        for uri in [
+            format!("/api/v1/game/{}", id),
+            "/api/v1/game/current".into(),
+        ] {
+            let response = fetch(uri.clone(), None).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let etag = response.headers()[header::ETAG].clone();
+
+            let response = fetch(uri.clone(), Some(etag)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_guesses_stop_once_the_game_is_won() {
+        let client = Client::new();
+        let game = test_support::played_game(client.user_id, "plant", &[]);
+        let uri = format!("/api/v1/game/{}/guesses", game.id);
+        client.state.game.save_game(game).await.unwrap();
+
+        let (status, body) = client
+            .send(
+                Method::POST,
+                &uri,
+                Some(json!({ "words": ["house", "plant", "plate"] })),
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["applied"], 2);
+        assert_eq!(body["game"]["won"], true);
+
+        let guesses: Vec<&str> = body["game"]["guesses"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|guess| guess["word"].as_str().unwrap())
+            .collect();
+        assert_eq!(guesses, ["house", "plant"]);
+    }
+
+    #[tokio::test]
+    async fn completed_dailies_are_replayed_only_when_allowed() {
+        for allow_daily_replay in [false, true] {
+            let mut config = test_support::config();
+            config.game.allow_daily_replay = allow_daily_replay;
+            let client = Client::with_config(config);
+
+            let (_, created) = client
+                .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+                .await;
+            let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+            let word = client.state.game.get_game(&id).await.unwrap().word;
+            client
+                .send(
+                    Method::POST,
+                    &format!("/api/v1/game/{}/guess", id),
+                    Some(json!({ "word": word })),
+                )
+                .await;
+
+            let (status, again) = client
+                .send(Method::POST, "/api/v1/game/new", Some(json!({})))
+                .await;
+            assert_eq!(status, StatusCode::OK);
+            if allow_daily_replay {
+                let replay: Uuid = again["id"].as_str().unwrap().parse().unwrap();
+                assert_ne!(replay, id);
+                assert_eq!(again["completed"], false);
+                assert_eq!(again["replay_count"], 1);
+                let replay = client.state.game.get_game(&replay).await.unwrap();
+                assert_eq!(replay.word, word);
+            } else {
+                assert_eq!(again["id"], created["id"]);
+                assert_eq!(again["won"], true);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn todays_puzzle_is_public_and_hides_the_word() {
+        let client = Client::new();
+        let (status, body) = send(
+            &client.router,
+            Method::GET,
+            "/api/v1/game/today",
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let today = client.state.game.game_service().today();
+        assert_eq!(body["puzzle_number"], puzzle_number(today));
+        assert_eq!(body["date"], today.to_string());
+        assert_eq!(body["word_length"], 5);
+        assert!(body.get("word").is_none());
+    }
+
+    #[tokio::test]
+    async fn games_are_checked_against_their_language() {
+        let mut config = test_support::config();
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/words_es.txt");
+        config.game.language_word_lists = vec![("es".to_string(), fixture)];
+        let client = Client::with_config(config);
+
+        let request = Request::post("/api/v1/game/new")
+            .header(header::AUTHORIZATION, format!("Bearer {}", client.token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT_LANGUAGE, "es-ES,en;q=0.5")
+            .body(Body::from("{}"))
+            .unwrap();
+        let response = client.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created["language"], "es");
+
+        let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+        let word = client.state.game.get_game(&id).await.unwrap().word;
+        let uri = format!("/api/v1/game/{}/guess", id);
+        let spanish = if word == "perro" { "libro" } else { "perro" };
+
+        let (status, _) = client
+            .send(Method::POST, &uri, Some(json!({ "word": spanish })))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = client
+            .send(Method::POST, &uri, Some(json!({ "word": "house" })))
+            .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn short_and_unknown_guesses_have_distinct_codes() {
+        let client = Client::new();
+        let game = test_support::played_game(client.user_id, "plant", &[]);
+        let uri = format!("/api/v1/game/{}/guess", game.id);
+        client.state.game.save_game(game).await.unwrap();
+
+        for (guess, code) in [("pla", "too_short"), ("zzzzz", "not_in_word_list")] {
+            let (status, body) = client
+                .send(Method::POST, &uri, Some(json!({ "word": guess })))
+                .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(body["code"], code, "{}", guess);
+        }
+    }
+
+    #[tokio::test]
+    async fn surrender_reveals_the_word() {
+        let client = Client::new();
+        let game = test_support::played_game(client.user_id, "plant", &["house"]);
+        let uri = format!("/api/v1/game/{}/surrender", game.id);
+        client.state.game.save_game(game).await.unwrap();
+
+        let (status, body) = client.send(Method::POST, &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["word"], "plant");
+        assert_eq!(body["completed"], true);
+        assert_eq!(body["surrendered"], true);
+        assert_eq!(body["won"], false);
+
+        let (status, _) = client.send(Method::POST, &uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn practice_games_beyond_the_daily_limit_are_refused() {
+        let mut config = test_support::config();
+        config.game.max_daily_games_per_user = 3;
+        let client = Client::with_config(config);
+
+        for _ in 0..3 {
+            let (status, _) = client
+                .send(Method::POST, "/api/v1/game/practice", Some(json!({})))
+                .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        let (status, _) = client
+            .send(Method::POST, "/api/v1/game/practice", Some(json!({})))
+            .await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn completed_games_reveal_the_word_only_when_configured() {
+        for reveal in [true, false] {
+            let mut config = test_support::config();
+            config.game.reveal_answer_on_complete = reveal;
+            let client = Client::with_config(config);
+            let game = test_support::played_game(client.user_id, "plant", &["plant"]);
+            let uri = format!("/api/v1/game/{}", game.id);
+            client.state.game.save_game(game).await.unwrap();
+
+            let (status, body) = client.send(Method::GET, &uri, None).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["completed"], true);
+            if reveal {
+                assert_eq!(body["word"], "plant");
+            } else {
+                assert!(body["word"].is_null());
+            }
+        }
+    }
 }
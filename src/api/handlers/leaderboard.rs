@@ -0,0 +1,110 @@
+use axum::{Json, extract::State};
+use axum_macros::debug_handler;
+
+use crate::api::AppState;
+use crate::api::models::{LeaderboardEntryResponse, LeaderboardResponse};
+use crate::auth::AuthUserId;
+use crate::game::error::GameError;
+use crate::game::leaderboard::{LEADERBOARD_SIZE, pseudonym, rank_winners};
+
+/// Get today's leaderboard of winning daily games
+#[debug_handler]
+pub async fn get_leaderboard(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+) -> Result<Json<LeaderboardResponse>, GameError> {
+    let game_service = state.game.game_service();
+    let date = game_service.today();
+
+    // Get summaries of today's games and rank the winners
+    let games = state
+        .game
+        .list_game_summaries_since(game_service.day_start(date))
+        .await?;
+    let anonymize = state.game.config().leaderboard_anonymize;
+
+    let mut entries = Vec::new();
+    for (i, game) in rank_winners(&games)
+        .into_iter()
+        .take(LEADERBOARD_SIZE)
+        .enumerate()
+    {
+        let you = game.user_id == auth_user_id.0;
+
+        // Users always see their own name; others are hidden when anonymizing
+        let name = if anonymize && !you {
+            pseudonym(&game.user_id)
+        } else {
+            match state.auth.get_user(&game.user_id).await {
+                Ok(user) => user.username,
+                Err(_) => pseudonym(&game.user_id),
+            }
+        };
+
+        entries.push(LeaderboardEntryResponse {
+            rank: i + 1,
+            name,
+            guesses: game.guess_count,
+            you,
+        });
+    }
+
+    Ok(Json(LeaderboardResponse { date, entries }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use uuid::Uuid;
+
+    use crate::auth::models::User;
+    use crate::game::leaderboard::pseudonym;
+    use crate::test_support::{self, played_game, send};
+
+    #[tokio::test]
+    async fn anonymized_leaderboard_shows_stable_pseudonyms() {
+        let mut config = test_support::config();
+        config.game.leaderboard_anonymize = true;
+        let state = test_support::app_state(config);
+        let router = test_support::router(&state);
+
+        let (me, other) = (Uuid::new_v4(), Uuid::new_v4());
+        for (user_id, username, guesses) in [
+            (me, "me", &["house", "plant"][..]),
+            (other, "alice", &["plant"][..]),
+        ] {
+            state
+                .auth
+                .save_user(User::new(user_id, username.to_string()))
+                .await
+                .unwrap();
+            state
+                .game
+                .save_game(played_game(user_id, "plant", guesses))
+                .await
+                .unwrap();
+        }
+        let token = test_support::token(&test_support::claims(me));
+
+        let mut names = Vec::new();
+        for _ in 0..2 {
+            let (status, body) = send(
+                &router,
+                Method::GET,
+                "/api/v1/leaderboard",
+                Some(&token),
+                None,
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+
+            let entries = body["entries"].as_array().unwrap();
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[1]["name"], "me");
+            names.push(entries[0]["name"].as_str().unwrap().to_string());
+        }
+
+        assert_eq!(names[0], pseudonym(&other));
+        assert_eq!(names[0], names[1]);
+    }
+}
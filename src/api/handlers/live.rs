@@ -0,0 +1,161 @@
+use axum::{
+    extract::{
+        Path, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    response::Response,
+};
+use axum_macros::debug_handler;
+use uuid::Uuid;
+
+use crate::api::AppState;
+use crate::api::handlers::game::{game_response, submit_guess};
+use crate::auth::AuthUserId;
+use crate::game::error::GameError;
+use crate::game::models::Game;
+use crate::server::subscribers::SubscriberGuard;
+
+/// Play a game over a WebSocket
+///
+/// Each text frame is a guess; the updated game is sent back after every
+/// guess and the socket is closed once the game is completed. Rejected
+/// guesses get an error frame and leave the socket open.
+#[debug_handler]
+pub async fn game_socket(
+    State(state): State<AppState>,
+    auth_user_id: AuthUserId,
+    Path(game_id): Path<Uuid>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, GameError> {
+    let game = state.game.get_game(&game_id).await?;
+
+    // Verify game ownership
+    if game.user_id != auth_user_id.0 {
+        return Err(GameError::GameNotFound);
+    }
+
+    let guard = state
+        .subscribers
+        .try_acquire()
+        .ok_or(GameError::TooManySubscribers)?;
+
+    Ok(ws.on_upgrade(move |socket| play(socket, state, game, guard)))
+}
+
+/// Relay guesses and game updates until the game ends or the client leaves
+async fn play(mut socket: WebSocket, state: AppState, game: Game, _guard: SubscriberGuard) {
+    let (user_id, game_id) = (game.user_id, game.id);
+
+    // Start with the current state so the client needs no separate fetch
+    let mut completed = game.is_completed();
+    if send_game(&mut socket, &state, game).await.is_err() {
+        return;
+    }
+
+    while !completed {
+        let word = match socket.recv().await {
+            Some(Ok(Message::Text(word))) => word,
+            Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+            Some(Ok(_)) => continue,
+        };
+
+        let sent = match submit_guess(&state, user_id, game_id, word.as_str()).await {
+            Ok(game) => {
+                completed = game.is_completed();
+                send_game(&mut socket, &state, game).await
+            }
+            Err(e) => send_error(&mut socket, e).await,
+        };
+        if sent.is_err() {
+            return;
+        }
+    }
+
+    let close = CloseFrame {
+        code: close_code::NORMAL,
+        reason: "Game completed".into(),
+    };
+    let _ = socket.send(Message::Close(Some(close))).await;
+}
+
+/// Send a game as a JSON text frame
+async fn send_game(
+    socket: &mut WebSocket,
+    state: &AppState,
+    game: Game,
+) -> Result<(), axum::Error> {
+    let response = game_response(state, game);
+    let text = serde_json::to_string(&response.0).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text.into())).await
+}
+
+/// Send an error as a JSON text frame with the same body as the HTTP API
+async fn send_error(socket: &mut WebSocket, error: GameError) -> Result<(), axum::Error> {
+    let (_, body) = error.status_and_body();
+    socket.send(Message::Text(body.to_string().into())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::header::AUTHORIZATION;
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::Value;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::{self, client::IntoClientRequest};
+
+    use super::*;
+    use crate::test_support::{self, played_game};
+
+    /// Receive the next text frame as JSON
+    async fn next_json<S>(socket: &mut S) -> Value
+    where
+        S: StreamExt<Item = Result<tungstenite::Message, tungstenite::Error>> + Unpin,
+    {
+        match socket.next().await {
+            Some(Ok(tungstenite::Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+            other => panic!("expected a text frame, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn guesses_over_a_socket_return_updated_games() {
+        let state = test_support::app_state(test_support::config());
+        let user_id = Uuid::new_v4();
+        let game = played_game(user_id, "plant", &[]);
+        let game_id = game.id;
+        state.game.save_game(game).await.unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = test_support::router(&state);
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let mut request = format!("ws://{}/api/v1/game/{}/ws", addr, game_id)
+            .into_client_request()
+            .unwrap();
+        let token = test_support::token(&test_support::claims(user_id));
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        let (mut socket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+
+        let initial = next_json(&mut socket).await;
+        assert_eq!(initial["guesses"].as_array().unwrap().len(), 0);
+
+        // Rejected guesses leave the socket open
+        socket.send("zzzzz".into()).await.unwrap();
+        assert!(next_json(&mut socket).await["error"].is_string());
+
+        socket.send("house".into()).await.unwrap();
+        let updated = next_json(&mut socket).await;
+        assert_eq!(updated["guesses"].as_array().unwrap().len(), 1);
+        assert_eq!(updated["completed"], false);
+
+        socket.send("plant".into()).await.unwrap();
+        assert_eq!(next_json(&mut socket).await["won"], true);
+        assert!(matches!(
+            socket.next().await,
+            Some(Ok(tungstenite::Message::Close(_)))
+        ));
+    }
+}
@@ -1,4 +1,10 @@
+pub mod admin;
+pub mod auth;
 pub mod game;
+pub mod leaderboard;
+pub mod live;
+pub mod user;
 pub mod util;
+pub mod words;
 
 pub use crate::api::models::{CreateGameRequest, GameResponse, GuessRequest};
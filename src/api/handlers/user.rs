@@ -0,0 +1,80 @@
+use axum::{Json, extract::State};
+use axum_macros::debug_handler;
+
+use crate::api::AppState;
+use crate::auth::Auth;
+use crate::auth::models::{User, UserResponse};
+use crate::game::error::GameError;
+use crate::repository::error::RepositoryError;
+
+/// Get the authenticated user's profile
+///
+/// Users who haven't started a game yet get a record created from their
+/// token, as creating a game would.
+#[debug_handler]
+pub async fn get_profile(
+    State(state): State<AppState>,
+    auth: Auth,
+) -> Result<Json<UserResponse>, GameError> {
+    let user = match state.auth.get_user(&auth.user_id).await {
+        Ok(user) => user,
+        Err(RepositoryError::NotFound) => {
+            tracing::info!(
+                "Creating new user record for: {} ({})",
+                auth.claims.username,
+                auth.user_id
+            );
+            let user = User::try_new(auth.user_id, auth.claims.username.clone())
+                .map_err(|e| GameError::BadRequest(e.to_string()))?;
+            state.auth.save_user(user.clone()).await?;
+            user
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let word_length = state.game.game_service().word_length();
+    let current_game_id = user.current_game_ids.get(&word_length).copied();
+
+    Ok(Json(UserResponse {
+        current_game_id,
+        ..UserResponse::from(user)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use uuid::Uuid;
+
+    use crate::test_support::{self, send};
+
+    #[tokio::test]
+    async fn profile_is_derived_from_the_token_before_any_game() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let user_id = Uuid::new_v4();
+        let token = test_support::token(&test_support::claims(user_id));
+
+        let (status, body) =
+            send(&router, Method::GET, "/api/v1/user/me", Some(&token), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], user_id.to_string());
+        assert_eq!(body["username"], "tester");
+        assert!(body["created_at"].is_string());
+        assert!(body["current_game_id"].is_null());
+
+        assert!(state.auth.get_user(&user_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn long_usernames_from_tokens_are_rejected() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let mut claims = test_support::claims(Uuid::new_v4());
+        claims.username = "a".repeat(100);
+        let token = test_support::token(&claims);
+
+        let (status, _) = send(&router, Method::GET, "/api/v1/user/me", Some(&token), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
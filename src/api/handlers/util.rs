@@ -1,8 +1,143 @@
-use axum::http::StatusCode;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
 use axum_macros::debug_handler;
 
+use crate::APP_VERSION;
+use crate::api::AppState;
+use crate::api::models::{
+    FeaturesResponse, HealthResponse, SignedSchedule, VerifyResultQuery, VerifyResultResponse,
+};
+use crate::game::signing::ResultPayload;
+
 /// Health check endpoint
+///
+/// Pings the storage backend unless games are held in memory, responding with
+/// `503` if it is unreachable.
+#[debug_handler]
+pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let (status, database) = if state.game.game_repository().is_in_memory() {
+        (StatusCode::OK, "memory")
+    } else {
+        match state.game.health_check().await {
+            Ok(()) => (StatusCode::OK, "up"),
+            Err(e) => {
+                tracing::warn!("Health check failed to reach storage: {}", e);
+                (StatusCode::SERVICE_UNAVAILABLE, "down")
+            }
+        }
+    };
+
+    let body = HealthResponse {
+        status: if status.is_success() {
+            "ok"
+        } else {
+            "unavailable"
+        },
+        version: APP_VERSION,
+        database,
+        pool: state.game.game_repository().pool_stats(),
+    };
+
+    (status, Json(body))
+}
+
+/// Get the features and settings enabled on this server
+#[debug_handler]
+pub async fn get_features(State(state): State<AppState>) -> Json<FeaturesResponse> {
+    let game_service = state.game.game_service();
+
+    Json(FeaturesResponse {
+        word_length: game_service.word_length(),
+        max_attempts: game_service.max_attempts(),
+        puzzles_per_day: game_service.puzzles_per_day(),
+        languages: game_service.languages(),
+        practice_mode: state.config.game.practice_mode,
+        hard_mode_available: true,
+        blind_mode_available: true,
+        database: cfg!(feature = "database"),
+        tls: state.config.tls.enabled,
+    })
+}
+
+/// Verify a signed game result
+#[debug_handler]
+pub async fn verify_result(
+    State(state): State<AppState>,
+    Query(query): Query<VerifyResultQuery>,
+) -> Json<VerifyResultResponse> {
+    let valid = state
+        .game
+        .result_signer()
+        .is_some_and(|signer| signer.verify(&ResultPayload::from(&query), &query.signature));
+
+    Json(VerifyResultResponse { valid })
+}
+
+/// Verify a signed word schedule
 #[debug_handler]
-pub async fn health_check() -> StatusCode {
-    StatusCode::OK
+pub async fn verify_schedule(
+    State(state): State<AppState>,
+    Json(signed): Json<SignedSchedule>,
+) -> Json<VerifyResultResponse> {
+    let valid = state
+        .game
+        .result_signer()
+        .is_some_and(|signer| signer.verify_schedule(&signed.schedule, &signed.signature));
+
+    Json(VerifyResultResponse { valid })
+}
+
+/// Render Prometheus metrics, sampling the connection pool first
+#[cfg(feature = "metrics")]
+#[debug_handler]
+pub async fn metrics(State(state): State<AppState>) -> Result<String, StatusCode> {
+    if let Some(stats) = state.game.game_repository().pool_stats() {
+        crate::server::metrics::record_pool_stats(&stats);
+    }
+    crate::server::metrics::render().ok_or(StatusCode::SERVICE_UNAVAILABLE)
+}
+
+/// Serve the OpenAPI description of the API
+#[cfg(feature = "openapi")]
+pub async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
+    use utoipa::OpenApi;
+
+    Json(crate::api::openapi::ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn features_reflect_config() {
+        let mut config = test_support::config();
+        config.game.max_attempts = 8;
+        config.game.practice_mode = false;
+        config.tls.enabled = true;
+
+        let Json(features) = get_features(State(test_support::app_state(config))).await;
+
+        assert_eq!(features.word_length, 5);
+        assert_eq!(features.max_attempts, 8);
+        assert_eq!(features.puzzles_per_day, 1);
+        assert_eq!(features.languages, vec!["en".to_string()]);
+        assert!(!features.practice_mode);
+        assert!(features.tls);
+    }
+
+    #[tokio::test]
+    async fn health_reports_in_memory_storage() {
+        let (status, Json(health)) =
+            health_check(State(test_support::app_state(test_support::config()))).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "ok");
+        assert_eq!(health.database, "memory");
+        assert_eq!(health.version, APP_VERSION);
+    }
 }
@@ -0,0 +1,59 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use axum_macros::debug_handler;
+
+use crate::api::AppState;
+use crate::api::language::AcceptLanguage;
+use crate::api::models::{WordQuery, WordValidityResponse};
+use crate::auth::AuthUserId;
+
+/// Check whether a word would be accepted as a guess
+#[debug_handler]
+pub async fn check_word(
+    State(state): State<AppState>,
+    _auth_user_id: AuthUserId,
+    accept_language: AcceptLanguage,
+    Query(query): Query<WordQuery>,
+) -> Json<WordValidityResponse> {
+    // Normalize the same way guesses are
+    let word = crate::game::normalize_word(&query.word);
+    let game_service = state.game.game_service();
+    let language = accept_language.select(&game_service);
+    let valid = game_service.is_valid_word_in(&word, language.as_deref());
+
+    Json(WordValidityResponse { word, valid })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use uuid::Uuid;
+
+    use crate::test_support::{self, send};
+
+    #[tokio::test]
+    async fn only_dictionary_words_are_valid() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let token = test_support::token(&test_support::claims(Uuid::new_v4()));
+
+        for (word, valid) in [("zzzzz", false), ("PLANT", true), ("%20plant%20", true)] {
+            let uri = format!("/api/v1/words/valid?word={}", word);
+            let (status, body) = send(&router, Method::GET, &uri, Some(&token), None).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["valid"], valid, "{}", word);
+        }
+
+        // A decomposed `è` (e + U+0300) matches the composed dictionary entry
+        let mut words: Vec<String> = ('a'..='t').map(|c| format!("qqqq{}", c)).collect();
+        words.push("crème".to_string());
+        state.game.replace_dictionary(&words).unwrap();
+        let uri = "/api/v1/words/valid?word=cre%CC%80me";
+        let (status, body) = send(&router, Method::GET, uri, Some(&token), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["valid"], true);
+        assert_eq!(body["word"], "crème");
+    }
+}
@@ -0,0 +1,71 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+
+use crate::game::{DEFAULT_LANGUAGE, GameService};
+
+/// Languages accepted by the client, most preferred first, from `Accept-Language`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AcceptLanguage(pub Vec<String>);
+
+impl AcceptLanguage {
+    /// Parse the primary language subtags of an `Accept-Language` header value
+    ///
+    /// Ranges are ordered by quality, keeping header order for ties; the
+    /// wildcard and ranges with `q=0` are dropped.
+    fn parse(value: &str) -> Vec<String> {
+        let mut ranges: Vec<(String, f32)> = value
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                let language = tag.split('-').next()?.to_ascii_lowercase();
+                (!language.is_empty() && language != "*" && quality > 0.0)
+                    .then_some((language, quality))
+            })
+            .collect();
+        ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        ranges.into_iter().map(|(language, _)| language).collect()
+    }
+
+    /// Pick the most preferred language with a dictionary
+    ///
+    /// Returns `None` for the default language, which is also used when no
+    /// accepted language has a dictionary.
+    pub fn select(&self, game_service: &GameService) -> Option<String> {
+        self.0
+            .iter()
+            .take_while(|language| *language != DEFAULT_LANGUAGE)
+            .find(|language| game_service.has_language(language))
+            .cloned()
+    }
+}
+
+impl<S> FromRequestParts<S> for AcceptLanguage
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Malformed ranges are ignored, leaving the default language
+        Ok(Self(
+            parts
+                .headers
+                .get_all(header::ACCEPT_LANGUAGE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(Self::parse)
+                .collect(),
+        ))
+    }
+}
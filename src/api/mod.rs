@@ -1,24 +1,75 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
-    Router,
+    Extension, Router,
     extract::FromRef,
     middleware,
-    routing::{get, post},
+    routing::{delete, get, post, put},
 };
+use tower_http::limit::RequestBodyLimitLayer;
 
 pub mod error;
+pub mod etag;
 pub mod handlers;
+pub mod language;
 pub mod models;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+pub mod prefer;
 
-use crate::auth::{AuthState, auth_middleware};
+use crate::auth::{AuthState, RequireRole, auth_middleware, read_only_auth_middleware};
+use crate::common::config::Config;
 use crate::game::GameState;
+use crate::repository::error::RepositoryResult;
+use crate::server::deadline::enforce_deadline;
+use crate::server::metrics::track_latency;
+use crate::server::request_id::propagate_request_id;
+use crate::server::subscribers::SubscriberLimit;
+use crate::server::{RateLimitLayer, cors_layer, mark_deprecated, payload_too_large};
+
+/// Maximum size of a game request body in bytes
+pub const MAX_GAME_BODY_BYTES: usize = 8 * 1024;
+
+/// Prefix of the current API version's routes
+pub const API_PREFIX: &str = "/api/v1";
 
 // Public struct for route state
 #[derive(Clone)]
 pub struct AppState {
     pub game: Arc<GameState>,
     pub auth: Arc<AuthState>,
+    pub config: Arc<Config>,
+    pub subscribers: Arc<SubscriberLimit>,
+}
+
+impl AppState {
+    /// Create the route state from the shared game, auth and configuration
+    pub fn new(game: Arc<GameState>, auth: Arc<AuthState>, config: Arc<Config>) -> Self {
+        Self {
+            game,
+            auth,
+            subscribers: Arc::new(SubscriberLimit::new(config.max_live_subscribers)),
+            config,
+        }
+    }
+
+    /// Check for a new day and reset games and users' current games if so
+    pub async fn check_and_update_date(&self) -> RepositoryResult<()> {
+        self.game
+            .check_and_update_date(|| async {
+                let reset = self
+                    .auth
+                    .user_repository()
+                    .reset_all_users_current_game()
+                    .await?;
+                tracing::info!("Daily reset cleared current game of {} users", reset);
+                self.auth.user_repository().reset_daily_creates().await
+            })
+            .await?;
+
+        Ok(())
+    }
 }
 
 // Implement FromRef for AppState to allow extracting AuthState
@@ -36,26 +87,380 @@ impl FromRef<AppState> for Arc<GameState> {
 }
 
 /// Configure API routes
-pub fn router(game_state: Arc<GameState>, auth_state: Arc<AuthState>) -> Router {
+pub fn router(
+    game_state: Arc<GameState>,
+    auth_state: Arc<AuthState>,
+    config: Arc<Config>,
+) -> Router {
     // Create combined state for routes
-    let route_state = AppState {
-        game: game_state,
-        auth: auth_state.clone(),
-    };
+    let route_state = AppState::new(game_state, auth_state.clone(), config);
+
+    // Allow browsers on the configured origins, or any origin without credentials
+    let cors = cors_layer(&route_state.config.cors_allowed_origins);
 
-    // Create health check route that doesn't need state
-    let health_route = Router::new().route("/health", get(handlers::util::health_check));
+    // Deadline for each request's repository calls (0 disables it)
+    let request_timeout = Duration::from_secs(route_state.config.request_timeout_secs);
+
+    // Whether the unversioned routes are served alongside the versioned ones
+    let legacy_api_routes = route_state.config.legacy_api_routes;
+
+    // Operational routes stay unversioned so probes and scrapers never move
+    let ops_routes = Router::new().route("/health", get(handlers::util::health_check));
+
+    // Expose Prometheus metrics when built with the metrics feature
+    #[cfg(feature = "metrics")]
+    let ops_routes = ops_routes.route("/metrics", get(handlers::util::metrics));
+
+    // Publish the API description when built with the openapi feature
+    #[cfg(feature = "openapi")]
+    let ops_routes = ops_routes.route("/openapi.json", get(handlers::util::openapi_spec));
+
+    let ops_routes = ops_routes.with_state(route_state.clone());
+
+    // Create public routes that don't require authentication
+    let mut public_routes = Router::new().route("/features", get(handlers::util::get_features));
+
+    // Only expose token issuance when enabled, behind passwords with local accounts
+    if route_state.config.jwt.allow_local_issuance {
+        #[cfg(not(feature = "local_auth"))]
+        {
+            public_routes = public_routes.route("/auth/login", post(handlers::auth::login));
+        }
+        #[cfg(feature = "local_auth")]
+        {
+            public_routes = public_routes
+                .route("/auth/register", post(handlers::auth::register))
+                .route("/auth/login", post(handlers::auth::password_login));
+        }
+    }
+
+    // Only expose result verification when result signing is enabled
+    if route_state.game.result_signer().is_some() {
+        public_routes = public_routes
+            .route("/verify", get(handlers::util::verify_result))
+            .route("/verify/schedule", post(handlers::util::verify_schedule));
+    }
+
+    let public_routes = public_routes.with_state(route_state.clone());
+
+    // Throttle guesses per client IP when a limit is configured, sharing
+    // one limit between guess requests, batches and WebSocket connections
+    let mut guess_route = post(handlers::game::make_guess);
+    let mut batch_guess_route = post(handlers::game::make_guesses);
+    let mut socket_route = get(handlers::live::game_socket);
+    if route_state.config.guess_rate_limit > 0 {
+        let limit = RateLimitLayer::per_minute(route_state.config.guess_rate_limit);
+        guess_route = guess_route.layer(limit.clone());
+        batch_guess_route = batch_guess_route.layer(limit.clone());
+        socket_route = socket_route.layer(limit);
+    }
 
     // Create protected game routes with auth
-    let game_routes = Router::new()
-        .route("/new", post(handlers::game::create_game))
+    let mut game_write_routes = Router::new().route("/new", post(handlers::game::create_game));
+
+    // Only expose games outside the daily puzzle when practice mode is enabled
+    if route_state.config.game.practice_mode {
+        game_write_routes = game_write_routes
+            .route("/practice", post(handlers::game::create_practice_game))
+            .route("/seeded", post(handlers::game::create_seeded_game))
+            .route("/archive/{date}", get(handlers::game::get_archive_game));
+    }
+
+    let game_write_routes = game_write_routes
+        .route("/{id}", delete(handlers::game::delete_game))
+        .route("/{id}/guess", guess_route)
+        .route("/{id}/guesses", batch_guess_route)
+        .route("/{id}/ws", socket_route)
+        .route("/{id}/surrender", post(handlers::game::surrender_game))
+        .route(
+            "/{id}/power/reveal-green",
+            post(handlers::game::reveal_green),
+        )
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            auth_middleware,
+        ));
+
+    // Read-only game routes accept recently expired tokens
+    let game_read_routes = Router::new()
+        .route("/current", get(handlers::game::get_current_game))
+        .route("/history", get(handlers::game::get_history))
         .route("/{id}", get(handlers::game::get_game))
-        .route("/{id}/guess", post(handlers::game::make_guess))
+        .route("/{id}/share", get(handlers::game::share_game))
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            read_only_auth_middleware,
+        ));
+
+    // Candidate answers would solve the game, so only admins may list them
+    let game_admin_routes = Router::new()
+        .route("/{id}/candidates", get(handlers::game::get_candidates))
+        .layer(Extension(RequireRole("admin")))
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            read_only_auth_middleware,
+        ));
+
+    // Today's puzzle is public, as it never includes the word
+    let game_public_routes = Router::new().route("/today", get(handlers::game::get_today_puzzle));
+
+    // Game requests carry at most a guess or a few options
+    let game_routes = game_write_routes
+        .merge(game_read_routes)
+        .merge(game_admin_routes)
+        .merge(game_public_routes)
+        .layer(RequestBodyLimitLayer::new(MAX_GAME_BODY_BYTES))
+        .with_state(route_state.clone());
+
+    // Create protected stats routes with read-only auth
+    let mut stats_routes = Router::new()
+        .route("/", get(handlers::game::get_stats))
+        .route("/calendar", get(handlers::game::get_calendar))
+        .route("/today/share", get(handlers::game::get_today_share))
+        .route("/puzzle/{number}", get(handlers::game::get_puzzle_result));
+
+    // Only expose the live player count when enabled
+    if route_state.config.game.live_stats {
+        stats_routes = stats_routes.route("/live", get(handlers::game::get_live_stats));
+    }
+
+    let stats_routes = stats_routes
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            read_only_auth_middleware,
+        ))
+        .with_state(route_state.clone());
+
+    // Create protected user routes with auth
+    let user_routes = Router::new()
+        .route("/me", get(handlers::user::get_profile))
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(route_state.clone());
+
+    // Create protected leaderboard routes with read-only auth
+    let leaderboard_routes = Router::new()
+        .route("/", get(handlers::leaderboard::get_leaderboard))
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            read_only_auth_middleware,
+        ))
+        .with_state(route_state.clone());
+
+    // Create protected word routes with read-only auth
+    let words_routes = Router::new()
+        .route("/valid", get(handlers::words::check_word))
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            read_only_auth_middleware,
+        ))
+        .with_state(route_state.clone());
+
+    // Create admin routes requiring the admin role
+    let mut admin_routes = Router::new()
+        .route("/games", get(handlers::admin::list_games))
+        .route("/reset", post(handlers::admin::reset))
+        .route("/dictionary", put(handlers::admin::replace_dictionary))
+        .route("/users/{id}", delete(handlers::admin::delete_user));
+
+    // Only expose the word schedule when result signing is enabled
+    if route_state.game.result_signer().is_some() {
+        admin_routes = admin_routes.route("/schedule", get(handlers::admin::word_schedule));
+    }
+
+    let admin_routes = admin_routes
+        .layer(Extension(RequireRole("admin")))
         .layer(middleware::from_fn_with_state(auth_state, auth_middleware))
         .with_state(route_state);
 
-    // Combine all routes
-    Router::new()
-        .nest("/api", health_route)
-        .nest("/api/game", game_routes)
+    // Combine the versioned routes
+    let api_routes = Router::new()
+        .merge(public_routes)
+        .nest("/game", game_routes)
+        .nest("/stats", stats_routes)
+        .nest("/user", user_routes)
+        .nest("/words", words_routes)
+        .nest("/leaderboard", leaderboard_routes)
+        .nest("/admin", admin_routes);
+
+    let mut router = Router::new().nest("/api", ops_routes);
+
+    // Keep serving the unversioned routes during the deprecation window
+    if legacy_api_routes {
+        router = router.nest(
+            "/api",
+            api_routes
+                .clone()
+                .layer(middleware::map_response(mark_deprecated)),
+        );
+    }
+
+    let router = router
+        .nest(API_PREFIX, api_routes)
+        .route_layer(middleware::from_fn(track_latency))
+        .layer(middleware::map_response(payload_too_large));
+
+    // Abort slow repository calls at each request's deadline
+    let router = if request_timeout.is_zero() {
+        router
+    } else {
+        router.layer(middleware::from_fn_with_state(
+            request_timeout,
+            enforce_deadline,
+        ))
+    };
+
+    // Tag every response, including CORS and auth rejections, with a request ID
+    router
+        .layer(cors)
+        .layer(middleware::from_fn(propagate_request_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode, header};
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::test_support::{self, played_game, send};
+
+    #[tokio::test]
+    async fn expired_token_within_grace_can_read_but_not_guess() {
+        let mut config = test_support::config();
+        config.jwt.leeway_secs = 0;
+        config.jwt.read_grace_secs = 60;
+        let state = test_support::app_state(config);
+        let router = test_support::router(&state);
+
+        let user_id = Uuid::new_v4();
+        let game = played_game(user_id, "plant", &[]);
+        let game_id = game.id;
+        state.game.save_game(game).await.unwrap();
+
+        let mut claims = test_support::claims(user_id);
+        claims.exp = OffsetDateTime::now_utc().unix_timestamp() as usize - 10;
+        let token = test_support::token(&claims);
+
+        let uri = format!("/api/v1/game/{}", game_id);
+        let (status, _) = send(&router, Method::GET, &uri, Some(&token), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send(
+            &router,
+            Method::POST,
+            &format!("{}/guess", uri),
+            Some(&token),
+            Some(json!({ "word": "plate" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn malformed_token_gets_a_challenge() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+
+        let response = router
+            .oneshot(
+                Request::get("/api/v1/stats")
+                    .header(header::AUTHORIZATION, "Bearer not.a.jwt")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let challenge = response.headers().get(header::WWW_AUTHENTICATE).unwrap();
+        assert!(
+            challenge
+                .to_str()
+                .unwrap()
+                .contains("error=\"invalid_token\"")
+        );
+    }
+
+    #[tokio::test]
+    async fn oversized_bodies_get_a_json_413() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let token = test_support::token(&test_support::claims(Uuid::new_v4()));
+
+        let padding = "x".repeat(1024 * 1024);
+        let (status, body) = send(
+            &router,
+            Method::POST,
+            "/api/v1/game/new",
+            Some(&token),
+            Some(json!({ "padding": padding })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body, json!({ "error": "payload too large" }));
+    }
+
+    #[tokio::test]
+    async fn cors_allows_only_configured_origins() {
+        let mut config = test_support::config();
+        config.cors_allowed_origins = vec![
+            "https://wordle.example".to_string(),
+            "https://admin.wordle.example".to_string(),
+        ];
+        let state = test_support::app_state(config);
+        let router = test_support::router(&state);
+
+        for (origin, allowed) in [
+            ("https://admin.wordle.example", true),
+            ("https://evil.example", false),
+        ] {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::get("/api/v1/features")
+                        .header(header::ORIGIN, origin)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            let allow_origin = response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|value| value.to_str().unwrap());
+            assert_eq!(allow_origin, allowed.then_some(origin), "{}", origin);
+        }
+    }
+
+    #[tokio::test]
+    async fn versioned_and_legacy_prefixes_both_route() {
+        let token = test_support::token(&test_support::claims(Uuid::new_v4()));
+        let get = |uri: &str| {
+            Request::get(uri)
+                .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let mut config = test_support::config();
+        let router = test_support::router(&test_support::app_state(config.clone()));
+        let response = router.clone().oneshot(get("/api/v1/stats")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get("deprecation").is_none());
+
+        let response = router.oneshot(get("/api/stats")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["deprecation"], "true");
+
+        config.legacy_api_routes = false;
+        let router = test_support::router(&test_support::app_state(config));
+        let response = router.oneshot(get("/api/stats")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,55 +1,474 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::game::models::{Game, LetterResult};
+use crate::game::models::{Game, LetterResult, MastermindResult, ScoringMode};
+use crate::game::signing::{ResultPayload, WordSchedule};
+use crate::game::stats::{CalendarDay, GameStats};
+use crate::repository::PoolStats;
 
 #[derive(Debug, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GameResponse {
     pub id: Uuid,
+    pub word_length: usize,
     pub attempts_remaining: u8,
     pub completed: bool,
     pub won: bool,
+    pub surrendered: bool,
+    pub hard_mode: bool,
+    pub practice: bool,
+    pub scoring: ScoringMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_date: Option<NaiveDate>,
+    pub replay_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub puzzle_index: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub word: Option<String>,
     pub guesses: Vec<GuessResponse>,
+    pub keyboard: HashMap<char, LetterResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub revealed_letters: Vec<RevealedLetterResponse>,
+    pub suspicious: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_signature: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RevealedLetterResponse {
+    pub position: usize,
+    pub letter: char,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GuessResponse {
     pub word: String,
     pub results: Vec<LetterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<MastermindResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms_since_previous: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatsResponse {
+    pub games_played: u32,
+    pub games_won: u32,
+    pub win_percentage: f64,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    pub guess_distribution: Vec<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LiveStatsResponse {
+    pub active_today: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
 }
 
+#[cfg(feature = "local_auth")]
 #[derive(Debug, Deserialize)]
-pub struct CreateGameRequest {}
+pub struct CredentialsRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub user_id: Uuid,
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyResultQuery {
+    pub game_id: Uuid,
+    pub user_id: Uuid,
+    pub won: bool,
+    pub guess_count: usize,
+    pub date: NaiveDate,
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyResultResponse {
+    pub valid: bool,
+}
 
 #[derive(Debug, Deserialize)]
+pub struct ScheduleQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedSchedule {
+    #[serde(flatten)]
+    pub schedule: WordSchedule,
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminResetResponse {
+    pub games_cleared: usize,
+    pub users_reset: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminDeleteUserResponse {
+    pub user_deleted: bool,
+    pub games_deleted: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PageQuery {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminGamesResponse {
+    pub games: Vec<Game>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DictionaryResponse {
+    pub words: usize,
+    pub version: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WordQuery {
+    pub word: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordValidityResponse {
+    pub word: String,
+    pub valid: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntryResponse {
+    pub rank: usize,
+    pub name: String,
+    pub guesses: usize,
+    pub you: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardResponse {
+    pub date: NaiveDate,
+    pub entries: Vec<LeaderboardEntryResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareResponse {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CandidatesResponse {
+    pub count: usize,
+    pub candidates: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodayPuzzleResponse {
+    pub puzzle_number: i64,
+    pub date: NaiveDate,
+    pub word_length: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub puzzle_index: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PuzzleResultResponse {
+    pub puzzle: i64,
+    pub date: NaiveDate,
+    pub completed: bool,
+    pub won: bool,
+    pub guess_count: usize,
+    pub grid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarQuery {
+    pub month: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalendarResponse {
+    pub month: String,
+    pub days: Vec<Option<CalendarDay>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub include_active: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryEntryResponse {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    pub completed: bool,
+    pub won: bool,
+    pub guess_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    pub guesses: Vec<GuessResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryResponse {
+    pub games: Vec<HistoryEntryResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateGameRequest {
+    #[serde(default)]
+    pub hard_mode: Option<bool>,
+    #[serde(default)]
+    pub blind_batch_size: Option<u8>,
+    #[serde(default)]
+    pub scoring: Option<ScoringMode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeededGameRequest {
+    pub seed: u64,
+    #[serde(default)]
+    pub hard_mode: Option<bool>,
+    #[serde(default)]
+    pub blind_batch_size: Option<u8>,
+    #[serde(default)]
+    pub scoring: Option<ScoringMode>,
+}
+
+/// Server status and the state of its storage
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub version: &'static str,
+    pub database: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<PoolStats>,
+}
+
+/// Features and settings enabled on this server
+#[derive(Debug, Serialize)]
+pub struct FeaturesResponse {
+    pub word_length: usize,
+    pub max_attempts: u8,
+    pub puzzles_per_day: u32,
+    pub languages: Vec<String>,
+    pub practice_mode: bool,
+    pub hard_mode_available: bool,
+    pub blind_mode_available: bool,
+    pub database: bool,
+    pub tls: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GuessRequest {
     pub word: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BatchGuessRequest {
+    pub words: Vec<String>,
+}
+
+/// Guess that stopped a batch, with the error it would have been rejected with
+#[derive(Debug, Serialize)]
+pub struct RejectedGuessResponse {
+    pub index: usize,
+    #[serde(flatten)]
+    pub error: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchGuessResponse {
+    pub applied: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected: Option<RejectedGuessResponse>,
+    pub game: GameResponse,
+}
+
 impl From<Game> for GameResponse {
     fn from(game: Game) -> Self {
+        Self::from_game(game, true)
+    }
+}
+
+impl GameResponse {
+    /// Build the response for a game, showing the word once it is completed
+    /// only if `reveal_word` is set
+    pub fn from_game(game: Game, reveal_word: bool) -> Self {
         // Only expose the secret word if the game is completed
-        let word = game.completed.then_some(game.word.clone());
+        let word = (reveal_word && game.completed).then(|| game.word.clone());
 
-        // Convert the guesses to GuessResponse
+        // Convert the guesses to GuessResponse, hiding results not yet revealed
+        // and, under Mastermind scoring, showing only the aggregate score
+        let revealed = game.revealed_guess_count();
+        let positional = game.scoring == ScoringMode::Wordle;
+        let intervals = game.guess_intervals_ms();
         let guesses = game
             .guesses
             .iter()
-            .map(|g| GuessResponse {
+            .zip(intervals)
+            .enumerate()
+            .map(|(i, (g, elapsed_ms_since_previous))| GuessResponse {
                 word: g.word.clone(),
-                results: g.results.clone(),
+                results: if i < revealed && positional {
+                    g.results.clone()
+                } else {
+                    Vec::new()
+                },
+                score: g.score.filter(|_| i < revealed),
+                elapsed_ms_since_previous,
+            })
+            .collect();
+
+        // Best-known state of each letter across revealed guesses
+        let mut keyboard: HashMap<char, LetterResult> = HashMap::new();
+        let keyboard_guesses = if positional { revealed } else { 0 };
+        for guess in &game.guesses[..keyboard_guesses] {
+            for (c, &result) in guess.word.chars().zip(&guess.results) {
+                keyboard
+                    .entry(c)
+                    .and_modify(|known| *known = known.best(result))
+                    .or_insert(result);
+            }
+        }
+
+        // Letters revealed by power-ups
+        let revealed_letters = game
+            .revealed_positions
+            .iter()
+            .filter_map(|&position| {
+                let letter = game.word.chars().nth(position)?;
+                Some(RevealedLetterResponse { position, letter })
             })
             .collect();
 
         Self {
             id: game.id,
+            word_length: game.word.chars().count(),
             attempts_remaining: game.attempts_remaining(),
             completed: game.completed,
             won: game.won,
+            surrendered: game.surrendered,
+            hard_mode: game.hard_mode,
+            practice: game.practice,
+            scoring: game.scoring,
+            seed: game.seed,
+            archive_date: game.archive_date,
+            replay_count: game.replay_count,
+            language: game.language.clone(),
+            puzzle_index: None,
             word,
             guesses,
+            keyboard,
+            revealed_letters,
+            suspicious: false,
+            score: None,
+            result_signature: None,
         }
     }
 }
+
+impl From<GameStats> for StatsResponse {
+    fn from(stats: GameStats) -> Self {
+        Self {
+            games_played: stats.games_played,
+            games_won: stats.games_won,
+            win_percentage: stats.win_percentage,
+            current_streak: stats.current_streak,
+            max_streak: stats.max_streak,
+            guess_distribution: stats.guess_distribution,
+        }
+    }
+}
+
+impl From<&VerifyResultQuery> for ResultPayload {
+    fn from(query: &VerifyResultQuery) -> Self {
+        Self {
+            game_id: query.game_id,
+            user_id: query.user_id,
+            won: query.won,
+            guess_count: query.guess_count,
+            date: query.date,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::game::GameService;
+
+    #[test]
+    fn blind_results_are_withheld_until_the_batch_is_full() {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), 6, Uuid::new_v4());
+        game.blind_batch_size = Some(2);
+
+        service.make_guess(&mut game, "plate").unwrap();
+        let response = GameResponse::from_game(game.clone(), true);
+        assert!(response.guesses[0].results.is_empty());
+        assert!(response.keyboard.is_empty());
+
+        service.make_guess(&mut game, "house").unwrap();
+        let response = GameResponse::from_game(game, true);
+        assert!(response.guesses.iter().all(|g| g.results.len() == 5));
+        assert_eq!(response.keyboard.get(&'p'), Some(&LetterResult::Correct));
+    }
+
+    #[test]
+    fn keyboard_keeps_the_best_result_for_each_letter() {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), 6, Uuid::new_v4());
+
+        // "trust" marks its first T Wrong, as an extra copy, before its last T Correct
+        for guess in ["sheet", "trust", "plate"] {
+            service.make_guess(&mut game, guess).unwrap();
+        }
+        assert_eq!(game.guesses[1].results[0], LetterResult::Wrong);
+
+        let keyboard = GameResponse::from_game(game, true).keyboard;
+        assert_eq!(keyboard[&'t'], LetterResult::Correct);
+        assert_eq!(keyboard[&'a'], LetterResult::Correct);
+        assert_eq!(keyboard[&'e'], LetterResult::Wrong);
+        assert_eq!(keyboard[&'r'], LetterResult::Wrong);
+    }
+}
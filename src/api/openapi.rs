@@ -0,0 +1,76 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::api::handlers;
+use crate::api::models::{
+    CreateGameRequest, GameResponse, GuessRequest, GuessResponse, RevealedLetterResponse,
+};
+use crate::game::models::{LetterResult, MastermindResult, ScoringMode};
+
+/// Name of the bearer token security scheme in the spec
+pub const BEARER_SCHEME: &str = "bearer";
+
+/// OpenAPI description of the game endpoints and their models
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Wordle API"),
+    paths(
+        handlers::game::create_game,
+        handlers::game::get_game,
+        handlers::game::make_guess,
+    ),
+    components(schemas(
+        GameResponse,
+        GuessResponse,
+        RevealedLetterResponse,
+        CreateGameRequest,
+        GuessRequest,
+        LetterResult,
+        MastermindResult,
+        ScoringMode,
+    )),
+    modifiers(&BearerAuth),
+    tags((name = "game", description = "Create and play games")),
+)]
+pub struct ApiDoc;
+
+/// Adds the JWT bearer scheme that the game endpoints require
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_SCHEME,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+
+    use crate::test_support::{self, send};
+
+    #[tokio::test]
+    async fn spec_describes_the_guess_endpoint() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+
+        let (status, spec) = send(&router, Method::GET, "/api/openapi.json", None, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let guess = &spec["paths"]["/api/v1/game/{id}/guess"];
+        assert!(guess["post"].is_object(), "{}", spec["paths"]);
+        assert_eq!(
+            spec["components"]["schemas"]["LetterResult"]["enum"],
+            serde_json::json!(["correct", "wrongposition", "wrong"])
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderName, HeaderValue, StatusCode, request::Parts},
+    response::{IntoResponse, Response},
+};
+
+/// `Prefer` request header (RFC 7240)
+static PREFER: HeaderName = HeaderName::from_static("prefer");
+
+/// `Preference-Applied` response header (RFC 7240)
+static PREFERENCE_APPLIED: HeaderName = HeaderName::from_static("preference-applied");
+
+/// How much of a mutated resource the client wants back, from `Prefer: return=...`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReturnPreference {
+    /// An empty `204 No Content` response
+    Minimal,
+
+    /// The full updated resource
+    #[default]
+    Representation,
+}
+
+impl ReturnPreference {
+    /// Parse the `return` preference from a `Prefer` header value
+    fn parse(value: &str) -> Option<Self> {
+        value
+            .split(',')
+            .map(|preference| preference.split(';').next().unwrap_or_default())
+            .find_map(
+                |preference| match preference.trim().to_ascii_lowercase().as_str() {
+                    "return=minimal" => Some(Self::Minimal),
+                    "return=representation" => Some(Self::Representation),
+                    _ => None,
+                },
+            )
+    }
+
+    /// Build the response for a mutation, dropping the body if minimal was preferred
+    pub fn respond(self, representation: impl IntoResponse) -> Response {
+        match self {
+            Self::Minimal => (
+                StatusCode::NO_CONTENT,
+                [(
+                    PREFERENCE_APPLIED.clone(),
+                    HeaderValue::from_static("return=minimal"),
+                )],
+            )
+                .into_response(),
+            Self::Representation => representation.into_response(),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ReturnPreference
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Unknown or malformed preferences are ignored, as RFC 7240 requires
+        Ok(parts
+            .headers
+            .get_all(&PREFER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(Self::parse)
+            .unwrap_or_default())
+    }
+}
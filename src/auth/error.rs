@@ -1,49 +1,253 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde_json::json;
+use std::time::Duration;
 use thiserror::Error;
 
+use crate::server::request_id;
+
+/// Coarse reason a JWT failed verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtErrorReason {
+    /// Token has expired
+    Expired,
+
+    /// Token is not yet valid
+    NotYetValid,
+
+    /// Signature does not match
+    BadSignature,
+
+    /// Issuer does not match
+    BadIssuer,
+
+    /// Audience does not match
+    BadAudience,
+
+    /// Algorithm does not match
+    BadAlgorithm,
+
+    /// A required claim is missing
+    MissingClaim,
+
+    /// Token could not be decoded
+    Malformed,
+}
+
+impl JwtErrorReason {
+    /// Get the reason as exposed to clients
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JwtErrorReason::Expired => "expired",
+            JwtErrorReason::NotYetValid => "not_yet_valid",
+            JwtErrorReason::BadSignature => "bad_signature",
+            JwtErrorReason::BadIssuer => "bad_issuer",
+            JwtErrorReason::BadAudience => "bad_audience",
+            JwtErrorReason::BadAlgorithm => "bad_algorithm",
+            JwtErrorReason::MissingClaim => "missing_claim",
+            JwtErrorReason::Malformed => "malformed",
+        }
+    }
+}
+
+/// Reason a username was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UsernameError {
+    /// Username is empty or only whitespace
+    #[error("Username must not be empty")]
+    Empty,
+
+    /// Username has more characters than allowed
+    #[error("Username must be at most {max} characters, got {actual}")]
+    TooLong { max: usize, actual: usize },
+
+    /// Username contains a character outside the allowed set
+    #[error("Username contains an invalid character: {0:?}")]
+    InvalidCharacter(char),
+}
+
+impl From<UsernameError> for AuthError {
+    fn from(error: UsernameError) -> Self {
+        AuthError::BadRequest(error.to_string())
+    }
+}
+
 /// Authentication-specific error types
 #[derive(Debug, Error)]
 pub enum AuthError {
     /// JWT token is invalid or expired
+    ///
+    /// Carries the failure reason only when it may be exposed to clients.
     #[error("Invalid or expired JWT token")]
-    JwtTokenInvalid,
+    JwtTokenInvalid(Option<JwtErrorReason>),
+
+    /// Request carries no bearer token
+    #[error("Missing bearer token")]
+    MissingCredentials,
 
     /// User is not authorized
     #[error("Unauthorized")]
     Unauthorized,
 
+    /// Too many failed attempts; the client may retry after the given delay
+    #[error("Too many failed attempts, try again later")]
+    TooManyAttempts(Duration),
+
+    /// User lacks a role required for the request
+    #[error("Forbidden")]
+    Forbidden,
+
+    /// Authorization header exceeds the configured size limit
+    #[error("Authorization header too large")]
+    HeaderTooLarge,
+
+    /// Username of a new local account is already registered
+    #[error("Username is already taken")]
+    UsernameTaken,
+
+    /// Request is malformed
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
     /// Internal server error
     #[error("Internal server error: {0}")]
     InternalError(#[from] anyhow::Error),
 }
 
+/// Bearer challenge for requests without credentials (RFC 6750 section 3.1)
+const BEARER_CHALLENGE: &str = "Bearer realm=\"wordle\"";
+
+/// Bearer challenge for requests whose token was rejected
+const INVALID_TOKEN_CHALLENGE: &str = "Bearer realm=\"wordle\", error=\"invalid_token\"";
+
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AuthError::JwtTokenInvalid => (StatusCode::UNAUTHORIZED, self.to_string()),
-            AuthError::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
+        // Challenge for failures the client can fix with a (new) token, and the
+        // delay before a throttled client may retry
+        let challenge = match self {
+            AuthError::MissingCredentials => Some(BEARER_CHALLENGE),
+            AuthError::JwtTokenInvalid(_) | AuthError::Unauthorized => {
+                Some(INVALID_TOKEN_CHALLENGE)
+            }
+            _ => None,
+        };
+        let retry_after = match self {
+            // Round up so clients never retry before the window ends
+            AuthError::TooManyAttempts(delay) => {
+                Some(delay.as_secs() + u64::from(delay.subsec_nanos() > 0))
+            }
+            _ => None,
+        };
+
+        let (status, mut body) = match self {
+            AuthError::JwtTokenInvalid(Some(reason)) => (
+                StatusCode::UNAUTHORIZED,
+                json!({
+                    "error": self.to_string(),
+                    "reason": reason.as_str(),
+                }),
+            ),
+            AuthError::JwtTokenInvalid(None) => (
+                StatusCode::UNAUTHORIZED,
+                json!({ "error": self.to_string() }),
+            ),
+            AuthError::MissingCredentials | AuthError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                json!({ "error": self.to_string() }),
+            ),
+            AuthError::TooManyAttempts(_) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                json!({ "error": self.to_string() }),
+            ),
+            AuthError::Forbidden => (StatusCode::FORBIDDEN, json!({ "error": self.to_string() })),
+            AuthError::HeaderTooLarge => (
+                StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE,
+                json!({ "error": self.to_string() }),
+            ),
+            AuthError::UsernameTaken => {
+                (StatusCode::CONFLICT, json!({ "error": self.to_string() }))
+            }
+            AuthError::BadRequest(msg) => (StatusCode::BAD_REQUEST, json!({ "error": msg })),
             AuthError::InternalError(e) => {
                 // Log the internal error
                 tracing::error!("Internal server error: {}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
+                    json!({ "error": "Internal server error" }),
                 )
             }
         };
 
-        let body = Json(json!({
-            "error": error_message,
-        }));
+        request_id::attach(&mut body);
+
+        let mut response = (status, Json(body)).into_response();
+        if let Some(challenge) = challenge {
+            response.headers_mut().insert(
+                header::WWW_AUTHENTICATE,
+                HeaderValue::from_static(challenge),
+            );
+        }
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs.max(1)));
+        }
 
-        (status, body).into_response()
+        response
     }
 }
 
 /// Result type for authentication operations
 pub type Result<T> = std::result::Result<T, AuthError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(error: AuthError, name: header::HeaderName) -> Option<String> {
+        let response = error.into_response();
+        response
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn missing_credentials_get_a_bare_challenge() {
+        assert_eq!(
+            header(AuthError::MissingCredentials, header::WWW_AUTHENTICATE).as_deref(),
+            Some("Bearer realm=\"wordle\"")
+        );
+    }
+
+    #[test]
+    fn rejected_tokens_get_an_invalid_token_challenge() {
+        for error in [
+            AuthError::JwtTokenInvalid(None),
+            AuthError::JwtTokenInvalid(Some(JwtErrorReason::Expired)),
+            AuthError::Unauthorized,
+        ] {
+            assert_eq!(
+                header(error, header::WWW_AUTHENTICATE).as_deref(),
+                Some("Bearer realm=\"wordle\", error=\"invalid_token\"")
+            );
+        }
+        assert_eq!(header(AuthError::Forbidden, header::WWW_AUTHENTICATE), None);
+    }
+
+    #[test]
+    fn throttled_attempts_get_retry_after() {
+        let error = AuthError::TooManyAttempts(Duration::from_millis(41_200));
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        let error = AuthError::TooManyAttempts(Duration::from_millis(41_200));
+        assert_eq!(header(error, header::RETRY_AFTER).as_deref(), Some("42"));
+        assert_eq!(header(AuthError::Unauthorized, header::RETRY_AFTER), None);
+    }
+}
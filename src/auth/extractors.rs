@@ -52,18 +52,18 @@ where
         let token = parts
             .headers
             .get(header::AUTHORIZATION)
-            .ok_or(AuthError::Unauthorized)?
+            .ok_or(AuthError::MissingCredentials)?
             .to_str()
             .map_err(|_| AuthError::Unauthorized)?;
 
         // Validate Bearer prefix
         if !token.starts_with("Bearer ") {
-            return Err(AuthError::Unauthorized);
+            return Err(AuthError::MissingCredentials);
         }
         let token = &token[7..]; // Skip "Bearer " prefix
 
         // Verify the token
-        let claims = auth_state.jwt_auth().verify(token)?;
+        let claims = auth_state.jwt_auth().verify(token).await?;
 
         // Extract user ID from claims
         let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AuthError::Unauthorized)?;
@@ -103,3 +103,81 @@ where
         Ok(Self(auth.user_id))
     }
 }
+
+/// Extractor that requires the authenticated user to hold a role
+///
+/// The required role is read from a `RequireRole` request extension, so routes
+/// are protected by layering `Extension(RequireRole("admin"))` onto the router.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireRole(pub &'static str);
+
+impl RequireRole {
+    /// Check whether the claims contain the required role
+    pub fn is_satisfied_by(&self, claims: &Claims) -> bool {
+        claims
+            .roles
+            .as_ref()
+            .is_some_and(|roles| roles.iter().any(|role| role == self.0))
+    }
+}
+
+impl<S> FromRequestParts<S> for RequireRole
+where
+    S: Send + Sync,
+    Auth: FromRequestParts<S, Rejection = AuthError>,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let required = parts
+            .extensions
+            .get::<RequireRole>()
+            .copied()
+            .ok_or_else(|| {
+                AuthError::InternalError(anyhow::anyhow!("No required role configured for route"))
+            })?;
+
+        // Authenticate first so missing tokens are reported as 401
+        let auth = Auth::from_request_parts(parts, state).await?;
+
+        if !required.is_satisfied_by(&auth.claims) {
+            tracing::warn!("User {} lacks required role: {}", auth.user_id, required.0);
+            return Err(AuthError::Forbidden);
+        }
+
+        Ok(required)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use uuid::Uuid;
+
+    use crate::test_support::{self, send};
+
+    async fn list_games_as(roles: &[&str]) -> StatusCode {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+
+        let mut claims = test_support::claims(Uuid::new_v4());
+        claims.roles = Some(roles.iter().map(|role| role.to_string()).collect());
+        let token = test_support::token(&claims);
+
+        let (status, _) = send(
+            &router,
+            Method::GET,
+            "/api/v1/admin/games",
+            Some(&token),
+            None,
+        )
+        .await;
+        status
+    }
+
+    #[tokio::test]
+    async fn admin_routes_require_the_admin_role() {
+        assert_eq!(list_games_as(&["user"]).await, StatusCode::FORBIDDEN);
+        assert_eq!(list_games_as(&["admin"]).await, StatusCode::OK);
+    }
+}
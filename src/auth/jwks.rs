@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::jwk::{AlgorithmParameters, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey};
+use parking_lot::RwLock;
+use tokio::sync::Mutex;
+
+use crate::auth::error::{AuthError, Result};
+
+/// Shortest wait between refreshes triggered by unknown key IDs
+const REFRESH_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Longest wait for the JWKS endpoint to respond
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Verification key published in a JWKS
+#[derive(Clone)]
+pub struct JwksKey {
+    /// Key for JWT signature verification
+    pub key: DecodingKey,
+
+    /// Algorithm the key is restricted to, if the JWK names one
+    pub algorithm: Option<Algorithm>,
+}
+
+/// Keys fetched from a JWKS endpoint, cached by key ID
+pub struct JwksCache {
+    /// URL of the JWKS document
+    url: String,
+
+    /// HTTP client used to fetch the JWKS
+    client: reqwest::Client,
+
+    /// Cached keys by `kid`
+    keys: RwLock<HashMap<String, JwksKey>>,
+
+    /// When the keys were last fetched, also serializing refreshes
+    last_refresh: Mutex<Option<Instant>>,
+}
+
+impl JwksCache {
+    /// Create an empty cache for the JWKS at `url`
+    pub fn new(url: String) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .map_err(|e| AuthError::InternalError(anyhow::anyhow!("Invalid JWKS client: {}", e)))?;
+
+        Ok(Self {
+            url,
+            client,
+            keys: RwLock::new(HashMap::new()),
+            last_refresh: Mutex::new(None),
+        })
+    }
+
+    /// Fetch the JWKS and replace the cached keys, returning how many were loaded
+    pub async fn refresh(&self) -> Result<usize> {
+        let mut last_refresh = self.last_refresh.lock().await;
+        *last_refresh = Some(Instant::now());
+        self.fetch().await
+    }
+
+    /// Look up the key for a `kid`, refreshing the cache if it is unknown
+    ///
+    /// Unknown IDs trigger at most one refresh per cooldown period, so tokens
+    /// with made-up IDs can't be used to hammer the JWKS endpoint.
+    pub async fn key(&self, kid: &str) -> Option<JwksKey> {
+        if let Some(key) = self.keys.read().get(kid) {
+            return Some(key.clone());
+        }
+
+        let mut last_refresh = self.last_refresh.lock().await;
+
+        // Another request may have refreshed while we waited for the lock
+        if let Some(key) = self.keys.read().get(kid) {
+            return Some(key.clone());
+        }
+        if last_refresh.is_some_and(|at| at.elapsed() < REFRESH_COOLDOWN) {
+            return None;
+        }
+
+        *last_refresh = Some(Instant::now());
+        if let Err(e) = self.fetch().await {
+            tracing::warn!("Failed to refresh JWKS: {}", e);
+        }
+
+        self.keys.read().get(kid).cloned()
+    }
+
+    /// Fetch the JWKS document and store its usable keys
+    async fn fetch(&self) -> Result<usize> {
+        let jwks: JwkSet = self
+            .client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AuthError::InternalError(anyhow::anyhow!("Failed to fetch JWKS: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| AuthError::InternalError(anyhow::anyhow!("Invalid JWKS: {}", e)))?;
+
+        let mut keys = HashMap::new();
+        for jwk in &jwks.keys {
+            // Symmetric keys have no place in a public key set
+            if matches!(jwk.algorithm, AlgorithmParameters::OctetKey(_)) {
+                continue;
+            }
+            let Some(kid) = jwk.common.key_id.clone() else {
+                continue;
+            };
+
+            let key = match DecodingKey::from_jwk(jwk) {
+                Ok(key) => key,
+                Err(e) => {
+                    tracing::warn!("Skipping JWKS key {}: {}", kid, e);
+                    continue;
+                }
+            };
+            let algorithm = jwk
+                .common
+                .key_algorithm
+                .and_then(|alg| Algorithm::from_str(&alg.to_string()).ok());
+
+            keys.insert(kid, JwksKey { key, algorithm });
+        }
+
+        let count = keys.len();
+        *self.keys.write() = keys;
+        tracing::debug!("Loaded {} keys from JWKS {}", count, self.url);
+
+        Ok(count)
+    }
+}
@@ -13,25 +13,55 @@ use crate::auth::state::AuthState;
 /// Authentication middleware for protected routes
 pub async fn auth_middleware(
     State(state): State<Arc<AuthState>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    authenticate(&state, request, next, 0).await
+}
+
+/// Authentication middleware for read-only routes
+///
+/// Accepts tokens that expired within the configured read grace period.
+pub async fn read_only_auth_middleware(
+    State(state): State<Arc<AuthState>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    let grace_secs = state.read_grace_secs();
+    authenticate(&state, request, next, grace_secs).await
+}
+
+/// Verify the request's token and run the next handler with its claims
+async fn authenticate(
+    state: &AuthState,
     mut request: Request,
     next: Next,
+    grace_secs: u64,
 ) -> Result<Response, AuthError> {
     // Extract the token from the Authorization header
-    let token = request
+    let header = request
         .headers()
         .get(header::AUTHORIZATION)
-        .ok_or(AuthError::Unauthorized)?
-        .to_str()
-        .map_err(|_| AuthError::Unauthorized)?;
+        .ok_or(AuthError::MissingCredentials)?;
+
+    // Reject oversized headers before doing any decoding work
+    if header.len() > state.max_header_bytes() {
+        return Err(AuthError::HeaderTooLarge);
+    }
+
+    let token = header.to_str().map_err(|_| AuthError::Unauthorized)?;
 
     // Validate Bearer prefix
     if !token.starts_with("Bearer ") {
-        return Err(AuthError::Unauthorized);
+        return Err(AuthError::MissingCredentials);
     }
     let token = &token[7..]; // Skip "Bearer " prefix
 
     // Verify the token
-    let claims = state.jwt_auth().verify(token)?;
+    let claims = state
+        .jwt_auth()
+        .verify_with_grace(token, grace_secs)
+        .await?;
 
     // Extract user ID from claims
     let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AuthError::Unauthorized)?;
@@ -52,7 +82,7 @@ pub async fn auth_middleware(
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// let app = Router::new()
 ///     .route("/public", get(public_handler))
 ///     .nest(
@@ -65,3 +95,41 @@ pub async fn auth_middleware(
 pub fn require_auth(state: Arc<AuthState>) -> impl Clone {
     middleware::from_fn_with_state::<_, _, Request>(state, auth_middleware)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::http::{Method, StatusCode};
+    use axum::routing::get;
+
+    use super::*;
+    use crate::test_support::{self, send};
+
+    /// Router with one protected route, accepting headers up to 512 bytes
+    fn protected_router() -> Router {
+        let mut config = test_support::config();
+        config.jwt.max_header_bytes = 512;
+        let state = test_support::app_state(config);
+
+        Router::new()
+            .route("/protected", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(
+                state.auth.clone(),
+                auth_middleware,
+            ))
+    }
+
+    #[tokio::test]
+    async fn oversized_bearer_is_rejected_before_verification() {
+        let router = protected_router();
+
+        let oversized = "a".repeat(600);
+        let (status, _) = send(&router, Method::GET, "/protected", Some(&oversized), None).await;
+        assert_eq!(status, StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE);
+
+        let token = test_support::token(&test_support::claims(Uuid::new_v4()));
+        assert!(token.len() < 512);
+        let (status, _) = send(&router, Method::GET, "/protected", Some(&token), None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
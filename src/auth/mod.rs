@@ -1,11 +1,15 @@
 pub mod error;
 pub mod extractors;
+#[cfg(feature = "jwks")]
+pub mod jwks;
 pub mod jwt;
 mod middleware;
 pub mod models;
+#[cfg(feature = "local_auth")]
+pub mod password;
 pub mod state;
 
 pub use error::AuthError;
-pub use extractors::{Auth, AuthUserId};
-pub use middleware::{auth_middleware, require_auth};
+pub use extractors::{Auth, AuthUserId, RequireRole};
+pub use middleware::{auth_middleware, read_only_auth_middleware, require_auth};
 pub use state::AuthState;
@@ -1,7 +1,16 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::auth::error::UsernameError;
+
+/// Maximum number of characters in a username
+pub const MAX_USERNAME_LENGTH: usize = 64;
+
+/// Punctuation allowed in usernames besides letters and digits
+const USERNAME_PUNCTUATION: &[char] = &[' ', '_', '-', '.', '@', '+', '\''];
+
 /// User model for storing game-related information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -17,8 +26,32 @@ pub struct User {
     /// When the user was last updated
     pub updated_at: DateTime<Utc>,
 
-    /// ID of today's game for this user (if exists)
-    pub current_game_id: Option<Uuid>,
+    /// IDs of today's games for this user, keyed by word length
+    #[serde(default, deserialize_with = "deserialize_game_ids")]
+    pub current_game_ids: HashMap<usize, Uuid>,
+
+    /// Argon2 hash of the password for local accounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_hash: Option<String>,
+}
+
+/// Deserialize game IDs keyed by word length from a map with string keys
+///
+/// Integer keys can't be read back from buffered content such as an
+/// internally tagged enum, so the keys are parsed explicitly.
+fn deserialize_game_ids<'de, D>(deserializer: D) -> Result<HashMap<usize, Uuid>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, Uuid>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(length, id)| {
+            length
+                .parse()
+                .map(|length| (length, id))
+                .map_err(serde::de::Error::custom)
+        })
+        .collect()
 }
 
 /// JWT Claims structure for token verification
@@ -36,6 +69,10 @@ pub struct Claims {
     /// Expiration timestamp
     pub exp: usize,
 
+    /// Not-before timestamp (Optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<usize>,
+
     /// Issuer (Optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iss: Option<String>,
@@ -63,7 +100,9 @@ pub struct UserResponse {
     pub id: Uuid,
     pub username: String,
     pub created_at: DateTime<Utc>,
+    /// Today's game at the server's word length, if one was started
     pub current_game_id: Option<Uuid>,
+    pub current_game_ids: HashMap<usize, Uuid>,
 }
 
 impl From<User> for UserResponse {
@@ -72,13 +111,51 @@ impl From<User> for UserResponse {
             id: user.id,
             username: user.username,
             created_at: user.created_at,
-            current_game_id: user.current_game_id,
+            current_game_id: None,
+            current_game_ids: user.current_game_ids,
         }
     }
 }
 
 impl User {
-    /// Create a new user record from token information
+    /// Create a new user record from token information, validating the username
+    pub fn try_new(user_id: Uuid, username: String) -> Result<Self, UsernameError> {
+        Self::validate_username(&username)?;
+        Ok(Self::new(user_id, username))
+    }
+
+    /// Check that a username is 1 to `MAX_USERNAME_LENGTH` letters, digits or
+    /// common punctuation, without surrounding whitespace
+    pub fn validate_username(username: &str) -> Result<(), UsernameError> {
+        if username.trim().is_empty() {
+            return Err(UsernameError::Empty);
+        }
+
+        let length = username.chars().count();
+        if length > MAX_USERNAME_LENGTH {
+            return Err(UsernameError::TooLong {
+                max: MAX_USERNAME_LENGTH,
+                actual: length,
+            });
+        }
+
+        // Surrounding whitespace would make look-alike usernames
+        let edges = [username.chars().next(), username.chars().last()];
+        if let Some(c) = edges.into_iter().flatten().find(|c| c.is_whitespace()) {
+            return Err(UsernameError::InvalidCharacter(c));
+        }
+
+        if let Some(c) = username
+            .chars()
+            .find(|c| !c.is_alphanumeric() && !USERNAME_PUNCTUATION.contains(c))
+        {
+            return Err(UsernameError::InvalidCharacter(c));
+        }
+
+        Ok(())
+    }
+
+    /// Create a new user record without validating the username
     pub fn new(user_id: Uuid, username: String) -> Self {
         let now = Utc::now();
 
@@ -87,7 +164,27 @@ impl User {
             username,
             created_at: now,
             updated_at: now,
-            current_game_id: None,
+            current_game_ids: HashMap::new(),
+            password_hash: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_usernames_are_rejected() {
+        assert!(User::try_new(Uuid::new_v4(), "wordle_fan-42".to_string()).is_ok());
+
+        let long = "a".repeat(100);
+        assert!(matches!(
+            User::try_new(Uuid::new_v4(), long),
+            Err(UsernameError::TooLong {
+                max: MAX_USERNAME_LENGTH,
+                actual: 100
+            })
+        ));
+    }
+}
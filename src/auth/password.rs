@@ -0,0 +1,40 @@
+use argon2::Argon2;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use uuid::Uuid;
+
+use crate::auth::error::{AuthError, Result};
+
+/// Minimum length of a local account password in characters
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Failed logins allowed per account per minute before further attempts are refused
+pub const MAX_FAILED_LOGINS_PER_MINUTE: u32 = 5;
+
+/// Namespace for deriving local account user IDs from usernames
+const LOCAL_USER_NAMESPACE: Uuid = Uuid::from_u128(0x6c8f_3b2e_4a1d_5e90_b7c2_19d4_e0a3_f5b1);
+
+/// Derive the user ID of a local account from its username
+///
+/// Usernames are case-insensitive, and deriving the ID lets a login find
+/// the account with a plain user lookup.
+pub fn local_user_id(username: &str) -> Uuid {
+    Uuid::new_v5(&LOCAL_USER_NAMESPACE, username.to_lowercase().as_bytes())
+}
+
+/// Hash a password with Argon2 and a random salt
+pub fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())
+        .map_err(|e| AuthError::InternalError(anyhow::anyhow!("Invalid salt: {}", e)))?;
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AuthError::InternalError(anyhow::anyhow!("Failed to hash password: {}", e)))
+}
+
+/// Check a password against a stored Argon2 hash
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .and_then(|hash| Argon2::default().verify_password(password.as_bytes(), &hash))
+        .is_ok()
+}
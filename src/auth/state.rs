@@ -1,13 +1,22 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+#[cfg(feature = "local_auth")]
+use crate::auth::error::AuthError;
 use crate::auth::error::Result as AuthResult;
 use crate::auth::jwt::JwtAuth;
 use crate::auth::models::User;
+#[cfg(feature = "local_auth")]
+use crate::auth::password::{
+    MAX_FAILED_LOGINS_PER_MINUTE, hash_password, local_user_id, verify_password,
+};
 use crate::common::config::JwtConfig;
 use crate::repository::UserRepositoryTrait;
+use crate::repository::deadline::with_deadline;
 use crate::repository::error::RepositoryError;
 use crate::repository::error::RepositoryResult;
+#[cfg(feature = "local_auth")]
+use crate::server::RateLimiter;
 
 /// Auth state that will be shared across routes
 pub struct AuthState {
@@ -16,6 +25,16 @@ pub struct AuthState {
 
     /// JWT authentication service
     jwt_auth: JwtAuth,
+
+    /// Maximum accepted size of the Authorization header in bytes
+    max_header_bytes: usize,
+
+    /// Seconds after expiry during which tokens are accepted on read-only routes
+    read_grace_secs: u64,
+
+    /// Failed password logins per local account
+    #[cfg(feature = "local_auth")]
+    failed_logins: RateLimiter<Uuid>,
 }
 
 impl AuthState {
@@ -29,9 +48,23 @@ impl AuthState {
         Ok(Self {
             user_repository,
             jwt_auth,
+            max_header_bytes: jwt_config.max_header_bytes,
+            read_grace_secs: jwt_config.read_grace_secs,
+            #[cfg(feature = "local_auth")]
+            failed_logins: RateLimiter::new(MAX_FAILED_LOGINS_PER_MINUTE),
         })
     }
 
+    /// Get the expiry grace period for read-only routes in seconds
+    pub fn read_grace_secs(&self) -> u64 {
+        self.read_grace_secs
+    }
+
+    /// Get the maximum accepted size of the Authorization header in bytes
+    pub fn max_header_bytes(&self) -> usize {
+        self.max_header_bytes
+    }
+
     /// Get the JWT authentication service
     pub fn jwt_auth(&self) -> &JwtAuth {
         &self.jwt_auth
@@ -44,27 +77,160 @@ impl AuthState {
 
     /// Get a user by ID
     pub async fn get_user(&self, id: &Uuid) -> RepositoryResult<User> {
-        self.user_repository.get_user(id).await
+        with_deadline(self.user_repository.get_user(id)).await
     }
 
     /// Save a user
     pub async fn save_user(&self, user: User) -> RepositoryResult<()> {
-        self.user_repository.save_user(user).await
+        with_deadline(self.user_repository.save_user(user)).await
     }
 
-    /// Update a user's current game
-    pub async fn update_user_game(&self, user_id: &Uuid, game_id: Uuid) -> RepositoryResult<bool> {
-        self.user_repository
-            .update_user_game(user_id, game_id)
-            .await
+    /// Delete a user, returning whether the user existed
+    pub async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool> {
+        with_deadline(self.user_repository.delete_user(id)).await
+    }
+
+    /// Update a user's current game for a word length
+    pub async fn update_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        with_deadline(
+            self.user_repository
+                .update_user_game(user_id, word_length, game_id),
+        )
+        .await
     }
 
-    /// Get the current game ID for a user
-    pub async fn get_current_user_game_id(&self, user_id: &Uuid) -> RepositoryResult<Option<Uuid>> {
-        match self.user_repository.get_user(user_id).await {
-            Ok(user) => Ok(user.current_game_id),
+    /// Clear a user's current game for a word length
+    pub async fn clear_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+    ) -> RepositoryResult<bool> {
+        with_deadline(self.user_repository.clear_user_game(user_id, word_length)).await
+    }
+
+    /// Count a game created by a user today, returning the user's count so far
+    pub async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32> {
+        with_deadline(
+            self.user_repository
+                .increment_and_get_daily_creates(user_id),
+        )
+        .await
+    }
+
+    /// Get a user's current game ID for a word length
+    pub async fn get_current_user_game_id(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+    ) -> RepositoryResult<Option<Uuid>> {
+        match self.get_user(user_id).await {
+            Ok(user) => Ok(user.current_game_ids.get(&word_length).copied()),
             Err(RepositoryError::NotFound) => Ok(None),
             Err(err) => Err(err),
         }
     }
+
+    /// Register a local account with a password
+    #[cfg(feature = "local_auth")]
+    pub async fn register(&self, username: &str, password: &str) -> AuthResult<User> {
+        let id = local_user_id(username);
+        match self.get_user(&id).await {
+            Ok(_) => return Err(AuthError::UsernameTaken),
+            Err(RepositoryError::NotFound) => {}
+            Err(e) => return Err(anyhow::Error::from(e).into()),
+        }
+
+        let mut user = User::try_new(id, username.to_string())?;
+        user.password_hash = Some(hash_password(password)?);
+        self.save_user(user.clone())
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        Ok(user)
+    }
+
+    /// Check a local account's password, returning the account on success
+    ///
+    /// After `MAX_FAILED_LOGINS_PER_MINUTE` failures, further attempts on the
+    /// account fail with `AuthError::TooManyAttempts` until the minute is over.
+    #[cfg(feature = "local_auth")]
+    pub async fn authenticate(&self, username: &str, password: &str) -> AuthResult<User> {
+        let id = local_user_id(username);
+        if let Some(delay) = self.failed_logins.retry_after(&id) {
+            return Err(AuthError::TooManyAttempts(delay));
+        }
+
+        let user = match self.get_user(&id).await {
+            Ok(user) => Some(user),
+            Err(RepositoryError::NotFound) => None,
+            Err(e) => return Err(anyhow::Error::from(e).into()),
+        }
+        .filter(|user| {
+            user.password_hash
+                .as_deref()
+                .is_some_and(|hash| verify_password(password, hash))
+        });
+
+        user.ok_or_else(|| {
+            self.failed_logins.check(id);
+            AuthError::Unauthorized
+        })
+    }
+}
+
+#[cfg(all(test, feature = "local_auth"))]
+mod tests {
+    use super::*;
+    use crate::repository::memory::InMemoryUserRepository;
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn password_login_checks_the_password() {
+        let mut config = test_support::config().jwt;
+        config.allow_local_issuance = true;
+        config.signing_key = Some(test_support::TEST_SECRET.to_string());
+        let state = AuthState::new(Arc::new(InMemoryUserRepository::new()), &config).unwrap();
+        let registered = state.register("alice", "correct horse").await.unwrap();
+
+        let user = state.authenticate("alice", "correct horse").await.unwrap();
+        assert_eq!(user.id, registered.id);
+        let token = state.jwt_auth().issue(user.id, &user.username).unwrap();
+        assert_eq!(
+            state.jwt_auth().verify(&token).await.unwrap().sub,
+            user.id.to_string()
+        );
+
+        assert!(matches!(
+            state.authenticate("alice", "wrong password").await,
+            Err(AuthError::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn repeated_failed_logins_are_throttled() {
+        let state = AuthState::new(
+            Arc::new(InMemoryUserRepository::new()),
+            &test_support::config().jwt,
+        )
+        .unwrap();
+        state.register("alice", "correct horse").await.unwrap();
+
+        for _ in 0..MAX_FAILED_LOGINS_PER_MINUTE {
+            assert!(matches!(
+                state.authenticate("alice", "wrong password").await,
+                Err(AuthError::Unauthorized)
+            ));
+        }
+
+        // Even the right password is refused until the window ends
+        assert!(matches!(
+            state.authenticate("alice", "correct horse").await,
+            Err(AuthError::TooManyAttempts(_))
+        ));
+    }
 }
@@ -3,15 +3,20 @@ use clap::{Parser, Subcommand};
 use colored::*;
 use dialoguer::Input;
 use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
-use reqwest::{Client, ClientBuilder, StatusCode};
+use reqwest::{Certificate, Client, ClientBuilder, StatusCode};
 use serde::{Deserialize, Serialize};
 use spinners::{Spinner, Spinners};
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 // Import from our library - only what we actually use
-use wordle::{APP_VERSION, game::models::LetterResult as GameLetterResult};
+use wordle::{
+    APP_VERSION,
+    api::API_PREFIX,
+    game::models::{LetterResult as GameLetterResult, MastermindResult},
+};
 
 /// CLI client for testing Wordle REST API server
 #[derive(Parser, Debug)]
@@ -21,6 +26,14 @@ struct Cli {
     #[clap(short, long, default_value = "https://localhost:3000")]
     api_url: String,
 
+    /// Accept invalid TLS certificates, such as a local server's self-signed one
+    #[clap(long)]
+    insecure: bool,
+
+    /// PEM file with an extra root certificate to trust
+    #[clap(long, value_name = "PATH")]
+    ca_cert: Option<PathBuf>,
+
     /// Command to execute
     #[clap(subcommand)]
     command: Commands,
@@ -62,6 +75,16 @@ enum Commands {
         #[clap(short, long)]
         game_id: Option<String>,
     },
+
+    /// List past games with their results
+    History {
+        /// Maximum number of games to list
+        #[clap(short, long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Delete the stored configuration and token
+    Reset {},
 }
 
 /// Configuration for storing settings and auth token
@@ -75,8 +98,6 @@ struct Config {
     user_id: Option<String>,
     /// Username
     username: Option<String>,
-    /// Current active game ID
-    current_game_id: Option<String>,
 }
 
 impl Default for Config {
@@ -86,24 +107,33 @@ impl Default for Config {
             token: None,
             user_id: None,
             username: None,
-            current_game_id: None,
         }
     }
 }
 
 impl Config {
-    /// Loads the configuration from the config file
+    /// Gets the path of the config file
     ///
     /// # Returns
     ///
-    /// A Result containing the loaded Config or an error
-    fn load() -> Result<Self> {
-        // Find config directory
+    /// A Result containing the config file path or an error
+    fn path() -> Result<PathBuf> {
         let mut config_path =
             dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
         config_path.push("wordle-cli");
         config_path.push("config.json");
 
+        Ok(config_path)
+    }
+
+    /// Loads the configuration from the config file
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the loaded Config or an error
+    fn load() -> Result<Self> {
+        let config_path = Self::path()?;
+
         // Try to load config, or create default
         if config_path.exists() {
             let config_str = std::fs::read_to_string(&config_path)?;
@@ -130,10 +160,7 @@ impl Config {
     ///
     /// A Result indicating success or an error
     fn save(&self) -> Result<()> {
-        let mut config_path =
-            dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-        config_path.push("wordle-cli");
-        config_path.push("config.json");
+        let config_path = Self::path()?;
 
         // Ensure parent directories exist
         if let Some(parent) = config_path.parent() {
@@ -145,6 +172,19 @@ impl Config {
 
         Ok(())
     }
+
+    /// Deletes the config file
+    ///
+    /// # Returns
+    ///
+    /// A Result containing whether a config file existed, or an error
+    fn delete() -> Result<bool> {
+        match fs::remove_file(Self::path()?) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 /// JWT Claims structure
@@ -158,6 +198,9 @@ struct Claims {
     iat: usize,
     /// Expiration timestamp
     exp: usize,
+    /// Not-before timestamp (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nbf: Option<usize>,
     /// Issuer (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     iss: Option<String>,
@@ -189,17 +232,46 @@ struct GuessRequest {
 struct GuessResponse {
     pub word: String,
     pub results: Vec<GameLetterResult>,
+    #[serde(default)]
+    pub score: Option<MastermindResult>,
 }
 
 // Define a local GameResponse that maps to the API version
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct GameResponse {
     pub id: String,
+    #[serde(default = "default_word_length")]
+    pub word_length: usize,
     pub attempts_remaining: u8,
     pub completed: bool,
     pub won: bool,
     pub word: Option<String>,
     pub guesses: Vec<GuessResponse>,
+    #[serde(default)]
+    pub score: Option<u32>,
+}
+
+// A past game as listed by the history endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HistoryEntry {
+    pub id: String,
+    pub date: String,
+    pub completed: bool,
+    pub won: bool,
+    pub guess_count: usize,
+    pub word: Option<String>,
+    #[serde(default)]
+    pub guesses: Vec<GuessResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HistoryResponse {
+    pub games: Vec<HistoryEntry>,
+}
+
+/// Word length assumed when the server does not report one
+fn default_word_length() -> usize {
+    5
 }
 
 /// Display a single guess with color-coded results
@@ -208,16 +280,100 @@ struct GameResponse {
 ///
 /// * `guess` - The guess to display
 fn display_guess(guess: &GuessResponse) {
-    for (idx, letter_result) in guess.results.iter().enumerate() {
-        let letter = guess.word.chars().nth(idx).unwrap_or('?');
+    // Results are withheld for guesses made in blind mode, and replaced by
+    // counts under Mastermind scoring
+    if guess.results.is_empty() {
+        for letter in guess.word.chars() {
+            print!("{} ", letter.to_string().dimmed());
+        }
+        if let Some(score) = guess.score {
+            print!(
+                " {} exact, {} misplaced",
+                score.exact.to_string().green().bold(),
+                score.misplaced.to_string().yellow().bold()
+            );
+        }
+        println!();
+        return;
+    }
 
-        let colored_letter = match letter_result {
-            GameLetterResult::Correct => letter.to_string().green().bold(),
-            GameLetterResult::WrongPosition => letter.to_string().yellow().bold(),
-            GameLetterResult::Wrong => letter.to_string().red(),
-        };
+    // Results are per character, so pair them with chars rather than bytes
+    for (letter, letter_result) in guess.word.chars().zip(&guess.results) {
+        print!("{} ", color_letter(&letter.to_string(), letter_result));
+    }
+    println!();
+}
+
+/// Color text by a letter result: green, yellow or red
+///
+/// # Arguments
+///
+/// * `text` - The text to color
+/// * `result` - The result deciding the color
+fn color_letter(text: &str, result: &GameLetterResult) -> ColoredString {
+    match result {
+        GameLetterResult::Correct => text.green().bold(),
+        GameLetterResult::WrongPosition => text.yellow().bold(),
+        GameLetterResult::Wrong => text.red(),
+    }
+}
+
+/// Format a guess as a row of result squares, e.g. `🟩🟨⬛⬛🟩`
+///
+/// # Arguments
+///
+/// * `guess` - The guess to format
+///
+/// # Returns
+///
+/// The row, or dots if the guess's results are hidden
+fn emoji_row(guess: &GuessResponse) -> String {
+    if guess.results.is_empty() {
+        return "·".repeat(guess.word.chars().count());
+    }
+
+    guess
+        .results
+        .iter()
+        .map(|result| match result {
+            GameLetterResult::Correct => "🟩",
+            GameLetterResult::WrongPosition => "🟨",
+            GameLetterResult::Wrong => "⬛",
+        })
+        .collect()
+}
 
-        print!("{} ", colored_letter);
+/// Display a past game with its emoji grid
+///
+/// # Arguments
+///
+/// * `entry` - The game to display
+fn display_history_entry(entry: &HistoryEntry) {
+    let outcome = if !entry.completed {
+        "in progress".blue()
+    } else if entry.won {
+        format!("won in {}", entry.guess_count).green().bold()
+    } else {
+        "lost".red().bold()
+    };
+    print!("{}  {}", entry.date, outcome);
+    if let Some(word) = &entry.word {
+        print!("  {}", word.yellow().bold());
+    }
+    println!();
+
+    for guess in &entry.guesses {
+        let word = if guess.results.is_empty() {
+            guess.word.dimmed().to_string()
+        } else {
+            guess
+                .word
+                .chars()
+                .zip(&guess.results)
+                .map(|(letter, result)| color_letter(&letter.to_string(), result).to_string())
+                .collect()
+        };
+        println!("  {}  {}", emoji_row(guess), word);
     }
     println!();
 }
@@ -242,6 +398,9 @@ fn display_game(game: &GameResponse) {
     if game.completed {
         if game.won {
             println!("{}", "Congratulations! You won!".green().bold());
+            if let Some(score) = game.score {
+                println!("Score: {}", score.to_string().bold());
+            }
         } else {
             println!("{}", "Game over! You lost.".red().bold());
         }
@@ -276,8 +435,9 @@ fn generate_token(username: &str) -> Result<(String, String)> {
         sub: user_id.clone(),
         username: username.to_string(),
         iat: now,
-        exp: now + 60 * 60 * 24,                       // 24 hours
-        iss: Some("auth-service".to_string()),         // Match server configuration
+        exp: now + 60 * 60 * 24, // 24 hours
+        nbf: None,
+        iss: Some("auth-service".to_string()), // Match server configuration
         aud: Some(vec!["wordle-service".to_string()]), // Match server configuration
         roles: Some(vec!["user".to_string()]),
         email: None,
@@ -314,11 +474,13 @@ impl WordleApi {
     /// # Arguments
     ///
     /// * `api_url` - Optional API URL to override the one in config
+    /// * `insecure` - Whether to accept invalid TLS certificates
+    /// * `ca_cert` - Optional PEM file with an extra root certificate to trust
     ///
     /// # Returns
     ///
     /// A Result containing the WordleApi instance or an error
-    fn new(api_url: Option<String>) -> Result<Self> {
+    fn new(api_url: Option<String>, insecure: bool, ca_cert: Option<&Path>) -> Result<Self> {
         // Load config
         let mut config = Config::load()?;
 
@@ -329,12 +491,31 @@ impl WordleApi {
         }
 
         // Create HTTP client with HTTP/2 support using rustls
-        // Also disable certificate verification for testing purposes
-        let client = ClientBuilder::new()
+        let mut builder = ClientBuilder::new()
             .timeout(Duration::from_secs(30))
-            .use_rustls_tls() // Use rustls TLS implementation with HTTP/2 support
-            .danger_accept_invalid_certs(true) // Accept self-signed certificates
-            .build()?;
+            .use_rustls_tls(); // Use rustls TLS implementation with HTTP/2 support
+
+        // Trust an extra root, e.g. the CA that signed a test server's certificate
+        if let Some(path) = ca_cert {
+            let pem = fs::read(path).map_err(|e| {
+                anyhow::anyhow!("Failed to read CA certificate {}: {}", path.display(), e)
+            })?;
+            builder = builder.add_root_certificate(Certificate::from_pem(&pem)?);
+        }
+
+        // Skip certificate verification only when explicitly asked to
+        if insecure {
+            eprintln!(
+                "{}",
+                "Warning: TLS certificate verification is disabled (--insecure). \
+                 Never use this against a production server."
+                    .yellow()
+                    .bold()
+            );
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        let client = builder.build()?;
 
         Ok(Self { client, config })
     }
@@ -388,7 +569,7 @@ impl WordleApi {
     async fn new_game(&mut self) -> Result<GameResponse> {
         self.ensure_auth()?;
 
-        let url = format!("{}/api/game/new", self.config.api_url);
+        let url = format!("{}{}/game/new", self.config.api_url, API_PREFIX);
         let resp = self
             .client
             .post(&url)
@@ -409,11 +590,6 @@ impl WordleApi {
 
         if resp.status().is_success() {
             let game: GameResponse = resp.json().await?;
-
-            // Update current game ID in config
-            self.config.current_game_id = Some(game.id.clone());
-            self.config.save()?;
-
             Ok(game)
         } else {
             let status = resp.status();
@@ -430,7 +606,7 @@ impl WordleApi {
     ///
     /// # Arguments
     ///
-    /// * `game_id` - Optional game ID, uses current game from config if None
+    /// * `game_id` - Optional game ID, uses today's game from the server if None
     ///
     /// # Returns
     ///
@@ -438,12 +614,10 @@ impl WordleApi {
     async fn get_game(&self, game_id: Option<String>) -> Result<GameResponse> {
         self.ensure_auth()?;
 
-        // Use provided game ID or current game from config
-        let game_id = game_id
-            .or_else(|| self.config.current_game_id.clone())
-            .ok_or_else(|| anyhow::anyhow!("No game ID provided or saved in config"))?;
+        // Use provided game ID or ask the server for today's game
+        let game_id = game_id.unwrap_or_else(|| "current".to_string());
 
-        let url = format!("{}/api/game/{}", self.config.api_url, game_id);
+        let url = format!("{}{}/game/{}", self.config.api_url, API_PREFIX, game_id);
         let resp = self
             .client
             .get(&url)
@@ -473,7 +647,7 @@ impl WordleApi {
     /// # Arguments
     ///
     /// * `word` - The word to guess
-    /// * `game_id` - Optional game ID, uses current game from config if None
+    /// * `game_id` - Optional game ID, uses today's game from the server if None
     ///
     /// # Returns
     ///
@@ -481,12 +655,16 @@ impl WordleApi {
     async fn make_guess(&self, word: String, game_id: Option<String>) -> Result<GameResponse> {
         self.ensure_auth()?;
 
-        // Use provided game ID or current game from config
-        let game_id = game_id
-            .or_else(|| self.config.current_game_id.clone())
-            .ok_or_else(|| anyhow::anyhow!("No game ID provided or saved in config"))?;
+        // Use provided game ID or look up today's game on the server
+        let game_id = match game_id {
+            Some(game_id) => game_id,
+            None => self.get_game(None).await?.id,
+        };
 
-        let url = format!("{}/api/game/{}/guess", self.config.api_url, game_id);
+        let url = format!(
+            "{}{}/game/{}/guess",
+            self.config.api_url, API_PREFIX, game_id
+        );
         let resp = self
             .client
             .post(&url)
@@ -512,6 +690,46 @@ impl WordleApi {
         }
     }
 
+    /// Lists the user's past games, most recent first
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of games to list
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the games or an error
+    async fn history(&self, limit: usize) -> Result<Vec<HistoryEntry>> {
+        self.ensure_auth()?;
+
+        let url = format!(
+            "{}{}/game/history?limit={}&include_active=true",
+            self.config.api_url, API_PREFIX, limit
+        );
+        let resp = self
+            .client
+            .get(&url)
+            .header(
+                "Authorization",
+                format!("Bearer {}", self.config.token.as_ref().unwrap()),
+            )
+            .send()
+            .await?;
+
+        if resp.status().is_success() {
+            let history: HistoryResponse = resp.json().await?;
+            Ok(history.games)
+        } else {
+            let status = resp.status();
+            let error_text = resp.text().await?;
+            Err(anyhow::anyhow!(
+                "Failed to get history: {} - {}",
+                status,
+                error_text
+            ))
+        }
+    }
+
     /// Ensures the user is authenticated with a valid token
     ///
     /// # Returns
@@ -544,14 +762,19 @@ impl WordleApi {
 
         // Main game loop
         while !game.completed {
-            println!("\n{}", "Enter your guess (5 letters):".yellow());
+            let word_length = game.word_length;
+            println!(
+                "\n{}",
+                format!("Enter your guess ({} letters):", word_length).yellow()
+            );
 
             // Prompt for guess
+            let length_error = format!("Guess must be exactly {} letters", word_length);
             let guess: String = Input::new()
                 .with_prompt(">")
                 .validate_with(|input: &String| -> Result<(), &str> {
-                    if input.len() != 5 {
-                        Err("Guess must be exactly 5 letters")
+                    if input.chars().count() != word_length {
+                        Err(&length_error)
                     } else if !input.chars().all(|c| c.is_ascii_alphabetic()) {
                         Err("Guess must contain only letters")
                     } else {
@@ -587,8 +810,18 @@ async fn main() -> Result<()> {
     // Parse command line arguments
     let cli = Cli::parse();
 
+    // Reset before the client loads, and so recreates, the config
+    if let Commands::Reset {} = cli.command {
+        match Config::delete() {
+            Ok(true) => println!("{}", "Stored configuration and token deleted.".green()),
+            Ok(false) => println!("Nothing to reset."),
+            Err(e) => println!("{}: {}", "Reset failed".red(), e),
+        }
+        return Ok(());
+    }
+
     // Create API client
-    let mut api = WordleApi::new(Some(cli.api_url))?;
+    let mut api = WordleApi::new(Some(cli.api_url), cli.insecure, cli.ca_cert.as_deref())?;
 
     // Execute requested command
     match cli.command {
@@ -674,6 +907,36 @@ async fn main() -> Result<()> {
             Ok(_) => println!("{}", "Thanks for playing!".green().bold()),
             Err(e) => println!("{}: {}", "Game error".red(), e),
         },
+
+        Commands::History { limit } => {
+            if api.config.token.is_none() {
+                println!(
+                    "{}",
+                    "No token stored yet. Run `login` first to see your games.".yellow()
+                );
+                return Ok(());
+            }
+
+            let mut spinner = Spinner::new(Spinners::Dots, "Getting game history...".into());
+            match api.history(limit).await {
+                Ok(games) => {
+                    spinner.stop();
+                    println!();
+                    if games.is_empty() {
+                        println!("No games played yet.");
+                    }
+                    for game in &games {
+                        display_history_entry(game);
+                    }
+                }
+                Err(e) => {
+                    spinner.stop();
+                    println!("{}: {}", "Failed to get game history".red(), e);
+                }
+            }
+        }
+
+        Commands::Reset {} => unreachable!("handled before the client is created"),
     }
 
     Ok(())
@@ -1,20 +1,33 @@
+use std::fs::File;
+use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use axum::Router;
+use axum_server::Handle;
 use axum_server::tls_rustls::RustlsConfig;
 use rustls::crypto::ring;
+use rustls::version::{TLS12, TLS13};
+use rustls::{ServerConfig, SupportedProtocolVersion};
 use tokio::net::TcpListener;
-use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::{self, TraceLayer};
 use tracing::Level;
 
 use wordle::{
-    api, auth::AuthState, common::config::Config, game::GameState, init_logging, load_env,
+    api::{self, AppState},
+    auth::AuthState,
+    common::config::{Config, TlsConfig, TlsVersion},
+    game::GameState,
+    init_logging, load_env,
     repository::init_repositories,
+    server::{analytics::AnalyticsSink, metrics, reset},
 };
 
+/// Time allowed for in-flight requests to finish after a shutdown signal
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load environment variables
@@ -37,11 +50,24 @@ async fn main() -> Result<()> {
     let (game_repo, user_repo) = init_repositories(&config).await?;
 
     // Create game and auth states with repositories
-    let game_state = Arc::new(GameState::new(game_repo));
+    let mut game_state =
+        GameState::new(game_repo, &config.game)?.with_event_sink(Arc::new(metrics::MetricsSink));
+    if config.analytics_enabled {
+        game_state = game_state.with_event_sink(Arc::new(AnalyticsSink));
+    }
+    let game_state = Arc::new(game_state);
     let auth_state = Arc::new(AuthState::new(user_repo, &config.jwt)?);
 
+    // Fetch the JWKS up front; unknown keys are fetched again on demand
+    if config.jwt.jwks_url.is_some() {
+        match auth_state.jwt_auth().load_jwks().await {
+            Ok(count) => tracing::info!("Loaded {} keys from the JWKS", count),
+            Err(e) => tracing::warn!("Failed to load the JWKS, retrying on demand: {}", e),
+        }
+    }
+
     // Run the server
-    run(game_state, auth_state, &config).await?;
+    run(game_state, auth_state, Arc::new(config)).await?;
 
     Ok(())
 }
@@ -50,40 +76,65 @@ async fn main() -> Result<()> {
 pub async fn run(
     game_state: Arc<GameState>,
     auth_state: Arc<AuthState>,
-    config: &Config,
+    config: Arc<Config>,
 ) -> Result<()> {
+    // Install the metrics recorder before any requests are handled
+    #[cfg(feature = "metrics")]
+    metrics::install()?;
+
+    // Pick up the last daily reset so a restart after midnight doesn't skip one
+    if let Err(e) = game_state.restore_last_reset_date().await {
+        tracing::warn!("Failed to restore the last daily reset date: {}", e);
+    }
+
+    // Reset games at each daily boundary instead of waiting for the first request
+    let reset_task = reset::spawn_daily_reset(AppState::new(
+        game_state.clone(),
+        auth_state.clone(),
+        config.clone(),
+    ));
+
     // Build our application with routes
-    let app = build_router(game_state, auth_state);
+    let app = build_router(game_state, auth_state, config.clone());
 
     // Run the server
     let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
     tracing::info!("Listening on {}", addr);
 
-    if config.tls.enabled {
-        run_tls_server(app, addr, config).await?;
+    let result = if config.tls.enabled {
+        run_tls_server(app, addr, &config).await
     } else {
-        run_http_server(app, addr).await?;
-    }
+        run_http_server(app, addr).await
+    };
 
-    Ok(())
+    // Stop background work once the server has shut down
+    reset_task.abort();
+
+    result
 }
 
-/// Configure the application router with middleware
-fn build_router(game_state: Arc<GameState>, auth_state: Arc<AuthState>) -> Router {
-    // CORS configuration
-    let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+/// Wait for Ctrl+C to begin a graceful shutdown
+async fn shutdown_signal() {
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        tracing::error!("Failed to listen for the shutdown signal: {}", e);
+        std::future::pending::<()>().await;
+    }
+    tracing::info!("Shutdown signal received, shutting down");
+}
 
+/// Configure the application router with middleware
+fn build_router(
+    game_state: Arc<GameState>,
+    auth_state: Arc<AuthState>,
+    config: Arc<Config>,
+) -> Router {
     Router::new()
-        .merge(api::router(game_state.clone(), auth_state.clone()))
+        .merge(api::router(game_state, auth_state, config))
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
                 .on_response(trace::DefaultOnResponse::new().level(Level::INFO)),
         )
-        .layer(cors)
 }
 
 /// Run the server with TLS enabled
@@ -97,25 +148,61 @@ async fn run_tls_server(app: Router, addr: SocketAddr, config: &Config) -> Resul
     let _ = ring::default_provider().install_default();
 
     // Configure with TLS - using axum_server
-    let rustls_config = RustlsConfig::from_pem_file(&config.tls.cert_file, &config.tls.key_file)
-        .await
+    let server_config = tls_server_config(&config.tls)
         .map_err(|e| anyhow::anyhow!("Failed to load TLS config: {}", e))?;
+    tracing::info!("Minimum TLS version: {}", config.tls.min_version);
+    let rustls_config = RustlsConfig::from_config(Arc::new(server_config));
+
+    // Shut down gracefully on Ctrl+C
+    let handle = Handle::new();
+    let shutdown_handle = handle.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        shutdown_handle.graceful_shutdown(Some(SHUTDOWN_GRACE_PERIOD));
+    });
 
     // Run server with TLS and HTTP/2 support
     axum_server::bind_rustls(addr, rustls_config)
-        .serve(app.into_make_service())
+        .handle(handle)
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await?;
 
     Ok(())
 }
 
+/// Build the rustls server configuration, accepting only the allowed protocol versions
+fn tls_server_config(tls: &TlsConfig) -> Result<ServerConfig> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(&tls.cert_file)?))
+        .collect::<Result<Vec<_>, _>>()?;
+    let key = rustls_pemfile::private_key(&mut BufReader::new(File::open(&tls.key_file)?))?
+        .ok_or_else(|| anyhow::anyhow!("No private key found in {:?}", tls.key_file))?;
+
+    let versions: &[&SupportedProtocolVersion] = match tls.min_version {
+        TlsVersion::Tls12 => &[&TLS13, &TLS12],
+        TlsVersion::Tls13 => &[&TLS13],
+    };
+    let mut server_config = ServerConfig::builder_with_protocol_versions(versions)
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    // Offer HTTP/2 first, as RustlsConfig::from_pem_file does
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(server_config)
+}
+
 /// Run the server without TLS
 async fn run_http_server(app: Router, addr: SocketAddr) -> Result<()> {
     tracing::warn!("TLS is disabled - running without HTTPS or HTTP/2 support");
     tracing::warn!("HTTP/2 requires TLS in most browsers");
 
     let listener = TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     Ok(())
 }
@@ -1,12 +1,30 @@
 use anyhow::{Context, Result};
+use axum::http::HeaderValue;
+use chrono_tz::Tz;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::game::{MAX_ATTEMPTS_LIMIT, MAX_PUZZLES_PER_DAY};
+
+/// Shortest accepted HS256 secret in bytes, the size of the HMAC-SHA256 output
+pub const MIN_JWT_SECRET_BYTES: usize = 32;
+
+/// Fragments of secrets copied from examples or left at a default
+const PLACEHOLDER_SECRET_MARKERS: &[&str] = &[
+    "secret",
+    "changeme",
+    "change-me",
+    "change_me",
+    "example",
+    "default",
+    "password",
+];
+
 /// JWT authentication configuration
 #[derive(Debug, Clone)]
 pub struct JwtConfig {
-    /// JWT signature verification method ("secret", "rsa", or "ed25519")
+    /// JWT signature verification method ("secret", "rsa", "rs384", "rs512", "ps256", "es256", or "ed25519")
     pub auth_type: String,
 
     /// Public key value or file path
@@ -17,6 +35,73 @@ pub struct JwtConfig {
 
     /// Expected audience
     pub audience: String,
+
+    /// Whether the server may issue its own tokens
+    pub allow_local_issuance: bool,
+
+    /// Signing key value used to issue tokens (secret or private key PEM)
+    pub signing_key: Option<String>,
+
+    /// Maximum accepted size of the Authorization header in bytes
+    pub max_header_bytes: usize,
+
+    /// Whether to include the verification failure reason in 401 responses
+    pub debug_errors: bool,
+
+    /// Seconds after expiry during which tokens are still accepted on read-only routes
+    pub read_grace_secs: u64,
+
+    /// Whether to reject tokens issued in the future beyond the validation leeway
+    pub reject_future_iat: bool,
+
+    /// Allowed clock skew in seconds when checking token time claims
+    pub leeway_secs: u64,
+
+    /// URL of a JWKS to verify tokens against instead of the public key
+    pub jwks_url: Option<String>,
+}
+
+impl JwtConfig {
+    /// Check that a shared HS256 secret is long enough to resist brute force
+    pub fn validate_secret(&self) -> Result<()> {
+        if self.auth_type == "secret" && self.public_key.len() < MIN_JWT_SECRET_BYTES {
+            anyhow::bail!(
+                "JWT_SECRET must be at least {} bytes for HS256, got {}",
+                MIN_JWT_SECRET_BYTES,
+                self.public_key.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Check that token failure reasons are never exposed in production
+    ///
+    /// Outside production the reasons are allowed, with a warning so they are
+    /// not left on by accident.
+    pub fn validate_debug_errors(&self, production: bool) -> Result<()> {
+        if !self.debug_errors {
+            return Ok(());
+        }
+        if production {
+            anyhow::bail!("DEBUG_AUTH_ERRORS must not be enabled when APP_ENV=production");
+        }
+
+        tracing::warn!(
+            "DEBUG_AUTH_ERRORS is enabled; 401 responses reveal why tokens are rejected"
+        );
+        Ok(())
+    }
+
+    /// Check if the shared secret looks like a placeholder rather than a random value
+    pub fn has_placeholder_secret(&self) -> bool {
+        let secret = self.public_key.to_ascii_lowercase();
+        self.auth_type == "secret"
+            && (PLACEHOLDER_SECRET_MARKERS
+                .iter()
+                .any(|marker| secret.contains(marker))
+                || secret.chars().all(|c| secret.starts_with(c)))
+    }
 }
 
 /// TLS configuration for HTTPS
@@ -30,6 +115,39 @@ pub struct TlsConfig {
 
     /// Path to the TLS key file
     pub key_file: PathBuf,
+
+    /// Oldest TLS protocol version accepted from clients
+    pub min_version: TlsVersion,
+}
+
+/// TLS protocol version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsVersion {
+    /// TLS 1.2
+    Tls12,
+
+    /// TLS 1.3
+    Tls13,
+}
+
+impl TlsVersion {
+    /// Parse a version written as `1.2` or `1.3`
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim() {
+            "1.2" => Ok(TlsVersion::Tls12),
+            "1.3" => Ok(TlsVersion::Tls13),
+            other => anyhow::bail!("TLS_MIN_VERSION must be 1.2 or 1.3, got {:?}", other),
+        }
+    }
+}
+
+impl std::fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlsVersion::Tls12 => write!(f, "TLS 1.2"),
+            TlsVersion::Tls13 => write!(f, "TLS 1.3"),
+        }
+    }
 }
 
 /// Database configuration
@@ -37,6 +155,136 @@ pub struct TlsConfig {
 pub struct DatabaseConfig {
     /// Database connection URL
     pub url: String,
+
+    /// Whether to store game guesses gzip-compressed
+    pub compress_guesses: bool,
+
+    /// Maximum serialized size of a game's guesses in bytes
+    pub max_guesses_bytes: usize,
+
+    /// Maximum number of connections in the pool
+    pub max_connections: u32,
+
+    /// Number of idle connections the pool keeps open
+    pub min_connections: u32,
+
+    /// Seconds to wait for a pooled connection before failing
+    pub acquire_timeout_secs: u64,
+
+    /// Seconds after which an idle connection is closed (0 keeps them open)
+    pub idle_timeout_secs: u64,
+}
+
+/// Redis configuration
+#[derive(Debug, Clone)]
+pub struct RedisConfig {
+    /// Redis connection URL (Redis storage is used only when set)
+    pub url: Option<String>,
+}
+
+/// Game behavior configuration
+#[derive(Debug, Clone)]
+pub struct GameConfig {
+    /// Length of words used in the game
+    pub word_length: usize,
+
+    /// Number of attempts allowed per game
+    pub max_attempts: u8,
+
+    /// Whether practice, seeded and archive games can be created
+    pub practice_mode: bool,
+
+    /// Whether completed games are kept when the daily reset clears games
+    pub preserve_completed_on_reset: bool,
+
+    /// Whether a user can start a completed daily game again with the same word
+    pub allow_daily_replay: bool,
+
+    /// Whether the word of a completed game is shown to its player
+    pub reveal_answer_on_complete: bool,
+
+    /// Practice, seeded, archive and replayed games a user can create per day (0 is unlimited)
+    pub max_daily_games_per_user: u32,
+
+    /// Days after which games are removed by the reset task (0 keeps them forever)
+    pub game_retention_days: u32,
+
+    /// Optional file with newline-separated words replacing the built-in list
+    pub word_list_file: Option<PathBuf>,
+
+    /// Whether a missing or empty word list file is a startup error
+    pub require_word_file: bool,
+
+    /// Word list files of other languages, by language code
+    pub language_word_lists: Vec<(String, PathBuf)>,
+
+    /// Secret key for signing completed game results (signing is disabled if unset)
+    pub result_signing_key: Option<String>,
+
+    /// Timezone in which the daily word changes and games are reset
+    pub daily_reset_timezone: Tz,
+
+    /// Whether the leaderboard shows pseudonyms instead of usernames
+    pub leaderboard_anonymize: bool,
+
+    /// Whether the number of players active today is exposed
+    pub live_stats: bool,
+
+    /// Gap between guesses in milliseconds below which a game is flagged as suspicious (0 disables)
+    pub min_guess_interval_ms: u64,
+
+    /// Number of daily puzzles, each getting an equal share of the day
+    pub puzzles_per_day: u32,
+
+    /// Weights of the points awarded for won games
+    pub scoring: ScoringConfig,
+}
+
+/// Weights of the points awarded for won games
+#[derive(Debug, Clone)]
+pub struct ScoringConfig {
+    /// Points for a win on the first guess
+    pub base_points: u32,
+
+    /// Points deducted for each guess after the first
+    pub attempt_penalty: u32,
+
+    /// Bonus points for a win in hard mode
+    pub hard_mode_bonus: u32,
+
+    /// Bonus points for a win without power-up hints
+    pub no_hint_bonus: u32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            base_points: 100,
+            attempt_penalty: 15,
+            hard_mode_bonus: 20,
+            no_hint_bonus: 10,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Load scoring weights from environment variables
+    fn load() -> Result<Self> {
+        let weight = |name: &str, default: u32| -> Result<u32> {
+            env::var(name)
+                .unwrap_or_else(|_| default.to_string())
+                .parse::<u32>()
+                .with_context(|| format!("{} must be a non-negative integer", name))
+        };
+        let defaults = Self::default();
+
+        Ok(Self {
+            base_points: weight("SCORE_BASE_POINTS", defaults.base_points)?,
+            attempt_penalty: weight("SCORE_ATTEMPT_PENALTY", defaults.attempt_penalty)?,
+            hard_mode_bonus: weight("SCORE_HARD_MODE_BONUS", defaults.hard_mode_bonus)?,
+            no_hint_bonus: weight("SCORE_NO_HINT_BONUS", defaults.no_hint_bonus)?,
+        })
+    }
 }
 
 /// Main application configuration
@@ -45,6 +293,24 @@ pub struct Config {
     /// Server port to listen on
     pub port: u16,
 
+    /// Maximum guesses per client IP per minute (0 disables the limit)
+    pub guess_rate_limit: u32,
+
+    /// Seconds after which a request's repository calls are aborted (0 disables the deadline)
+    pub request_timeout_secs: u64,
+
+    /// Maximum live update subscribers across all games (0 disables the cap)
+    pub max_live_subscribers: usize,
+
+    /// Origins allowed to make credentialed CORS requests (any origin if empty)
+    pub cors_allowed_origins: Vec<String>,
+
+    /// Whether the API is also served under the unversioned `/api` prefix
+    pub legacy_api_routes: bool,
+
+    /// Whether completed games are logged as analytics events
+    pub analytics_enabled: bool,
+
     /// JWT authentication settings
     pub jwt: JwtConfig,
 
@@ -53,6 +319,15 @@ pub struct Config {
 
     /// Database configuration
     pub database: DatabaseConfig,
+
+    /// Redis configuration
+    pub redis: RedisConfig,
+
+    /// Write-ahead log file making in-memory storage durable (disabled if unset)
+    pub wal_file: Option<PathBuf>,
+
+    /// Game configuration
+    pub game: GameConfig,
 }
 
 impl Config {
@@ -63,10 +338,44 @@ impl Config {
             .unwrap_or_else(|_| "8080".to_string())
             .parse::<u16>()?;
 
+        // Load guess rate limit from GUESS_RATE_LIMIT env var or use default
+        let guess_rate_limit = env::var("GUESS_RATE_LIMIT")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<u32>()
+            .context("GUESS_RATE_LIMIT must be a non-negative integer")?;
+
+        let request_timeout_secs = env::var("REQUEST_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse::<u64>()
+            .context("REQUEST_TIMEOUT_SECS must be a non-negative integer")?;
+
+        let max_live_subscribers = env::var("MAX_LIVE_SUBSCRIBERS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse::<usize>()
+            .context("MAX_LIVE_SUBSCRIBERS must be a non-negative integer")?;
+
+        // Load CORS allowlist from a comma-separated CORS_ALLOWED_ORIGINS
+        let cors_allowed_origins: Vec<String> = env::var("CORS_ALLOWED_ORIGINS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|origin| origin.trim().to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+        if let Some(origin) = cors_allowed_origins
+            .iter()
+            .find(|origin| HeaderValue::from_str(origin).is_err())
+        {
+            anyhow::bail!("Invalid origin in CORS_ALLOWED_ORIGINS: {}", origin);
+        }
+
         // Load JWT configuration
         let auth_type = env::var("JWT_AUTH_TYPE").unwrap_or_else(|_| "secret".to_string());
+        let jwks_url = env::var("JWT_JWKS_URL").ok().filter(|url| !url.is_empty());
         let public_key = if auth_type == "secret" {
             env::var("JWT_SECRET").unwrap_or_default()
+        } else if jwks_url.is_some() {
+            // Verification keys come from the JWKS instead
+            String::new()
         } else {
             // Try to load from direct key value first
             match env::var("JWT_PUBLIC_KEY") {
@@ -82,12 +391,60 @@ impl Config {
             }
         };
 
+        // Load the signing key only when local token issuance is enabled
+        let allow_local_issuance = env::var("JWT_ALLOW_LOCAL_ISSUANCE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        let signing_key = if !allow_local_issuance {
+            None
+        } else if auth_type == "secret" {
+            Some(public_key.clone())
+        } else {
+            match env::var("JWT_PRIVATE_KEY") {
+                Ok(key) if !key.is_empty() => Some(key),
+                _ => {
+                    let key_file = env::var("JWT_PRIVATE_KEY_FILE")
+                        .unwrap_or_else(|_| "./keys/jwt/private.pem".to_string());
+                    Some(fs::read_to_string(&key_file).with_context(|| {
+                        format!("Failed to read JWT private key from {}", key_file)
+                    })?)
+                }
+            }
+        };
+
         let jwt = JwtConfig {
             auth_type,
             public_key,
             issuer: env::var("JWT_ISSUER").unwrap_or_else(|_| "wordle".to_string()),
             audience: env::var("JWT_AUDIENCE").unwrap_or_else(|_| "users".to_string()),
+            allow_local_issuance,
+            signing_key,
+            max_header_bytes: env::var("JWT_MAX_HEADER_BYTES")
+                .unwrap_or_else(|_| "8192".to_string())
+                .parse::<usize>()
+                .context("JWT_MAX_HEADER_BYTES must be a positive integer")?,
+            debug_errors: env::var("DEBUG_AUTH_ERRORS")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            read_grace_secs: env::var("JWT_READ_GRACE_SECS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse::<u64>()
+                .context("JWT_READ_GRACE_SECS must be a non-negative integer")?,
+            reject_future_iat: env::var("JWT_REJECT_FUTURE_IAT")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            leeway_secs: env::var("JWT_LEEWAY_SECONDS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse::<u64>()
+                .context("JWT_LEEWAY_SECONDS must be a non-negative integer")?,
+            jwks_url,
         };
+        jwt.validate_secret()?;
+        let production = env::var("APP_ENV").is_ok_and(|v| v.eq_ignore_ascii_case("production"));
+        jwt.validate_debug_errors(production)?;
+        if jwt.has_placeholder_secret() {
+            tracing::warn!("JWT_SECRET looks like an example value; use a random secret instead");
+        }
 
         // Load TLS configuration
         let tls = TlsConfig {
@@ -100,18 +457,221 @@ impl Config {
             key_file: env::var("TLS_KEY_FILE")
                 .map(PathBuf::from)
                 .unwrap_or_else(|_| PathBuf::from("keys/key.pem")),
+            min_version: TlsVersion::parse(
+                &env::var("TLS_MIN_VERSION").unwrap_or_else(|_| "1.2".to_string()),
+            )?,
         };
 
         // Load database configuration
         let database = DatabaseConfig {
             url: env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite::memory:".to_string()),
+            compress_guesses: env::var("DB_COMPRESS_GUESSES")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            max_guesses_bytes: env::var("DB_MAX_GUESSES_BYTES")
+                .unwrap_or_else(|_| "1048576".to_string())
+                .parse::<usize>()
+                .context("DB_MAX_GUESSES_BYTES must be a positive integer")?,
+            max_connections: env::var("DB_MAX_CONNECTIONS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse::<u32>()
+                .context("DB_MAX_CONNECTIONS must be a positive integer")?,
+            min_connections: env::var("DB_MIN_CONNECTIONS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse::<u32>()
+                .context("DB_MIN_CONNECTIONS must be a non-negative integer")?,
+            acquire_timeout_secs: env::var("DB_ACQUIRE_TIMEOUT")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse::<u64>()
+                .context("DB_ACQUIRE_TIMEOUT must be a positive integer")?,
+            idle_timeout_secs: env::var("DB_IDLE_TIMEOUT")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse::<u64>()
+                .context("DB_IDLE_TIMEOUT must be a non-negative integer")?,
+        };
+        if database.max_connections == 0 {
+            anyhow::bail!("DB_MAX_CONNECTIONS must be at least 1");
+        }
+        if database.min_connections > database.max_connections {
+            anyhow::bail!(
+                "DB_MIN_CONNECTIONS ({}) must not exceed DB_MAX_CONNECTIONS ({})",
+                database.min_connections,
+                database.max_connections
+            );
+        }
+
+        // Load Redis configuration
+        let redis = RedisConfig {
+            url: env::var("REDIS_URL").ok().filter(|v| !v.is_empty()),
+        };
+
+        // Load in-memory storage WAL file
+        let wal_file = env::var("WAL_FILE")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from);
+
+        // Load game configuration
+        let max_attempts = env::var("MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "6".to_string())
+            .parse::<u8>()
+            .context("MAX_ATTEMPTS must be a positive integer")?;
+        if !(1..=MAX_ATTEMPTS_LIMIT).contains(&max_attempts) {
+            anyhow::bail!(
+                "MAX_ATTEMPTS must be between 1 and {}, got {}",
+                MAX_ATTEMPTS_LIMIT,
+                max_attempts
+            );
+        }
+
+        let puzzles_per_day = env::var("PUZZLES_PER_DAY")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse::<u32>()
+            .context("PUZZLES_PER_DAY must be a positive integer")?;
+        if !(1..=MAX_PUZZLES_PER_DAY).contains(&puzzles_per_day) {
+            anyhow::bail!(
+                "PUZZLES_PER_DAY must be between 1 and {}, got {}",
+                MAX_PUZZLES_PER_DAY,
+                puzzles_per_day
+            );
+        }
+
+        let language_word_lists = env::var("LANGUAGE_WORD_LISTS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (language, path) = entry
+                    .split_once('=')
+                    .filter(|(language, path)| {
+                        !language.is_empty()
+                            && language.chars().all(|c| c.is_ascii_alphabetic())
+                            && !path.is_empty()
+                    })
+                    .with_context(|| {
+                        format!(
+                            "LANGUAGE_WORD_LISTS entries must look like es=./words-es.txt, got {}",
+                            entry
+                        )
+                    })?;
+                Ok((language.to_ascii_lowercase(), PathBuf::from(path)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let game = GameConfig {
+            word_length: env::var("WORD_LENGTH")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse::<usize>()
+                .context("WORD_LENGTH must be a positive integer")?,
+            max_attempts,
+            practice_mode: env::var("PRACTICE_MODE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            preserve_completed_on_reset: env::var("PRESERVE_COMPLETED_ON_RESET")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            allow_daily_replay: env::var("ALLOW_DAILY_REPLAY")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            reveal_answer_on_complete: env::var("REVEAL_ANSWER_ON_COMPLETE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            max_daily_games_per_user: env::var("MAX_DAILY_GAMES_PER_USER")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse::<u32>()
+                .context("MAX_DAILY_GAMES_PER_USER must be a non-negative integer")?,
+            game_retention_days: env::var("GAME_RETENTION_DAYS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse::<u32>()
+                .context("GAME_RETENTION_DAYS must be a non-negative integer")?,
+            word_list_file: env::var("WORD_LIST_FILE")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from),
+            require_word_file: env::var("REQUIRE_WORD_FILE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            language_word_lists,
+            result_signing_key: env::var("RESULT_SIGNING_KEY")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            daily_reset_timezone: env::var("DAILY_RESET_TIMEZONE")
+                .unwrap_or_else(|_| "UTC".to_string())
+                .parse::<Tz>()
+                .map_err(|e| anyhow::anyhow!("Invalid DAILY_RESET_TIMEZONE: {}", e))?,
+            leaderboard_anonymize: env::var("LEADERBOARD_ANONYMIZE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            live_stats: env::var("LIVE_STATS")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            min_guess_interval_ms: env::var("MIN_GUESS_INTERVAL_MS")
+                .unwrap_or_else(|_| "500".to_string())
+                .parse::<u64>()
+                .context("MIN_GUESS_INTERVAL_MS must be a non-negative integer")?,
+            puzzles_per_day,
+            scoring: ScoringConfig::load()?,
         };
 
         Ok(Self {
             port,
+            guess_rate_limit,
+            request_timeout_secs,
+            max_live_subscribers,
+            cors_allowed_origins,
+            legacy_api_routes: env::var("LEGACY_API_ROUTES")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            analytics_enabled: env::var("ANALYTICS_ENABLED")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
             jwt,
             tls,
             database,
+            redis,
+            wal_file,
+            game,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TlsVersion;
+    use crate::test_support;
+
+    #[test]
+    fn debug_errors_are_refused_in_production() {
+        let mut config = test_support::config().jwt;
+        assert!(config.validate_debug_errors(true).is_ok());
+
+        config.debug_errors = true;
+        assert!(config.validate_debug_errors(true).is_err());
+        assert!(config.validate_debug_errors(false).is_ok());
+    }
+
+    #[test]
+    fn short_hs256_secrets_are_refused() {
+        let mut config = test_support::config().jwt;
+        assert!(config.validate_secret().is_ok());
+
+        config.public_key = "8bytes!!".to_string();
+        let error = config.validate_secret().unwrap_err();
+        assert!(error.to_string().contains("got 8"), "{}", error);
+
+        // Public keys of asymmetric algorithms are not secrets
+        config.auth_type = "rsa".to_string();
+        assert!(config.validate_secret().is_ok());
+    }
+
+    #[test]
+    fn tls_min_version_parses_known_versions_only() {
+        assert_eq!(TlsVersion::parse("1.3").unwrap(), TlsVersion::Tls13);
+        assert_eq!(TlsVersion::parse(" 1.2 ").unwrap(), TlsVersion::Tls12);
+
+        for invalid in ["1.1", "tls1.3", ""] {
+            assert!(TlsVersion::parse(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+}
@@ -1,4 +1,7 @@
 /// Initialize logging with sensible defaults
+///
+/// `LOG_FORMAT=json` selects structured JSON output, anything else the compact
+/// human format. Calling this more than once is harmless.
 pub fn init_logging() {
     // Get log level from environment or use default
     let log_level = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string());
@@ -6,11 +9,23 @@ pub fn init_logging() {
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(log_level));
 
-    tracing_subscriber::fmt()
+    let builder = tracing_subscriber::fmt()
         .with_env_filter(env_filter)
-        .with_target(true)
-        .compact()
-        .init();
+        .with_target(true);
+
+    // A global subscriber may already be installed, which is fine to ignore
+    let result = match std::env::var("LOG_FORMAT").as_deref() {
+        Ok("json") => builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .try_init(),
+        _ => builder.compact().try_init(),
+    };
+
+    if result.is_err() {
+        tracing::debug!("Logging already initialized");
+    }
 }
 
 /// Load environment variables from .env file
@@ -25,3 +40,18 @@ pub fn current_timestamp() -> u64 {
         .expect("Time went backwards")
         .as_secs()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_logging_can_be_initialized_repeatedly() {
+        // SAFETY: no other test reads or writes LOG_FORMAT
+        unsafe { std::env::set_var("LOG_FORMAT", "json") };
+
+        init_logging();
+        init_logging();
+        tracing::info!(check = "json", "logging initialized");
+    }
+}
@@ -8,6 +8,39 @@ use thiserror::Error;
 use tracing;
 
 use crate::repository::error::RepositoryError;
+use crate::server::request_id;
+
+/// Reason a guess was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InvalidGuessReason {
+    /// Guess has fewer letters than the word
+    #[error("Word is too short: expected {expected} letters, got {actual}")]
+    TooShort { expected: usize, actual: usize },
+
+    /// Guess has more letters than the word
+    #[error("Word is too long: expected {expected} letters, got {actual}")]
+    TooLong { expected: usize, actual: usize },
+
+    /// Guess contains characters other than letters
+    #[error("Word must contain letters only")]
+    NotLetters,
+
+    /// Guess is not in the dictionary
+    #[error("Not in word list: {0}")]
+    NotInWordList(String),
+}
+
+impl InvalidGuessReason {
+    /// Get the machine-readable code for the reason
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::NotLetters => "letters_only",
+            Self::NotInWordList(_) => "not_in_word_list",
+        }
+    }
+}
 
 /// Game-specific error types
 #[derive(Debug, Error)]
@@ -16,25 +49,75 @@ pub enum GameError {
     #[error("Game is already completed")]
     GameCompleted,
 
-    /// Invalid word provided
-    #[error("Invalid word: {0}")]
-    InvalidWord(String),
+    /// Game is not completed yet
+    #[error("Game is not completed yet")]
+    GameInProgress,
+
+    /// Guess was rejected
+    #[error("Invalid guess: {0}")]
+    InvalidGuess(InvalidGuessReason),
+
+    /// Guess does not use the hints revealed so far
+    #[error("Hard mode: {0}")]
+    HardModeViolation(String),
 
     /// Game not found
     #[error("Game not found")]
     GameNotFound,
 
+    /// User has not started a game today
+    #[error("No game started today")]
+    NoCurrentGame,
+
+    /// Request parameters are invalid
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    /// Server-wide cap on live update subscribers is reached
+    #[error("Too many live connections, try again later")]
+    TooManySubscribers,
+
+    /// User created as many games as allowed today
+    #[error("Daily game limit of {0} reached, try again tomorrow")]
+    DailyGameLimit(u32),
+
     /// Repository error
     #[error(transparent)]
     Repository(#[from] RepositoryError),
 }
 
-impl IntoResponse for GameError {
-    fn into_response(self) -> Response {
+impl GameError {
+    /// Get the HTTP status and JSON body reported to clients for the error
+    pub fn status_and_body(self) -> (StatusCode, serde_json::Value) {
+        // Rejected guesses carry a code so clients can tell reasons apart, and
+        // the lengths or word involved so they needn't parse the message
+        if let Self::InvalidGuess(reason) = &self {
+            let mut body = json!({
+                "error": reason.to_string(),
+                "code": reason.code(),
+            });
+            match reason {
+                InvalidGuessReason::TooShort { expected, actual }
+                | InvalidGuessReason::TooLong { expected, actual } => {
+                    body["expected"] = json!(expected);
+                    body["actual"] = json!(actual);
+                }
+                InvalidGuessReason::NotInWordList(word) => body["word"] = json!(word),
+                InvalidGuessReason::NotLetters => {}
+            }
+            return (StatusCode::BAD_REQUEST, body);
+        }
+
         let (status, error_message) = match self {
             Self::GameCompleted => (StatusCode::BAD_REQUEST, self.to_string()),
-            Self::InvalidWord(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::GameInProgress => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::InvalidGuess(reason) => (StatusCode::BAD_REQUEST, reason.to_string()),
+            Self::HardModeViolation(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             Self::GameNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::NoCurrentGame => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::TooManySubscribers => (StatusCode::SERVICE_UNAVAILABLE, self.to_string()),
+            Self::DailyGameLimit(_) => (StatusCode::TOO_MANY_REQUESTS, self.to_string()),
             Self::Repository(err) => {
                 // Log the repository error
                 tracing::error!("Repository error: {}", err);
@@ -59,11 +142,63 @@ impl IntoResponse for GameError {
                         StatusCode::NOT_IMPLEMENTED,
                         "Operation not supported".into(),
                     ),
+                    RepositoryError::Conflict => (
+                        StatusCode::CONFLICT,
+                        "Game was modified by another request, please retry".into(),
+                    ),
+                    RepositoryError::ResetInProgress => (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Daily reset in progress, please retry".into(),
+                    ),
                 }
             }
         };
 
-        // Construct JSON response
-        (status, Json(json!({ "error": error_message }))).into_response()
+        (status, json!({ "error": error_message }))
+    }
+}
+
+impl IntoResponse for GameError {
+    fn into_response(self) -> Response {
+        let (status, mut body) = self.status_and_body();
+        request_id::attach(&mut body);
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::game::GameService;
+    use crate::game::models::Game;
+
+    fn rejection(guess: &str) -> serde_json::Value {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), 6, Uuid::new_v4());
+        let error = service.make_guess(&mut game, guess).unwrap_err();
+
+        let (status, body) = error.status_and_body();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        body
+    }
+
+    #[test]
+    fn length_rejections_have_distinct_codes_and_lengths() {
+        let short = rejection("pla");
+        assert_eq!(short["code"], "too_short");
+        assert_eq!(short["expected"], 5);
+        assert_eq!(short["actual"], 3);
+        assert_eq!(
+            short["error"],
+            "Word is too short: expected 5 letters, got 3"
+        );
+
+        let long = rejection("planets");
+        assert_eq!(long["code"], "too_long");
+        assert_eq!(long["expected"], 5);
+        assert_eq!(long["actual"], 7);
+        assert_eq!(long["error"], "Word is too long: expected 5 letters, got 7");
     }
 }
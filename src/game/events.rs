@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::game::models::Game;
+
+/// Event emitted when a game is won or lost
+#[derive(Debug, Clone)]
+pub struct GameCompletedEvent {
+    /// ID of the completed game
+    pub game_id: Uuid,
+
+    /// ID of the player
+    pub user_id: Uuid,
+
+    /// Whether the game was won
+    pub won: bool,
+
+    /// Number of guesses made
+    pub guess_count: usize,
+
+    /// Number of letters in the word
+    pub word_length: usize,
+
+    /// Whether the game was played in hard mode
+    pub hard_mode: bool,
+
+    /// Whether the game was a practice game
+    pub practice: bool,
+
+    /// When the game was completed
+    pub completed_at: DateTime<Utc>,
+}
+
+impl From<&Game> for GameCompletedEvent {
+    fn from(game: &Game) -> Self {
+        Self {
+            game_id: game.id,
+            user_id: game.user_id,
+            won: game.won,
+            guess_count: game.guesses.len(),
+            word_length: game.word.chars().count(),
+            hard_mode: game.hard_mode,
+            practice: game.practice,
+            completed_at: game.updated_at,
+        }
+    }
+}
+
+/// Event emitted when a daily reset clears a game that was never finished
+#[derive(Debug, Clone)]
+pub struct GameAbandonedEvent {
+    /// ID of the abandoned game
+    pub game_id: Uuid,
+
+    /// ID of the player
+    pub user_id: Uuid,
+
+    /// Number of guesses made before the game was abandoned
+    pub guess_count: usize,
+
+    /// Number of letters in the word
+    pub word_length: usize,
+
+    /// Whether the game was a practice game
+    pub practice: bool,
+
+    /// When the game was last played
+    pub last_played_at: DateTime<Utc>,
+}
+
+impl From<&Game> for GameAbandonedEvent {
+    fn from(game: &Game) -> Self {
+        Self {
+            game_id: game.id,
+            user_id: game.user_id,
+            guess_count: game.guesses.len(),
+            word_length: game.word.chars().count(),
+            practice: game.practice,
+            last_played_at: game.updated_at,
+        }
+    }
+}
+
+/// Integration notified of game events
+#[async_trait]
+pub trait GameEventSink: Send + Sync {
+    /// Handle a completed game
+    async fn on_game_completed(&self, event: &GameCompletedEvent);
+
+    /// Handle a game cleared by a daily reset before it was completed
+    async fn on_game_abandoned(&self, _event: &GameAbandonedEvent) {}
+}
+
+/// Sink dispatching each event to every registered sink in order
+#[derive(Default, Clone)]
+pub struct CompositeSink {
+    sinks: Vec<Arc<dyn GameEventSink>>,
+}
+
+impl CompositeSink {
+    /// Create a sink with no registered sinks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a sink
+    pub fn register(&mut self, sink: Arc<dyn GameEventSink>) {
+        self.sinks.push(sink);
+    }
+
+    /// Number of registered sinks
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Whether no sinks are registered
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+}
+
+#[async_trait]
+impl GameEventSink for CompositeSink {
+    async fn on_game_completed(&self, event: &GameCompletedEvent) {
+        for sink in &self.sinks {
+            sink.on_game_completed(event).await;
+        }
+    }
+
+    async fn on_game_abandoned(&self, event: &GameAbandonedEvent) {
+        for sink in &self.sinks {
+            sink.on_game_abandoned(event).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::test_support::played_game;
+
+    /// Sink recording the IDs of completed games
+    #[derive(Default)]
+    struct CompletedGames(Mutex<Vec<Uuid>>);
+
+    #[async_trait]
+    impl GameEventSink for CompletedGames {
+        async fn on_game_completed(&self, event: &GameCompletedEvent) {
+            self.0.lock().push(event.game_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn composite_sink_dispatches_to_every_sink_once() {
+        let (first, second) = (
+            Arc::new(CompletedGames::default()),
+            Arc::new(CompletedGames::default()),
+        );
+        let mut sink = CompositeSink::new();
+        sink.register(first.clone());
+        sink.register(second.clone());
+
+        let game = played_game(Uuid::new_v4(), "plant", &["plant"]);
+        sink.on_game_completed(&GameCompletedEvent::from(&game))
+            .await;
+
+        assert_eq!(*first.0.lock(), vec![game.id]);
+        assert_eq!(*second.0.lock(), vec![game.id]);
+    }
+}
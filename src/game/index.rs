@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::game::models::{Guess, LetterResult};
+
+/// Set of word indices backed by a bitset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordSet {
+    bits: Vec<u64>,
+}
+
+impl WordSet {
+    /// Create an empty set able to hold `len` words
+    pub fn empty(len: usize) -> Self {
+        Self {
+            bits: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Create a set containing all `len` words
+    pub fn full(len: usize) -> Self {
+        let mut set = Self::empty(len);
+        for i in 0..len {
+            set.insert(i);
+        }
+        set
+    }
+
+    /// Add a word index to the set
+    pub fn insert(&mut self, i: usize) {
+        self.bits[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Keep only the words also present in `other`
+    pub fn intersect_with(&mut self, other: &WordSet) {
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a &= b;
+        }
+    }
+
+    /// Remove the words present in `other`
+    pub fn subtract(&mut self, other: &WordSet) {
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a &= !b;
+        }
+    }
+
+    /// Remove every word from the set
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|b| *b = 0);
+    }
+
+    /// Iterate over the word indices in the set
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(block, &bits)| {
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| block * 64 + bit)
+        })
+    }
+}
+
+/// Precomputed letter indexes over a word list for fast candidate filtering
+#[derive(Debug, Clone)]
+pub struct CandidateIndex {
+    /// Number of indexed words
+    len: usize,
+
+    /// Words having a given letter at each position
+    by_position: Vec<HashMap<char, WordSet>>,
+
+    /// Words containing at least `n` occurrences of a letter, keyed by `(letter, n)`
+    by_min_count: HashMap<(char, usize), WordSet>,
+}
+
+impl CandidateIndex {
+    /// Build the index for a word list
+    pub fn new(words: &[String]) -> Self {
+        let len = words.len();
+        let mut by_position: Vec<HashMap<char, WordSet>> = Vec::new();
+        let mut by_min_count: HashMap<(char, usize), WordSet> = HashMap::new();
+
+        for (i, word) in words.iter().enumerate() {
+            let mut counts: HashMap<char, usize> = HashMap::new();
+
+            for (pos, c) in word.chars().enumerate() {
+                if by_position.len() <= pos {
+                    by_position.push(HashMap::new());
+                }
+                by_position[pos]
+                    .entry(c)
+                    .or_insert_with(|| WordSet::empty(len))
+                    .insert(i);
+
+                let count = counts.entry(c).or_insert(0);
+                *count += 1;
+                by_min_count
+                    .entry((c, *count))
+                    .or_insert_with(|| WordSet::empty(len))
+                    .insert(i);
+            }
+        }
+
+        Self {
+            len,
+            by_position,
+            by_min_count,
+        }
+    }
+
+    /// Get the indices of words that satisfy the constraints implied by the guesses
+    ///
+    /// This is a superset filter: every consistent word is included, so callers
+    /// needing exact results should confirm each match by re-evaluating it.
+    pub fn filter(&self, guesses: &[Guess]) -> WordSet {
+        let mut set = WordSet::full(self.len);
+
+        for guess in guesses {
+            let mut min_counts: HashMap<char, usize> = HashMap::new();
+            let mut capped: Vec<char> = Vec::new();
+
+            for (pos, (c, result)) in guess.word.chars().zip(&guess.results).enumerate() {
+                let at_position = self.by_position.get(pos).and_then(|m| m.get(&c));
+
+                // Correct letters must be at this position, others must not
+                match (result, at_position) {
+                    (LetterResult::Correct, Some(words)) => set.intersect_with(words),
+                    (LetterResult::Correct, None) => set.clear(),
+                    (_, Some(words)) => set.subtract(words),
+                    (_, None) => {}
+                }
+
+                // Revealed letters set a minimum count, a Wrong one caps it
+                match result {
+                    LetterResult::Wrong => capped.push(c),
+                    _ => *min_counts.entry(c).or_insert(0) += 1,
+                }
+            }
+
+            for (&c, &min) in &min_counts {
+                match self.by_min_count.get(&(c, min)) {
+                    Some(words) => set.intersect_with(words),
+                    None => set.clear(),
+                }
+            }
+
+            for c in capped {
+                let max = min_counts.get(&c).copied().unwrap_or(0);
+                if let Some(words) = self.by_min_count.get(&(c, max + 1)) {
+                    set.subtract(words);
+                }
+            }
+        }
+
+        set
+    }
+}
@@ -0,0 +1,21 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::game::models::GameSummary;
+
+/// Number of entries shown on the leaderboard
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Rank the winning daily games, fewest guesses first, ties broken by finish time
+pub fn rank_winners(games: &[GameSummary]) -> Vec<&GameSummary> {
+    let mut winners: Vec<&GameSummary> = games.iter().filter(|g| g.won && g.is_daily()).collect();
+    winners.sort_by_key(|g| (g.guess_count, g.updated_at));
+    winners
+}
+
+/// Get a stable pseudonym for a user, e.g. `Player#1234`
+pub fn pseudonym(user_id: &Uuid) -> String {
+    let digest = Sha256::digest(user_id.as_bytes());
+    let number = u16::from_be_bytes([digest[0], digest[1]]) % 10000;
+    format!("Player#{:04}", number)
+}
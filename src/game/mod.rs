@@ -1,46 +1,538 @@
-use chrono::{Datelike, NaiveDate, Utc};
+use anyhow::Context;
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use rand::rngs::StdRng;
 use rand::{SeedableRng, prelude::*};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use unicode_normalization::UnicodeNormalization;
 
 pub mod error;
+pub mod events;
+mod index;
+pub mod leaderboard;
 pub mod models;
+pub mod signing;
 pub mod state;
+pub mod stats;
 mod words;
 
-use self::error::GameError;
-use self::models::{Game, Guess, LetterResult};
+use crate::common::config::GameConfig;
+
+use self::error::{GameError, InvalidGuessReason};
+use self::index::CandidateIndex;
+use self::models::{Game, Guess, LetterResult, MastermindResult, ScoringMode};
+use self::signing::{ScheduleEntry, WordSchedule};
 pub use self::state::GameState;
 
-// Daily word cache
-static DAILY_WORD_CACHE: Lazy<Mutex<HashMap<NaiveDate, String>>> =
+/// Default maximum number of attempts allowed per game
+pub const MAX_ATTEMPTS: u8 = 6;
+
+/// Upper bound on the configurable number of attempts per game
+pub const MAX_ATTEMPTS_LIMIT: u8 = 10;
+
+/// Upper bound on the configurable number of puzzles per day
+pub const MAX_PUZZLES_PER_DAY: u32 = 24;
+
+/// Default length of words used in the game
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+
+/// Maximum number of reveal-green power-ups per game
+pub const MAX_REVEAL_POWERUPS: u8 = 2;
+
+/// Minimum number of valid words accepted when replacing the dictionary
+pub const MIN_DICTIONARY_WORDS: usize = 20;
+
+/// Maximum number of days covered by a single word schedule
+pub const MAX_SCHEDULE_DAYS: i64 = 366;
+
+/// Language served by the main dictionary
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Domain tag hashed into player-supplied seeds, keeping them apart from daily seeds
+const SEEDED_GAME_DOMAIN: &[u8] = b"wordle/seeded-game/v1";
+
+/// Number of invalid entries quoted when a word list file is rejected
+const MAX_REPORTED_INVALID_WORDS: usize = 5;
+
+/// Normalize a word for comparison: trimmed, NFC-composed and lowercased
+///
+/// Composing first means an accented letter is always a single `char`, so
+/// letter positions and lengths count characters the same way for every input.
+pub fn normalize_word(word: &str) -> String {
+    word.trim().nfc().collect::<String>().to_lowercase()
+}
+
+/// Parse newline-separated words, failing on entries that aren't `word_length` letters
+///
+/// Blank lines are ignored; `path` is only used in the error message.
+fn parse_word_list(contents: &str, path: &Path, word_length: usize) -> anyhow::Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut invalid = Vec::new();
+    for (line, entry) in contents.lines().enumerate() {
+        let word = normalize_word(entry);
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().count() == word_length && word.chars().all(char::is_alphabetic) {
+            words.push(word);
+        } else {
+            invalid.push(format!("line {}: {:?}", line + 1, entry.trim()));
+        }
+    }
+
+    if !invalid.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Word list file {} has {} entries that are not {}-letter words ({}{})",
+            path.display(),
+            invalid.len(),
+            word_length,
+            invalid[..invalid.len().min(MAX_REPORTED_INVALID_WORDS)].join(", "),
+            if invalid.len() > MAX_REPORTED_INVALID_WORDS {
+                ", ..."
+            } else {
+                ""
+            }
+        ));
+    }
+
+    Ok(words)
+}
+
+/// Date of puzzle number 0
+pub const PUZZLE_EPOCH: NaiveDate = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+
+/// Get the puzzle number for a date, counted in days since the puzzle epoch
+pub fn puzzle_number(date: NaiveDate) -> i64 {
+    date.signed_duration_since(PUZZLE_EPOCH).num_days()
+}
+
+/// Get the date of a puzzle number, the inverse of `puzzle_number`
+pub fn puzzle_date(number: i64) -> Option<NaiveDate> {
+    let days = u64::try_from(number).ok()?;
+    PUZZLE_EPOCH.checked_add_days(Days::new(days))
+}
+
+/// Daily word cache key: date, puzzle index, word length, dictionary version and language
+type DailyWordKey = (NaiveDate, u32, usize, u64, Option<String>);
+
+// Daily word cache, keyed by date, puzzle index, word length, dictionary version and language
+static DAILY_WORD_CACHE: Lazy<Mutex<HashMap<DailyWordKey, String>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
 /// Service for handling game logic
 #[derive(Debug, Clone)]
 pub struct GameService {
-    // Dictionary of valid words
+    // Dictionary of valid guesses, including every answer
     word_list: Vec<String>,
+    // Words the daily, seeded and practice words are chosen from
+    answer_list: Vec<String>,
     // Length of words used in the game
     word_length: usize,
+    // Precomputed letter indexes over the word list
+    index: CandidateIndex,
+    // Version of the word list, incremented when it is replaced at runtime
+    version: u64,
+    // Timezone in which the daily word changes
+    timezone: Tz,
+    // Number of attempts allowed in new games
+    max_attempts: u8,
+    // Number of daily puzzles, each getting an equal share of the day
+    puzzles_per_day: u32,
+    // Dictionaries of other languages by code, each used for answers and guesses
+    languages: HashMap<String, Vec<String>>,
 }
 
 impl GameService {
-    /// Create a new game service
-    pub fn new() -> Self {
+    /// Create a new game service using the built-in word lists for a word length
+    pub fn new(word_length: usize) -> anyhow::Result<Self> {
+        let words = words::word_list_for(word_length).ok_or_else(|| {
+            anyhow::anyhow!("No built-in word list for {}-letter words", word_length)
+        })?;
+        let answers = words::answer_list_for(word_length).unwrap_or(words);
+
+        Ok(Self::new_with_lists(
+            answers.iter().map(|&w| String::from(w)).collect(),
+            words.iter().map(|&w| String::from(w)).collect(),
+            word_length,
+        ))
+    }
+
+    /// Create a game service choosing words from `answers` and accepting
+    /// guesses from either list
+    pub fn new_with_lists(answers: Vec<String>, guesses: Vec<String>, word_length: usize) -> Self {
+        let mut word_list = guesses;
+        for answer in &answers {
+            if !word_list.contains(answer) {
+                word_list.push(answer.clone());
+            }
+        }
+
+        Self {
+            answer_list: answers,
+            ..Self::with_words(word_list, word_length)
+        }
+    }
+
+    /// Create a game service from configuration
+    pub fn from_config(config: &GameConfig) -> anyhow::Result<Self> {
+        let mut service = match &config.word_list_file {
+            Some(path) => Self::from_word_file(path, config.word_length, config.require_word_file),
+            None if config.require_word_file => Err(anyhow::anyhow!(
+                "REQUIRE_WORD_FILE is set but WORD_LIST_FILE is not configured"
+            )),
+            None => Self::new(config.word_length),
+        }?;
+
+        for (language, path) in &config.language_word_lists {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read word list from {}", path.display()))?;
+            let words = parse_word_list(&contents, path, config.word_length)?;
+            if words.is_empty() {
+                anyhow::bail!("Word list file {} is empty", path.display());
+            }
+
+            tracing::info!(
+                "Loaded {} {} words from {}",
+                words.len(),
+                language,
+                path.display()
+            );
+            service = service.with_language(language, words);
+        }
+
+        Ok(service
+            .with_timezone(config.daily_reset_timezone)
+            .with_max_attempts(config.max_attempts)
+            .with_puzzles_per_day(config.puzzles_per_day))
+    }
+
+    /// Set the timezone in which the daily word changes
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Set the number of attempts allowed in new games
+    pub fn with_max_attempts(mut self, max_attempts: u8) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the number of daily puzzles, clamped to `1..=MAX_PUZZLES_PER_DAY`
+    pub fn with_puzzles_per_day(mut self, puzzles_per_day: u32) -> Self {
+        self.puzzles_per_day = puzzles_per_day.clamp(1, MAX_PUZZLES_PER_DAY);
+        self
+    }
+
+    /// Add the dictionary of another language, used for its answers and guesses
+    pub fn with_language(mut self, language: &str, words: Vec<String>) -> Self {
+        self.languages.insert(language.to_string(), words);
+        self
+    }
+
+    /// Create a game service using newline-separated words from a file
+    ///
+    /// Falls back to the embedded word list when the file is missing or empty,
+    /// unless `require` is set, in which case that is an error. Entries that
+    /// are not `word_length` letters long are always an error.
+    pub fn from_word_file(path: &Path, word_length: usize, require: bool) -> anyhow::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if require => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read word list from {}", path.display()));
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to read word list from {} ({}), using embedded word list",
+                    path.display(),
+                    e
+                );
+                return Self::new(word_length);
+            }
+        };
+
+        let words = parse_word_list(&contents, path, word_length)?;
+        if words.is_empty() {
+            if require {
+                return Err(anyhow::anyhow!(
+                    "Word list file {} is empty",
+                    path.display()
+                ));
+            }
+            tracing::warn!(
+                "Word list file {} is empty, using embedded word list",
+                path.display()
+            );
+            return Self::new(word_length);
+        }
+
+        tracing::info!("Loaded {} words from {}", words.len(), path.display());
+        Ok(Self::with_words(words, word_length))
+    }
+
+    /// Create a game service using the given word list for both answers and guesses
+    fn with_words(word_list: Vec<String>, word_length: usize) -> Self {
+        let index = CandidateIndex::new(&word_list);
+
         Self {
-            word_list: words::WORD_LIST.iter().map(|&w| String::from(w)).collect(),
-            word_length: 5, // Standard Wordle uses 5-letter words
+            answer_list: word_list.clone(),
+            word_list,
+            word_length,
+            index,
+            version: 0,
+            timezone: Tz::UTC,
+            max_attempts: MAX_ATTEMPTS,
+            puzzles_per_day: 1,
+            languages: HashMap::new(),
         }
     }
 
-    /// Get today's word for external use
+    /// Create a new game service with a replacement word list
+    ///
+    /// Words are normalized with `normalize_word`; entries that are not made of
+    /// exactly `word_length` letters are skipped. Fails if fewer than
+    /// `MIN_DICTIONARY_WORDS` valid words remain.
+    pub fn replace_words(&self, words: &[String]) -> Result<Self, GameError> {
+        let mut word_list: Vec<String> = words
+            .iter()
+            .map(|word| normalize_word(word))
+            .filter(|word| {
+                word.chars().count() == self.word_length && word.chars().all(char::is_alphabetic)
+            })
+            .collect();
+        word_list.sort();
+        word_list.dedup();
+
+        if word_list.len() < MIN_DICTIONARY_WORDS {
+            return Err(GameError::BadRequest(format!(
+                "Dictionary must contain at least {} valid {}-letter words, got {}",
+                MIN_DICTIONARY_WORDS,
+                self.word_length,
+                word_list.len()
+            )));
+        }
+
+        Ok(Self {
+            version: self.version + 1,
+            timezone: self.timezone,
+            max_attempts: self.max_attempts,
+            puzzles_per_day: self.puzzles_per_day,
+            languages: self.languages.clone(),
+            ..Self::with_words(word_list, self.word_length)
+        })
+    }
+
+    /// Get the length of words used in the game
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Get the number of attempts allowed in new games
+    pub fn max_attempts(&self) -> u8 {
+        self.max_attempts
+    }
+
+    /// Get the number of daily puzzles
+    pub fn puzzles_per_day(&self) -> u32 {
+        self.puzzles_per_day
+    }
+
+    /// Get the number of words in the dictionary
+    pub fn word_count(&self) -> usize {
+        self.word_list.len()
+    }
+
+    /// Get the dictionary version
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Get the codes of every language with a dictionary, the default first
+    pub fn languages(&self) -> Vec<String> {
+        let mut others: Vec<String> = self.languages.keys().cloned().collect();
+        others.sort();
+
+        std::iter::once(DEFAULT_LANGUAGE.to_string())
+            .chain(others)
+            .collect()
+    }
+
+    /// Check if a language other than the default has a dictionary
+    pub fn has_language(&self, language: &str) -> bool {
+        self.languages.contains_key(language)
+    }
+
+    /// Get the words answers are chosen from in a language
+    ///
+    /// The default dictionary is used when `language` is `None` or unknown.
+    fn answers_in(&self, language: Option<&str>) -> &[String] {
+        language
+            .and_then(|language| self.languages.get(language))
+            .unwrap_or(&self.answer_list)
+    }
+
+    /// Get the timezone in which the daily word changes
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Get the current date in the daily reset timezone
+    pub fn today(&self) -> NaiveDate {
+        self.date_of(Utc::now())
+    }
+
+    /// Get the date of an instant in the daily reset timezone
+    pub fn date_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        instant.with_timezone(&self.timezone).date_naive()
+    }
+
+    /// Get the instant a date starts in the daily reset timezone
+    pub fn day_start(&self, date: NaiveDate) -> DateTime<Utc> {
+        date.and_time(NaiveTime::MIN)
+            .and_local_timezone(self.timezone)
+            .earliest()
+            .map(|start| start.with_timezone(&Utc))
+            .unwrap_or_else(|| date.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    /// Get the date and puzzle index current at an instant
+    ///
+    /// The day is split into `puzzles_per_day` equal slots, so days lengthened
+    /// or shortened by a DST change get slightly longer or shorter slots.
+    pub fn puzzle_at(&self, instant: DateTime<Utc>) -> (NaiveDate, u32) {
+        let date = self.date_of(instant);
+        if self.puzzles_per_day == 1 {
+            return (date, 0);
+        }
+
+        let start = self.day_start(date);
+        let day_length = self.day_start(date.succ_opt().unwrap_or(date)) - start;
+        let elapsed = instant - start;
+        let index = elapsed.num_seconds() * i64::from(self.puzzles_per_day)
+            / day_length.num_seconds().max(1);
+
+        (
+            date,
+            index.clamp(0, i64::from(self.puzzles_per_day - 1)) as u32,
+        )
+    }
+
+    /// Get the current date and puzzle index
+    pub fn current_puzzle(&self) -> (NaiveDate, u32) {
+        self.puzzle_at(Utc::now())
+    }
+
+    /// Get the instant a puzzle starts in the daily reset timezone
+    ///
+    /// An index of `puzzles_per_day` gives the start of the next day.
+    pub fn puzzle_start(&self, date: NaiveDate, index: u32) -> DateTime<Utc> {
+        let start = self.day_start(date);
+        if index == 0 {
+            return start;
+        }
+
+        let day_length = self.day_start(date.succ_opt().unwrap_or(date)) - start;
+        start + day_length * index as i32 / self.puzzles_per_day as i32
+    }
+
+    /// Get the current puzzle's word for external use
     pub fn select_daily_word(&self) -> String {
         self.get_daily_word()
     }
 
+    /// Get the current puzzle's word in a language, `None` meaning the default one
+    pub fn select_daily_word_in(&self, language: Option<&str>) -> String {
+        let (date, index) = self.current_puzzle();
+        self.word_for_puzzle_in(date, index, language)
+    }
+
+    /// Get the word of a date's first puzzle
+    ///
+    /// Bypasses the daily word cache: archive and schedule lookups reach any
+    /// past date, and caching them would grow the cache without bound.
+    pub fn word_for_date(&self, date: NaiveDate) -> String {
+        self.generate_word_from_date(date, 0, None)
+    }
+
+    /// Get the word of one of a date's puzzles
+    pub fn word_for_puzzle(&self, date: NaiveDate, index: u32) -> String {
+        self.word_for_puzzle_in(date, index, None)
+    }
+
+    /// Get the word of one of a date's puzzles in a language
+    pub fn word_for_puzzle_in(
+        &self,
+        date: NaiveDate,
+        index: u32,
+        language: Option<&str>,
+    ) -> String {
+        // Acquire the mutex of the daily word cache
+        let mut cache = DAILY_WORD_CACHE.lock();
+
+        // Return cached word if available, otherwise generate and cache
+        let key = (
+            date,
+            index,
+            self.word_length,
+            self.version,
+            language.map(str::to_string),
+        );
+        cache
+            .entry(key)
+            .or_insert_with(|| self.generate_word_from_date(date, index, language))
+            .clone()
+    }
+
+    /// Build the schedule of daily words from `from` to `to` inclusive
+    ///
+    /// Only dates up to today are allowed so future words are never revealed.
+    pub fn word_schedule(&self, from: NaiveDate, to: NaiveDate) -> Result<WordSchedule, GameError> {
+        if to > self.today() {
+            return Err(GameError::BadRequest(
+                "Schedule cannot include future dates".into(),
+            ));
+        }
+        if from > to {
+            return Err(GameError::BadRequest(
+                "Schedule start must not be after its end".into(),
+            ));
+        }
+        if to.signed_duration_since(from).num_days() >= MAX_SCHEDULE_DAYS {
+            return Err(GameError::BadRequest(format!(
+                "Schedule cannot span more than {} days",
+                MAX_SCHEDULE_DAYS
+            )));
+        }
+
+        let entries = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| ScheduleEntry {
+                date,
+                word: self.word_for_date(date),
+            })
+            .collect();
+
+        Ok(WordSchedule {
+            word_length: self.word_length,
+            entries,
+        })
+    }
+
+    /// Select a random word, independent of the daily word
+    pub fn select_random_word(&self) -> String {
+        self.answer_list
+            .choose(&mut rand::rng())
+            .unwrap_or(&"hello".to_string())
+            .clone()
+    }
+
     /// Make a guess in a game
     pub fn make_guess(&self, game: &mut Game, guess_word: &str) -> Result<(), GameError> {
         // Check if the game is already completed
@@ -48,32 +540,57 @@ impl GameService {
             return Err(GameError::GameCompleted);
         }
 
-        // Convert to lowercase
-        let guess_word_lower = guess_word.to_lowercase();
+        // Normalize so lengths and positions count characters, not bytes
+        let guess_word_lower = normalize_word(guess_word);
+
+        // Reject digits, punctuation and inner spaces before counting letters
+        if !guess_word_lower.chars().all(char::is_alphabetic) {
+            return Err(GameError::InvalidGuess(InvalidGuessReason::NotLetters));
+        }
 
         // Check if the word has the correct length
-        if guess_word_lower.chars().count() != self.word_length {
-            return Err(GameError::InvalidWord(format!(
-                "Word must be {} letters",
-                self.word_length
-            )));
+        let expected = self.word_length;
+        let actual = guess_word_lower.chars().count();
+        if actual < expected {
+            return Err(GameError::InvalidGuess(InvalidGuessReason::TooShort {
+                expected,
+                actual,
+            }));
+        }
+        if actual > expected {
+            return Err(GameError::InvalidGuess(InvalidGuessReason::TooLong {
+                expected,
+                actual,
+            }));
         }
 
-        // Check if the word is valid
-        if !self.is_valid_word(&guess_word_lower) {
-            return Err(GameError::InvalidWord(format!(
-                "Not in word list: {}",
-                guess_word_lower
+        // Check if the word is valid in the game's language (the answer always
+        // is, even if the dictionary changed)
+        if guess_word_lower != game.word
+            && !self.is_valid_word_in(&guess_word_lower, game.language.as_deref())
+        {
+            return Err(GameError::InvalidGuess(InvalidGuessReason::NotInWordList(
+                guess_word_lower,
             )));
         }
 
+        // In hard mode, the guess must use all hints revealed so far
+        if game.hard_mode {
+            self.check_hard_mode(game, &guess_word_lower)?;
+        }
+
         // Evaluate the guess
         let results = self.evaluate_guess(&game.word, &guess_word_lower);
 
+        // Mastermind scoring reports only the counts of the per-letter results
+        let score = (game.scoring == ScoringMode::Mastermind)
+            .then(|| MastermindResult::from_results(&results));
+
         // Create the guess
         let guess = Guess {
             word: guess_word_lower.clone(),
             results,
+            score,
             created_at: Utc::now(),
         };
 
@@ -92,42 +609,215 @@ impl GameService {
         Ok(())
     }
 
-    /// Get today's word. All users get the same word on the same date.
-    fn get_daily_word(&self) -> String {
-        let today = Utc::now().date_naive();
+    /// Get the words still consistent with every guess made so far
+    ///
+    /// Uses the precomputed letter indexes to narrow the word list, then confirms
+    /// each remaining word by re-evaluating the guesses against it.
+    pub fn remaining_candidates(&self, guesses: &[Guess]) -> Vec<String> {
+        self.index
+            .filter(guesses)
+            .iter()
+            .map(|i| &self.word_list[i])
+            .filter(|word| self.is_consistent(word, guesses))
+            .cloned()
+            .collect()
+    }
 
-        // Acquire the mutex of the daily word cache
-        let mut cache = DAILY_WORD_CACHE.lock();
+    /// Get the words still consistent with every guess by scanning the whole word list
+    ///
+    /// Reference implementation for `remaining_candidates`.
+    pub fn remaining_candidates_scan(&self, guesses: &[Guess]) -> Vec<String> {
+        self.word_list
+            .iter()
+            .filter(|word| self.is_consistent(word, guesses))
+            .cloned()
+            .collect()
+    }
 
-        // Return cached word if available, otherwise generate and cache
-        cache
-            .entry(today)
-            .or_insert_with(|| self.generate_word_from_date(today))
-            .clone()
+    /// Get the possible answers consistent with every guess made so far
+    pub fn filter_candidates(&self, guesses: &[Guess]) -> Vec<String> {
+        self.filter_candidates_in(guesses, None)
+    }
+
+    /// Get the possible answers in a language consistent with every guess made so far
+    ///
+    /// Unlike `remaining_candidates`, only words that can be chosen as answers
+    /// are considered.
+    pub fn filter_candidates_in(&self, guesses: &[Guess], language: Option<&str>) -> Vec<String> {
+        self.answers_in(language)
+            .iter()
+            .filter(|word| self.is_consistent(word, guesses))
+            .cloned()
+            .collect()
+    }
+
+    /// Reveal one correct letter position the player has not yet seen
+    ///
+    /// Returns the revealed position. A game won after using a power-up costs
+    /// the player a streak point instead of extending the streak.
+    pub fn reveal_green(&self, game: &mut Game) -> Result<usize, GameError> {
+        if game.is_completed() {
+            return Err(GameError::GameCompleted);
+        }
+
+        if game.powerups_used >= MAX_REVEAL_POWERUPS {
+            return Err(GameError::BadRequest(format!(
+                "No more than {} reveals allowed per game",
+                MAX_REVEAL_POWERUPS
+            )));
+        }
+
+        // Positions already known from revealed guesses or earlier power-ups
+        let known = |pos: usize| {
+            game.revealed_positions.contains(&pos)
+                || game.guesses[..game.revealed_guess_count()]
+                    .iter()
+                    .any(|guess| guess.results.get(pos) == Some(&LetterResult::Correct))
+        };
+
+        let position = (0..game.word.chars().count())
+            .find(|&pos| !known(pos))
+            .ok_or_else(|| {
+                GameError::BadRequest("All letter positions are already known".into())
+            })?;
+
+        game.revealed_positions.push(position);
+        game.powerups_used += 1;
+        game.updated_at = Utc::now();
+
+        Ok(position)
     }
 
-    /// Generate a word using the date as a seed
-    fn generate_word_from_date(&self, date: NaiveDate) -> String {
+    /// Check whether a word would have produced the results of every guess
+    fn is_consistent(&self, word: &str, guesses: &[Guess]) -> bool {
+        guesses
+            .iter()
+            .all(|g| self.evaluate_guess(word, &g.word) == g.results)
+    }
+
+    /// Get the current puzzle's word. All users get the same word in the same puzzle.
+    fn get_daily_word(&self) -> String {
+        let (date, index) = self.current_puzzle();
+        self.word_for_puzzle(date, index)
+    }
+
+    /// Generate a word in a language using the date and puzzle index as a seed
+    fn generate_word_from_date(
+        &self,
+        date: NaiveDate,
+        index: u32,
+        language: Option<&str>,
+    ) -> String {
         // Create a seed from the date (combining year, month, day)
-        let seed = (date.year() as u64 * 10000) + (date.month() as u64 * 100) + date.day() as u64;
+        let mut seed =
+            (date.year() as u64 * 10000) + (date.month() as u64 * 100) + date.day() as u64;
+
+        // Fold in later puzzles' index, keeping the first puzzle's word unchanged
+        if index > 0 {
+            seed = seed * 100 + u64::from(index);
+        }
 
+        self.word_from_seed_in(seed, language)
+    }
+
+    /// Select a word reproducibly from a player-supplied seed
+    ///
+    /// The seed is hashed with a domain tag before seeding the generator, so
+    /// no seed reproduces the generator state of a daily puzzle.
+    pub fn word_from_seed(&self, seed: u64) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(SEEDED_GAME_DOMAIN);
+        hasher.update(seed.to_be_bytes());
+
+        let mut rng = StdRng::from_seed(hasher.finalize().into());
+        self.choose_answer(&mut rng, None)
+    }
+
+    /// Select a word in a language reproducibly from a seed
+    fn word_from_seed_in(&self, seed: u64, language: Option<&str>) -> String {
         // Initialize random number generator from the seed
         let mut rng = StdRng::seed_from_u64(seed);
+        self.choose_answer(&mut rng, language)
+    }
 
-        // Randomly select a word from the word list
-        self.word_list
-            .choose(&mut rng)
+    /// Choose a word from a language's answer list
+    fn choose_answer(&self, rng: &mut StdRng, language: Option<&str>) -> String {
+        // Randomly select a word from the answer list
+        self.answers_in(language)
+            .choose(rng)
             .unwrap_or(&"hello".to_string())
             .clone()
     }
 
-    /// Check if a word is valid
-    fn is_valid_word(&self, word: &str) -> bool {
+    /// Check if a lowercase word is in the dictionary
+    pub fn is_valid_word(&self, word: &str) -> bool {
         self.word_list.contains(&word.to_string())
     }
 
+    /// Check if a lowercase word is in a language's dictionary
+    ///
+    /// The default dictionary is used when `language` is `None` or unknown.
+    pub fn is_valid_word_in(&self, word: &str, language: Option<&str>) -> bool {
+        match language.and_then(|language| self.languages.get(language)) {
+            Some(words) => words.iter().any(|w| w == word),
+            None => self.is_valid_word(word),
+        }
+    }
+
+    /// Check that a guess uses every hint revealed by previous guesses
+    fn check_hard_mode(&self, game: &Game, guess: &str) -> Result<(), GameError> {
+        let guess_chars: Vec<char> = guess.chars().collect();
+
+        // Only hints the player has actually seen are enforced
+        for previous in &game.guesses[..game.revealed_guess_count()] {
+            let previous_chars: Vec<char> = previous.word.chars().collect();
+
+            // Correct letters must stay in place
+            for (i, (&c, result)) in previous_chars.iter().zip(&previous.results).enumerate() {
+                if *result == LetterResult::Correct && guess_chars.get(i) != Some(&c) {
+                    return Err(GameError::HardModeViolation(format!(
+                        "Letter {} must be {}",
+                        i + 1,
+                        c.to_uppercase()
+                    )));
+                }
+            }
+
+            // Revealed letters must be reused at least as many times as revealed
+            let mut required_counts = HashMap::new();
+            for (&c, result) in previous_chars.iter().zip(&previous.results) {
+                if *result != LetterResult::Wrong {
+                    *required_counts.entry(c).or_insert(0) += 1;
+                }
+            }
+
+            for (c, required) in required_counts {
+                let used = guess_chars.iter().filter(|&&g| g == c).count();
+                if used < required {
+                    return Err(GameError::HardModeViolation(format!(
+                        "Guess must contain {}",
+                        c.to_uppercase()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Expose `evaluate_guess` to the known-answer tests
+    #[cfg(test)]
+    pub(crate) fn evaluate_guess_for_test(&self, target: &str, guess: &str) -> Vec<LetterResult> {
+        self.evaluate_guess(target, guess)
+    }
+
     /// Evaluate a guess against the target word
     /// Returns a vector of LetterResult indicating the status of each letter
+    ///
+    /// Exact matches are marked first; repeated guess letters then claim the
+    /// target's remaining copies from left to right, so copies beyond the
+    /// target's count are `Wrong` (guessing "allee" for "apple" marks only the
+    /// first `l` as `WrongPosition`).
     fn evaluate_guess(&self, target: &str, guess: &str) -> Vec<LetterResult> {
         let target_chars: Vec<char> = target.chars().collect();
         let guess_chars: Vec<char> = guess.chars().collect();
@@ -154,13 +844,12 @@ impl GameService {
 
         // Second pass: Mark wrong position matches
         for (i, &c) in guess_chars.iter().enumerate() {
-            if results[i] != LetterResult::Correct {
-                if let Some(count) = remaining_counts.get_mut(&c) {
-                    if *count > 0 {
-                        results[i] = LetterResult::WrongPosition;
-                        *count -= 1;
-                    }
-                }
+            if results[i] != LetterResult::Correct
+                && let Some(count) = remaining_counts.get_mut(&c)
+                && *count > 0
+            {
+                results[i] = LetterResult::WrongPosition;
+                *count -= 1;
             }
         }
 
@@ -171,6 +860,391 @@ impl GameService {
 // Implement Default for GameService
 impl Default for GameService {
     fn default() -> Self {
-        Self::new()
+        Self::new(DEFAULT_WORD_LENGTH).expect("built-in word list exists for the default length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::signing::ResultSigner;
+
+    /// Known answers as (target, guess, results), results written as `g`/`y`/`x`
+    const KNOWN_ANSWERS: &[(&str, &str, &str)] = &[
+        ("apple", "apple", "ggggg"),
+        ("crane", "ghoul", "xxxxx"),
+        ("plant", "plane", "ggggx"),
+        ("crane", "nacre", "yyyyg"),
+        // Repeated guess letter, single target letter
+        ("apple", "allee", "gyxxg"),
+        ("otter", "tatty", "yxgxx"),
+        ("lever", "eerie", "ygyxx"),
+        // Repeated guess letter, repeated target letter
+        ("hello", "llama", "yyxxx"),
+        ("hello", "lolly", "xyggx"),
+        ("mummy", "mamma", "gxggx"),
+        // Repeated target letter, single guess letter
+        ("abbey", "kebab", "xygyy"),
+        ("abbey", "babes", "yyggx"),
+        ("those", "geese", "xxxgg"),
+        ("geese", "those", "xxxgg"),
+        ("speed", "abide", "xxxyy"),
+    ];
+
+    fn results(pattern: &str) -> Vec<LetterResult> {
+        pattern
+            .chars()
+            .map(|c| LetterResult::from_char(c).expect("pattern uses g, y or x"))
+            .collect()
+    }
+
+    #[test]
+    fn evaluate_guess_known_answers() {
+        let service = GameService::default();
+        for &(target, guess, expected) in KNOWN_ANSWERS {
+            assert_eq!(
+                service.evaluate_guess_for_test(target, guess),
+                results(expected),
+                "{} guessed against {}",
+                guess,
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn results_align_to_characters_in_accented_words() {
+        let service = GameService::new_with_lists(vec!["crème".into()], vec!["mèche".into()], 5);
+        let mut game = Game::new("crème".to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+
+        service.make_guess(&mut game, "mèche").unwrap();
+        assert_eq!(game.guesses[0].results, results("yyyxg"));
+
+        let position = service.reveal_green(&mut game).unwrap();
+        // Only the final `e` is green, so a character index below four remains
+        assert!(position < 4);
+    }
+
+    #[test]
+    fn schedule_matches_daily_words_and_refuses_future_dates() {
+        let service = GameService::default();
+        let today = service.today();
+        let from = today - Days::new(6);
+
+        let schedule = service.word_schedule(from, today).unwrap();
+        assert_eq!(schedule.entries.len(), 7);
+        assert_eq!(schedule.entries[0].date, from);
+        for entry in &schedule.entries {
+            assert_eq!(entry.word, service.word_for_date(entry.date));
+        }
+        assert_eq!(schedule.entries[6].word, service.select_daily_word());
+
+        let signer = ResultSigner::new("result-signing-key");
+        assert!(signer.verify_schedule(&schedule, &signer.sign_schedule(&schedule)));
+
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(matches!(
+            service.word_schedule(from, tomorrow),
+            Err(GameError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn mastermind_scores_count_exact_and_misplaced_letters() {
+        let service = GameService::default();
+        // (target, guess, exact, misplaced), including repeated letters
+        let cases = [
+            ("plant", "plane", 4, 0),
+            ("apple", "allow", 1, 1),
+            ("hello", "level", 1, 2),
+            ("speed", "steel", 3, 0),
+            ("trade", "house", 1, 0),
+        ];
+
+        for (target, guess, exact, misplaced) in cases {
+            let mut game = Game::new(target.to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+            game.scoring = ScoringMode::Mastermind;
+            service.make_guess(&mut game, guess).unwrap();
+
+            assert_eq!(
+                game.guesses[0].score,
+                Some(MastermindResult { exact, misplaced }),
+                "{} guessed against {}",
+                guess,
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn guess_only_words_are_never_answers() {
+        let service = GameService::new_with_lists(
+            vec!["plant".into(), "house".into()],
+            vec!["trade".into()],
+            5,
+        );
+        assert!(service.is_valid_word("trade"));
+
+        for seed in 0..1000 {
+            assert_ne!(service.word_from_seed(seed), "trade", "seed {}", seed);
+        }
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        for date in start.iter_days().take(365) {
+            assert_ne!(service.word_for_date(date), "trade", "{}", date);
+        }
+    }
+
+    #[test]
+    fn guesses_are_trimmed_and_must_be_letters() {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+
+        service.make_guess(&mut game, "  House\n").unwrap();
+        assert_eq!(game.guesses[0].word, "house");
+
+        assert!(matches!(
+            service.make_guess(&mut game, "hous3"),
+            Err(GameError::InvalidGuess(InvalidGuessReason::NotLetters))
+        ));
+        assert_eq!(game.guesses.len(), 1);
+    }
+
+    #[test]
+    fn puzzle_slots_on_one_date_get_different_words() {
+        let service = GameService::default().with_puzzles_per_day(4);
+        let date = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let at = |hour| date.and_hms_opt(hour, 0, 0).unwrap().and_utc();
+
+        assert_eq!(service.puzzle_at(at(1)), (date, 0));
+        assert_eq!(service.puzzle_at(at(13)), (date, 2));
+        assert_ne!(
+            service.word_for_puzzle(date, 0),
+            service.word_for_puzzle(date, 2)
+        );
+    }
+
+    #[test]
+    fn puzzle_numbers_increment_by_day() {
+        assert_eq!(puzzle_number(PUZZLE_EPOCH), 0);
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+        for date in start.iter_days().take(5) {
+            let next = date.succ_opt().unwrap();
+            assert_eq!(puzzle_number(next), puzzle_number(date) + 1);
+            assert_eq!(puzzle_date(puzzle_number(date)), Some(date));
+        }
+    }
+
+    #[test]
+    fn daily_word_follows_the_reset_timezone() {
+        let utc = GameService::default();
+        let tokyo = GameService::default().with_timezone(chrono_tz::Asia::Tokyo);
+
+        // Shortly before UTC midnight it is already the next day in Tokyo
+        let instant = NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .and_hms_opt(23, 30, 0)
+            .unwrap()
+            .and_utc();
+        let today = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let tomorrow = today.succ_opt().unwrap();
+        assert_eq!(utc.puzzle_at(instant), (today, 0));
+        assert_eq!(tokyo.puzzle_at(instant), (tomorrow, 0));
+
+        // A date has the same word wherever it is played
+        assert_eq!(utc.word_for_date(tomorrow), tokyo.word_for_date(tomorrow));
+        assert_eq!(
+            tokyo.day_start(tomorrow),
+            instant - chrono::Duration::minutes(510)
+        );
+    }
+
+    #[test]
+    fn same_seed_gives_same_word() {
+        let service = GameService::default();
+        for seed in [0, 1, 42, 20261016, u64::MAX] {
+            assert_eq!(service.word_from_seed(seed), service.word_from_seed(seed));
+        }
+    }
+
+    #[test]
+    fn six_letter_games_reject_five_letter_guesses() {
+        let service = GameService::new(6).unwrap();
+        let mut game = Game::new(
+            service.select_random_word(),
+            MAX_ATTEMPTS,
+            uuid::Uuid::new_v4(),
+        );
+
+        let result = service.make_guess(&mut game, "plant");
+        assert!(matches!(
+            result,
+            Err(GameError::InvalidGuess(InvalidGuessReason::TooShort {
+                expected: 6,
+                actual: 5
+            }))
+        ));
+        service.make_guess(&mut game, "yellow").unwrap();
+    }
+
+    #[test]
+    fn missing_word_file_falls_back_unless_required() {
+        let path = Path::new("/nonexistent/wordle/words.txt");
+
+        let service = GameService::from_word_file(path, DEFAULT_WORD_LENGTH, false).unwrap();
+        assert_eq!(service.word_count(), GameService::default().word_count());
+
+        assert!(GameService::from_word_file(path, DEFAULT_WORD_LENGTH, true).is_err());
+    }
+
+    #[test]
+    fn word_files_replace_the_built_in_list() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+        let service = GameService::from_word_file(&fixtures.join("words.txt"), 5, true).unwrap();
+        assert_eq!(service.word_count(), 5);
+        assert!(service.is_valid_word("blaze"));
+        assert!(!service.is_valid_word("plant"));
+        // The daily word cache is process-wide, so read the uncached lookup
+        let word = service.word_for_date(service.today());
+        assert!(["amber", "blaze", "cider", "dwell", "ember"].contains(&word.as_str()));
+
+        let error = GameService::from_word_file(&fixtures.join("words_wrong_length.txt"), 5, true)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("line 2"), "{}", error);
+    }
+
+    #[test]
+    fn candidates_exclude_letters_marked_wrong() {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+        service.make_guess(&mut game, "house").unwrap();
+        assert_eq!(game.guesses[0].results, results("xxxxx"));
+
+        let candidates = service.remaining_candidates(&game.guesses);
+        assert!(candidates.iter().any(|word| word == "plant"));
+        for word in &candidates {
+            assert!(!word.contains(['h', 'o', 'u', 's', 'e']), "{}", word);
+        }
+    }
+
+    #[test]
+    fn indexed_candidates_match_the_full_scan() {
+        let service = GameService::default();
+        let games: &[(&str, &[&str])] = &[
+            ("plant", &[]),
+            ("plant", &["house"]),
+            ("plant", &["plate", "plane"]),
+            ("apple", &["allow", "apply"]),
+            ("hello", &["below", "level"]),
+            ("speed", &["steel", "sheet", "spend"]),
+            ("mouse", &["trade", "house"]),
+        ];
+
+        for (target, guesses) in games {
+            let mut game = Game::new(target.to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+            for guess in *guesses {
+                service.make_guess(&mut game, guess).unwrap();
+            }
+
+            let indexed = service.remaining_candidates(&game.guesses);
+            assert_eq!(indexed, service.remaining_candidates_scan(&game.guesses));
+            assert!(indexed.iter().any(|word| word == target));
+        }
+    }
+
+    /// Hard-mode game for "plant" after guessing "plate" (P, L, A green; T yellow)
+    fn hard_mode_game(service: &GameService) -> Game {
+        let mut game = Game::new("plant".to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+        game.hard_mode = true;
+        service.make_guess(&mut game, "plate").unwrap();
+        game
+    }
+
+    #[test]
+    fn hard_mode_rejects_a_guess_dropping_a_green_letter() {
+        let service = GameService::default();
+        let mut game = hard_mode_game(&service);
+
+        let result = service.make_guess(&mut game, "blast");
+        assert!(
+            matches!(result, Err(GameError::HardModeViolation(msg)) if msg == "Letter 1 must be P")
+        );
+        assert_eq!(game.guesses.len(), 1);
+    }
+
+    #[test]
+    fn hard_mode_rejects_a_guess_omitting_a_yellow_letter() {
+        let service = GameService::default();
+        let mut game = hard_mode_game(&service);
+
+        let result = service.make_guess(&mut game, "place");
+        assert!(
+            matches!(result, Err(GameError::HardModeViolation(msg)) if msg == "Guess must contain T")
+        );
+
+        // Reusing every hint is allowed
+        service.make_guess(&mut game, "plate").unwrap();
+        assert_eq!(game.guesses.len(), 2);
+    }
+
+    #[test]
+    fn reveal_green_reveals_a_correct_position() {
+        let service = GameService::default();
+        let mut game = Game::new("plant".to_string(), MAX_ATTEMPTS, uuid::Uuid::new_v4());
+        service.make_guess(&mut game, "plate").unwrap();
+
+        // "plate" already shows the first three letters of "plant" as green
+        let position = service.reveal_green(&mut game).unwrap();
+        assert_eq!(position, 3);
+        assert_eq!(game.word.chars().nth(position), Some('n'));
+        assert_eq!(game.revealed_positions, vec![3]);
+        assert_eq!(game.powerups_used, 1);
+    }
+
+    #[test]
+    fn archive_words_are_stable_and_not_cached() {
+        let service = GameService::default();
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap();
+
+        assert_eq!(service.word_for_date(date), service.word_for_date(date));
+        assert_eq!(
+            service.word_for_date(date),
+            service.word_for_puzzle(date, 0)
+        );
+
+        let uncached = NaiveDate::from_ymd_opt(2021, 3, 15).unwrap();
+        service.word_for_date(uncached);
+        let cache = DAILY_WORD_CACHE.lock();
+        assert!(cache.keys().all(|(cached, ..)| *cached != uncached));
+    }
+
+    #[test]
+    fn different_seeds_usually_differ() {
+        let service = GameService::default();
+        let words: std::collections::HashSet<String> =
+            (0..20).map(|seed| service.word_from_seed(seed)).collect();
+        assert!(words.len() > 10, "only {} distinct words", words.len());
+    }
+
+    #[test]
+    fn date_seeds_do_not_reproduce_daily_words() {
+        let service = GameService::default();
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let matches = start
+            .iter_days()
+            .take(30)
+            .filter(|date| {
+                let seed =
+                    date.year() as u64 * 10000 + date.month() as u64 * 100 + date.day() as u64;
+                service.word_from_seed(seed) == service.generate_word_from_date(*date, 0, None)
+            })
+            .count();
+        assert!(
+            matches < 3,
+            "{} of 30 date seeds matched the daily word",
+            matches
+        );
     }
 }
@@ -1,8 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use uuid::Uuid;
 
+use crate::common::config::ScoringConfig;
 use crate::game::error::GameError;
+use crate::game::puzzle_number;
 
 /// Game model for storing game state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +31,54 @@ pub struct Game {
     /// Whether the player won
     pub won: bool,
 
+    /// Whether the player gave up before running out of guesses
+    #[serde(default)]
+    pub surrendered: bool,
+
+    /// Whether revealed hints must be used in subsequent guesses
+    #[serde(default)]
+    pub hard_mode: bool,
+
+    /// Number of guesses to commit before their results are revealed (blind mode)
+    #[serde(default)]
+    pub blind_batch_size: Option<u8>,
+
+    /// Whether this is a practice game with a random word outside the daily puzzle
+    #[serde(default)]
+    pub practice: bool,
+
+    /// Seed the word was chosen from, for reproducible custom games
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// Past date whose daily word this archive game replays
+    #[serde(default)]
+    pub archive_date: Option<NaiveDate>,
+
+    /// Number of earlier completed plays of this daily game by the same user
+    #[serde(default)]
+    pub replay_count: u32,
+
+    /// Language whose dictionary the word and guesses come from (the default if unset)
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// How guesses are scored for the player
+    #[serde(default)]
+    pub scoring: ScoringMode,
+
+    /// Number of power-ups used in this game
+    #[serde(default)]
+    pub powerups_used: u8,
+
+    /// Letter positions revealed by power-ups, as character indices into the word
+    #[serde(default)]
+    pub revealed_positions: Vec<usize>,
+
+    /// Number of times the game has been saved, checked to catch concurrent updates
+    #[serde(default)]
+    pub version: u64,
+
     /// When the game was created
     pub created_at: DateTime<Utc>,
 
@@ -35,6 +86,109 @@ pub struct Game {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Headline fields of a game, used where its guesses aren't needed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSummary {
+    /// Unique game identifier
+    pub id: Uuid,
+
+    /// ID of the user who owns this game
+    pub user_id: Uuid,
+
+    /// Whether the game was won
+    pub won: bool,
+
+    /// Number of guesses made
+    pub guess_count: usize,
+
+    /// Maximum number of attempts allowed
+    pub max_attempts: u8,
+
+    /// Whether this is a practice game that doesn't count toward stats
+    pub practice: bool,
+
+    /// Number of times the daily game was replayed before this game
+    pub replay_count: u32,
+
+    /// Number of power-ups used in this game
+    pub powerups_used: u8,
+
+    /// When the game was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the game was last updated
+    pub updated_at: DateTime<Utc>,
+
+    /// Whether the game is completed
+    pub completed: bool,
+}
+
+impl GameSummary {
+    /// Check if the game replays a completed daily game
+    pub fn is_replay(&self) -> bool {
+        self.replay_count > 0
+    }
+
+    /// Check if the game is a daily game that counts toward stats
+    pub fn is_daily(&self) -> bool {
+        !self.practice && !self.is_replay()
+    }
+}
+
+impl From<&Game> for GameSummary {
+    fn from(game: &Game) -> Self {
+        Self {
+            id: game.id,
+            user_id: game.user_id,
+            won: game.won,
+            guess_count: game.guesses.len(),
+            max_attempts: game.max_attempts,
+            practice: game.practice,
+            replay_count: game.replay_count,
+            powerups_used: game.powerups_used,
+            created_at: game.created_at,
+            updated_at: game.updated_at,
+            completed: game.completed,
+        }
+    }
+}
+
+/// How guesses are scored for the player
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ScoringMode {
+    /// Each letter is colored by its position
+    #[default]
+    Wordle,
+
+    /// Only the counts of exact and misplaced letters are shown
+    Mastermind,
+}
+
+/// Aggregate score of a guess under Mastermind scoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MastermindResult {
+    /// Letters in the right position
+    pub exact: usize,
+
+    /// Letters in the word but in the wrong position
+    pub misplaced: usize,
+}
+
+impl MastermindResult {
+    /// Count the exact and misplaced letters of per-letter results
+    pub fn from_results(results: &[LetterResult]) -> Self {
+        let count = |wanted| results.iter().filter(|&&r| r == wanted).count();
+
+        Self {
+            exact: count(LetterResult::Correct),
+            misplaced: count(LetterResult::WrongPosition),
+        }
+    }
+}
+
 /// Guess model for storing a player's guess
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Guess {
@@ -44,23 +198,76 @@ pub struct Guess {
     /// Results for each letter
     pub results: Vec<LetterResult>,
 
+    /// Aggregate score shown instead of the results under Mastermind scoring
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<MastermindResult>,
+
     /// When the guess was made
     pub created_at: DateTime<Utc>,
 }
 
 /// Result for a single letter in a guess
+///
+/// Serialized as `correct`, `wrongposition` and `wrong`; the capitalized names
+/// written by earlier versions are still accepted. Displayed as `g`, `y` and
+/// `x` for compact grids.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
 pub enum LetterResult {
     /// Letter is correct and in the right position
+    #[serde(alias = "Correct")]
     Correct,
 
     /// Letter is in the word but in the wrong position
+    #[serde(alias = "WrongPosition")]
     WrongPosition,
 
     /// Letter is not in the word
+    #[serde(alias = "Wrong")]
     Wrong,
 }
 
+impl LetterResult {
+    /// Combine two results for the same letter, keeping the most informative
+    ///
+    /// `Correct` beats `WrongPosition`, which beats `Wrong`.
+    pub fn best(self, other: LetterResult) -> LetterResult {
+        match (self, other) {
+            (LetterResult::Correct, _) | (_, LetterResult::Correct) => LetterResult::Correct,
+            (LetterResult::WrongPosition, _) | (_, LetterResult::WrongPosition) => {
+                LetterResult::WrongPosition
+            }
+            _ => LetterResult::Wrong,
+        }
+    }
+
+    /// Get the short form of the result, `g`, `y` or `x`
+    pub fn as_char(self) -> char {
+        match self {
+            LetterResult::Correct => 'g',
+            LetterResult::WrongPosition => 'y',
+            LetterResult::Wrong => 'x',
+        }
+    }
+
+    /// Parse the short form produced by `Display`, ignoring case
+    pub fn from_char(c: char) -> Option<LetterResult> {
+        match c.to_ascii_lowercase() {
+            'g' => Some(LetterResult::Correct),
+            'y' => Some(LetterResult::WrongPosition),
+            'x' => Some(LetterResult::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LetterResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
 impl Game {
     /// Create a new game
     pub fn new(word: String, max_attempts: u8, user_id: Uuid) -> Self {
@@ -74,11 +281,74 @@ impl Game {
             guesses: Vec::new(),
             completed: false,
             won: false,
+            surrendered: false,
+            hard_mode: false,
+            blind_batch_size: None,
+            practice: false,
+            seed: None,
+            archive_date: None,
+            replay_count: 0,
+            language: None,
+            scoring: ScoringMode::Wordle,
+            powerups_used: 0,
+            revealed_positions: Vec::new(),
+            version: 0,
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Check that the game's options can be combined
+    pub fn validate_options(&self) -> Result<(), GameError> {
+        // Hard mode enforces positional hints that Mastermind scoring never shows
+        if self.hard_mode && self.scoring == ScoringMode::Mastermind {
+            return Err(GameError::BadRequest(
+                "Hard mode is not available with Mastermind scoring".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check if this is a daily game rather than a practice or seeded one
+    pub fn is_daily(&self) -> bool {
+        !self.practice && self.seed.is_none()
+    }
+
+    /// Check if this is a replay of a daily game the user already completed
+    pub fn is_replay(&self) -> bool {
+        self.replay_count > 0
+    }
+
+    /// Check if both games are the same play of a user's daily game for the same word
+    pub fn is_same_daily_game(&self, other: &Game) -> bool {
+        self.is_daily()
+            && other.is_daily()
+            && self.user_id == other.user_id
+            && self.word == other.word
+            && self.replay_count == other.replay_count
+    }
+
+    /// Check if both games are a user's archive game for the same date
+    pub fn is_same_archive_game(&self, other: &Game) -> bool {
+        self.archive_date.is_some()
+            && self.archive_date == other.archive_date
+            && self.user_id == other.user_id
+    }
+
+    /// Get the milliseconds elapsed since the previous guess for each guess
+    pub fn guess_intervals_ms(&self) -> Vec<Option<u64>> {
+        let previous = std::iter::once(None).chain(self.guesses.iter().map(Some));
+        self.guesses
+            .iter()
+            .zip(previous)
+            .map(|(guess, previous)| {
+                let elapsed = guess.created_at - previous?.created_at;
+                Some(elapsed.num_milliseconds().max(0) as u64)
+            })
+            .collect()
+    }
+
     /// Check if the game is completed
     pub fn is_completed(&self) -> bool {
         self.completed
@@ -89,6 +359,99 @@ impl Game {
         self.max_attempts.saturating_sub(self.guesses.len() as u8)
     }
 
+    /// Get the number of guesses whose results are visible to the player
+    ///
+    /// In blind mode, results are withheld until a full batch of guesses
+    /// has been submitted or the game ends.
+    pub fn revealed_guess_count(&self) -> usize {
+        match self.blind_batch_size {
+            Some(batch) if batch > 0 && !self.completed => {
+                let batch = batch as usize;
+                self.guesses.len() / batch * batch
+            }
+            _ => self.guesses.len(),
+        }
+    }
+
+    /// Get the points earned by a won game, or `None` if it wasn't won
+    ///
+    /// Fewer guesses score higher, with bonuses for hard mode and for not
+    /// using any power-ups.
+    pub fn score(&self, weights: &ScoringConfig) -> Option<u32> {
+        if !self.won {
+            return None;
+        }
+
+        let extra_guesses = self.guesses.len().saturating_sub(1) as u32;
+        let mut score = weights
+            .base_points
+            .saturating_sub(extra_guesses.saturating_mul(weights.attempt_penalty));
+        if self.hard_mode {
+            score = score.saturating_add(weights.hard_mode_bonus);
+        }
+        if self.powerups_used == 0 {
+            score = score.saturating_add(weights.no_hint_bonus);
+        }
+
+        Some(score)
+    }
+
+    /// Render the guesses as rows of colored-square emoji
+    pub fn emoji_grid(&self) -> String {
+        self.guesses
+            .iter()
+            .map(|guess| {
+                guess
+                    .results
+                    .iter()
+                    .map(|result| match result {
+                        LetterResult::Correct => '🟩',
+                        LetterResult::WrongPosition => '🟨',
+                        LetterResult::Wrong => '⬛',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render the shareable result text, e.g. `Wordle 1234 4/6` followed by the grid
+    pub fn share_grid(&self) -> String {
+        let score = if self.won {
+            self.guesses.len().to_string()
+        } else {
+            "X".to_string()
+        };
+
+        let puzzle = match (self.seed, self.archive_date) {
+            (Some(seed), _) => format!("Seed {}", seed),
+            (None, Some(date)) => format!("{} (archive)", puzzle_number(date)),
+            (None, None) if self.practice => "Practice".to_string(),
+            (None, None) => puzzle_number(self.created_at.date_naive()).to_string(),
+        };
+
+        format!(
+            "Wordle {} {}/{}\n\n{}",
+            puzzle,
+            score,
+            self.max_attempts,
+            self.emoji_grid()
+        )
+    }
+
+    /// Give up the game, ending it as a loss so the word is revealed
+    pub fn surrender(&mut self) -> Result<(), GameError> {
+        if self.completed {
+            return Err(GameError::GameCompleted);
+        }
+
+        self.completed = true;
+        self.won = false;
+        self.surrendered = true;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Add a guess to the game
     pub fn add_guess(&mut self, guess: Guess) -> Result<(), GameError> {
         if self.attempts_remaining() == 0 {
@@ -100,3 +463,68 @@ impl Game {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::played_game;
+
+    #[test]
+    fn share_grid_has_a_header_and_a_row_per_guess() {
+        let mut game = played_game(Uuid::new_v4(), "plant", &["house", "plate", "plant"]);
+        game.practice = true;
+
+        let share = game.share_grid();
+        let (header, grid) = share.split_once("\n\n").unwrap();
+        assert_eq!(header, "Wordle Practice 3/6");
+
+        let rows: Vec<&str> = grid.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1], "🟩🟩🟩🟨⬛");
+        assert_eq!(rows[2], "🟩🟩🟩🟩🟩");
+    }
+
+    #[test]
+    fn letter_results_round_trip_in_new_and_legacy_forms() {
+        let cases = [
+            (LetterResult::Correct, "correct", "Correct", 'g'),
+            (
+                LetterResult::WrongPosition,
+                "wrongposition",
+                "WrongPosition",
+                'y',
+            ),
+            (LetterResult::Wrong, "wrong", "Wrong", 'x'),
+        ];
+
+        for (result, name, legacy, letter) in cases {
+            let json = serde_json::to_string(&result).unwrap();
+            assert_eq!(json, format!("\"{}\"", name));
+            assert_eq!(serde_json::from_str::<LetterResult>(&json).unwrap(), result);
+            assert_eq!(
+                serde_json::from_str::<LetterResult>(&format!("\"{}\"", legacy)).unwrap(),
+                result
+            );
+
+            assert_eq!(result.to_string(), letter.to_string());
+            assert_eq!(LetterResult::from_char(letter), Some(result));
+        }
+        assert_eq!(LetterResult::from_char('q'), None);
+    }
+
+    #[test]
+    fn fewer_guesses_and_no_hints_score_higher() {
+        let weights = ScoringConfig::default();
+        let user_id = Uuid::new_v4();
+
+        let first_try = played_game(user_id, "plant", &["plant"]);
+        let second_try = played_game(user_id, "plant", &["house", "plant"]);
+        let lost = played_game(user_id, "plant", &["house"]);
+        assert!(first_try.score(&weights) > second_try.score(&weights));
+        assert_eq!(lost.score(&weights), None);
+
+        let mut hinted = second_try.clone();
+        hinted.powerups_used = 1;
+        assert!(hinted.score(&weights) < second_try.score(&weights));
+    }
+}
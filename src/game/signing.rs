@@ -0,0 +1,165 @@
+use chrono::NaiveDate;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::game::models::Game;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Summary of a completed game covered by a result signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultPayload {
+    /// Game identifier
+    pub game_id: Uuid,
+
+    /// User who played the game
+    pub user_id: Uuid,
+
+    /// Whether the game was won
+    pub won: bool,
+
+    /// Number of guesses made
+    pub guess_count: usize,
+
+    /// Date the game was played
+    pub date: NaiveDate,
+}
+
+impl ResultPayload {
+    /// Build the payload for a game
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            game_id: game.id,
+            user_id: game.user_id,
+            won: game.won,
+            guess_count: game.guesses.len(),
+            date: game.created_at.date_naive(),
+        }
+    }
+
+    /// Canonical byte representation that is signed
+    fn canonical(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.game_id, self.user_id, self.won, self.guess_count, self.date
+        )
+    }
+}
+
+/// Daily word of a single date in a published schedule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    /// Date the word was played
+    pub date: NaiveDate,
+
+    /// Daily word of the date
+    pub word: String,
+}
+
+/// Daily words over a range of past dates covered by a schedule signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordSchedule {
+    /// Length of the scheduled words
+    pub word_length: usize,
+
+    /// Daily words in date order
+    pub entries: Vec<ScheduleEntry>,
+}
+
+impl WordSchedule {
+    /// Canonical byte representation that is signed
+    fn canonical(&self) -> String {
+        let entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|entry| format!("{}:{}", entry.date, entry.word))
+            .collect();
+
+        format!("schedule|{}|{}", self.word_length, entries.join(","))
+    }
+}
+
+/// Signs and verifies game results and word schedules with HMAC-SHA256
+#[derive(Clone)]
+pub struct ResultSigner {
+    key: Vec<u8>,
+}
+
+impl ResultSigner {
+    /// Create a signer with the given secret key
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// Sign a payload, returning the hex-encoded signature
+    pub fn sign(&self, payload: &ResultPayload) -> String {
+        self.sign_message(&payload.canonical())
+    }
+
+    /// Check a hex-encoded signature against a payload in constant time
+    pub fn verify(&self, payload: &ResultPayload, signature: &str) -> bool {
+        self.verify_message(&payload.canonical(), signature)
+    }
+
+    /// Sign a word schedule, returning the hex-encoded signature
+    pub fn sign_schedule(&self, schedule: &WordSchedule) -> String {
+        self.sign_message(&schedule.canonical())
+    }
+
+    /// Check a hex-encoded signature against a word schedule in constant time
+    pub fn verify_schedule(&self, schedule: &WordSchedule, signature: &str) -> bool {
+        self.verify_message(&schedule.canonical(), signature)
+    }
+
+    fn sign_message(&self, message: &str) -> String {
+        let mut mac = self.mac();
+        mac.update(message.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn verify_message(&self, message: &str, signature: &str) -> bool {
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+
+        let mut mac = self.mac();
+        mac.update(message.as_bytes());
+        mac.verify_slice(&signature).is_ok()
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length")
+    }
+}
+
+impl std::fmt::Debug for ResultSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResultSigner").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::played_game;
+
+    #[test]
+    fn genuine_signatures_verify_and_tampered_payloads_fail() {
+        let signer = ResultSigner::new("result-signing-key");
+        let game = played_game(Uuid::new_v4(), "plant", &["house", "plant"]);
+        let payload = ResultPayload::from_game(&game);
+        let signature = signer.sign(&payload);
+
+        assert!(signer.verify(&payload, &signature));
+
+        let tampered = ResultPayload {
+            guess_count: 1,
+            ..payload.clone()
+        };
+        assert!(!signer.verify(&tampered, &signature));
+        assert!(!ResultSigner::new("another-key").verify(&payload, &signature));
+        assert!(!signer.verify(&payload, "not-hex"));
+    }
+}
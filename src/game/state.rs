@@ -1,32 +1,81 @@
-use chrono::{DateTime, Local};
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use parking_lot::RwLock;
+use std::collections::HashSet;
+use std::future::Future;
 use std::sync::Arc;
+use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::common::config::GameConfig;
 use crate::game::GameService;
-use crate::models::Game;
+use crate::game::error::GameError;
+use crate::game::events::{CompositeSink, GameAbandonedEvent, GameCompletedEvent, GameEventSink};
+use crate::game::signing::{ResultPayload, ResultSigner};
+use crate::models::{Game, GameSummary};
 use crate::repository::GameRepositoryTrait;
-use crate::repository::error::RepositoryResult;
+use crate::repository::deadline::with_deadline;
+use crate::repository::error::{RepositoryError, RepositoryResult};
 
 /// Game state that will be shared across routes
 pub struct GameState {
     /// Repository for game data access
     game_repository: Arc<dyn GameRepositoryTrait + Send + Sync>,
 
-    /// Last date check (for daily word refresh)
-    last_date_check: RwLock<DateTime<Local>>,
+    /// Date and puzzle index at the last check (for daily word refresh)
+    last_puzzle_check: RwLock<(NaiveDate, u32)>,
 
-    /// Game service for game logic
-    game_service: GameService,
+    /// Held while a daily reset runs, so concurrent checks wait for it
+    reset_lock: Mutex<()>,
+
+    /// Game service for game logic, swapped when the dictionary is replaced
+    game_service: ArcSwap<GameService>,
+
+    /// Game configuration
+    config: GameConfig,
+
+    /// Signer for completed game results (if enabled)
+    result_signer: Option<ResultSigner>,
+
+    /// Users who created or played a daily game today
+    active_players: RwLock<HashSet<Uuid>>,
+
+    /// Integrations notified when games complete
+    event_sink: CompositeSink,
 }
 
 impl GameState {
     /// Create a new game state with provided repository
-    pub fn new(game_repository: Arc<dyn GameRepositoryTrait + Send + Sync>) -> Self {
-        Self {
+    pub fn new(
+        game_repository: Arc<dyn GameRepositoryTrait + Send + Sync>,
+        config: &GameConfig,
+    ) -> anyhow::Result<Self> {
+        let game_service = GameService::from_config(config)?;
+
+        Ok(Self {
             game_repository,
-            last_date_check: RwLock::new(Local::now()),
-            game_service: GameService::new(),
+            last_puzzle_check: RwLock::new(game_service.current_puzzle()),
+            reset_lock: Mutex::new(()),
+            game_service: ArcSwap::from_pointee(game_service),
+            config: config.clone(),
+            result_signer: config.result_signing_key.as_deref().map(ResultSigner::new),
+            active_players: RwLock::new(HashSet::new()),
+            event_sink: CompositeSink::new(),
+        })
+    }
+
+    /// Register an integration notified when games complete
+    pub fn with_event_sink(mut self, sink: Arc<dyn GameEventSink>) -> Self {
+        self.event_sink.register(sink);
+        self
+    }
+
+    /// Notify registered integrations that a game was completed
+    pub async fn notify_completed(&self, game: &Game) {
+        if !self.event_sink.is_empty() {
+            self.event_sink
+                .on_game_completed(&GameCompletedEvent::from(game))
+                .await;
         }
     }
 
@@ -35,37 +84,456 @@ impl GameState {
         self.game_repository.as_ref()
     }
 
-    /// Get the game service
-    pub fn game_service(&self) -> &GameService {
-        &self.game_service
+    /// Get the current game service
+    pub fn game_service(&self) -> Arc<GameService> {
+        self.game_service.load_full()
+    }
+
+    /// Atomically replace the dictionary, returning the new game service
+    pub fn replace_dictionary(&self, words: &[String]) -> Result<Arc<GameService>, GameError> {
+        loop {
+            let current = self.game_service.load_full();
+            let replacement = Arc::new(current.replace_words(words)?);
+
+            // Retry if another replacement happened concurrently
+            let previous = self
+                .game_service
+                .compare_and_swap(&current, replacement.clone());
+            if Arc::ptr_eq(&previous, &current) {
+                return Ok(replacement);
+            }
+        }
+    }
+
+    /// Get the game configuration
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Get the result signer, if result signing is enabled
+    pub fn result_signer(&self) -> Option<&ResultSigner> {
+        self.result_signer.as_ref()
+    }
+
+    /// Check if any guess in a game came faster than the configured minimum interval
+    pub fn is_suspicious(&self, game: &Game) -> bool {
+        let min_interval = self.config.min_guess_interval_ms;
+        min_interval > 0
+            && game
+                .guess_intervals_ms()
+                .into_iter()
+                .flatten()
+                .any(|elapsed| elapsed < min_interval)
+    }
+
+    /// Sign the result of a completed game, if result signing is enabled
+    pub fn sign_result(&self, game: &Game) -> Option<String> {
+        if !game.is_completed() || game.practice || game.is_replay() {
+            return None;
+        }
+
+        self.result_signer
+            .as_ref()
+            .map(|signer| signer.sign(&ResultPayload::from_game(game)))
+    }
+
+    /// Record that a user created or played a daily game today
+    pub fn mark_active(&self, user_id: Uuid) {
+        self.active_players.write().insert(user_id);
+    }
+
+    /// Number of distinct users who created or played a daily game today
+    pub fn active_today(&self) -> usize {
+        self.active_players.read().len()
     }
 
-    /// Get the last date check lock
-    pub fn last_date_check(&self) -> &RwLock<DateTime<Local>> {
-        &self.last_date_check
+    /// Get the lock holding the date and puzzle index of the last check
+    pub fn last_puzzle_check(&self) -> &RwLock<(NaiveDate, u32)> {
+        &self.last_puzzle_check
     }
 
     /// Get a game by ID
     pub async fn get_game(&self, id: &Uuid) -> RepositoryResult<Game> {
-        self.game_repository.get_game(id).await
+        with_deadline(self.game_repository.get_game(id)).await
     }
 
-    /// Save a game
-    pub async fn save_game(&self, game: Game) -> RepositoryResult<()> {
-        self.game_repository.save_game(game).await
+    /// Save a game, bumping its version
+    pub async fn save_game(&self, mut game: Game) -> RepositoryResult<()> {
+        game.version += 1;
+        with_deadline(self.game_repository.save_game(game)).await
     }
 
-    /// Check if the date has changed and update the daily word if necessary
-    pub async fn check_and_update_date(&self) -> RepositoryResult<()> {
-        let now = Local::now();
-        let mut last_check = self.last_date_check.write();
+    /// Save a game unless another save happened since it was read, bumping its version
+    pub async fn save_game_if_version(&self, mut game: Game) -> RepositoryResult<()> {
+        let expected_version = game.version;
+        game.version += 1;
+        with_deadline(
+            self.game_repository
+                .save_game_if_version(game, expected_version),
+        )
+        .await
+    }
+
+    /// Save a daily or archive game unless the user already has it, returning the stored game
+    pub async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game> {
+        with_deadline(self.game_repository.create_game_if_absent_for_user(game)).await
+    }
+
+    /// List all games owned by a user
+    pub async fn list_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<Vec<Game>> {
+        with_deadline(self.game_repository.list_games_for_user(user_id)).await
+    }
+
+    /// List summaries of all games for a user
+    pub async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>> {
+        with_deadline(self.game_repository.list_game_summaries(user_id)).await
+    }
+
+    /// List a page of all games ordered by creation time, with the total game count
+    pub async fn list_games_paginated(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)> {
+        with_deadline(self.game_repository.list_games_paginated(limit, offset)).await
+    }
+
+    /// List all games created at or after the given time
+    pub async fn list_games_since(&self, since: DateTime<Utc>) -> RepositoryResult<Vec<Game>> {
+        with_deadline(self.game_repository.list_games_since(since)).await
+    }
+
+    /// List summaries of all games created at or after the given time
+    pub async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        with_deadline(self.game_repository.list_game_summaries_since(since)).await
+    }
+
+    /// Delete a game
+    pub async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()> {
+        with_deadline(self.game_repository.delete_game(id)).await
+    }
+
+    /// Delete every game owned by a user
+    pub async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize> {
+        with_deadline(self.game_repository.delete_games_for_user(user_id)).await
+    }
+
+    /// Check that the game repository is reachable
+    pub async fn health_check(&self) -> RepositoryResult<()> {
+        with_deadline(self.game_repository.health_check()).await
+    }
+
+    /// Delete every game
+    pub async fn clear_all_games(&self) -> RepositoryResult<usize> {
+        with_deadline(self.game_repository.clear_all_games()).await
+    }
 
-        // If the date has changed, update the daily word
-        if now.date_naive() != last_check.date_naive() {
-            // In a real app, we would update the daily word here
-            *last_check = now;
+    /// Restore the date of the last daily reset from the repository
+    ///
+    /// A server restarted after midnight then still resets on its first date
+    /// check. Without a recorded date, today is recorded as the reset date.
+    /// Only the date is recorded, so a restart on the same day assumes the
+    /// current puzzle was already reset.
+    pub async fn restore_last_reset_date(&self) -> RepositoryResult<()> {
+        match self.game_repository.get_last_reset_date().await? {
+            Some(date) => {
+                tracing::info!("Restored last daily reset date: {}", date);
+                let mut last_check = self.last_puzzle_check.write();
+                if date != last_check.0 {
+                    *last_check = (date, 0);
+                }
+            }
+            None => {
+                let (today, _) = *self.last_puzzle_check.read();
+                self.game_repository.set_last_reset_date(today).await?;
+            }
         }
 
         Ok(())
     }
+
+    /// Remove games older than the configured retention period, returning how many
+    pub async fn remove_expired_games(&self) -> RepositoryResult<usize> {
+        if self.config.game_retention_days == 0 {
+            return Ok(0);
+        }
+
+        let cutoff = Utc::now() - Duration::days(i64::from(self.config.game_retention_days));
+        self.game_repository.remove_games_older_than(cutoff).await
+    }
+
+    /// Check if the date or puzzle has changed and clear games if necessary
+    ///
+    /// `reset_users` runs as part of the reset, after games are cleared. Only
+    /// one caller performs a reset; concurrent callers wait for it to finish,
+    /// failing with `RepositoryError::ResetInProgress` if their request
+    /// deadline passes first. The new puzzle is only recorded once the reset
+    /// succeeds, so a failed reset is retried by the next check.
+    ///
+    /// Returns whether this caller performed a daily reset.
+    pub async fn check_and_update_date<F, Fut>(&self, reset_users: F) -> RepositoryResult<bool>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = RepositoryResult<()>>,
+    {
+        // Use the same timezone as daily word selection so they never disagree
+        if self.game_service().current_puzzle() == *self.last_puzzle_check.read() {
+            return Ok(false);
+        }
+
+        let _reset = with_deadline(async { Ok(self.reset_lock.lock().await) })
+            .await
+            .map_err(|_| RepositoryError::ResetInProgress)?;
+
+        // Another caller may have finished the reset while we waited
+        let puzzle = self.game_service().current_puzzle();
+        let (today, _) = puzzle;
+        if puzzle == *self.last_puzzle_check.read() {
+            return Ok(false);
+        }
+
+        self.active_players.write().clear();
+
+        // Clear games from the previous day, without the request deadline so the
+        // reset is never abandoned halfway. The cleared games are always fetched:
+        // the server registers the metrics sink, so some sink always wants them.
+        let cleared = if self.config.preserve_completed_on_reset {
+            self.game_repository.drain_incomplete_games().await?
+        } else {
+            self.game_repository.drain_all_games().await?
+        };
+        for game in cleared.iter().filter(|game| !game.completed) {
+            self.event_sink
+                .on_game_abandoned(&GameAbandonedEvent::from(game))
+                .await;
+        }
+        tracing::info!("Daily reset cleared {} games", cleared.len());
+        reset_users().await?;
+
+        // Record the reset only once it is done, so an interrupted reset is redone
+        if let Err(e) = self.game_repository.set_last_reset_date(today).await {
+            tracing::warn!("Failed to record daily reset date: {}", e);
+        }
+        *self.last_puzzle_check.write() = puzzle;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use parking_lot::Mutex as SyncMutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::game::events::GameCompletedEvent;
+    use crate::repository::memory::InMemoryGameRepository;
+    use crate::test_support::{self, played_game};
+
+    /// Sink recording the IDs of abandoned games
+    #[derive(Default)]
+    struct AbandonedGames(SyncMutex<Vec<Uuid>>);
+
+    #[async_trait]
+    impl GameEventSink for AbandonedGames {
+        async fn on_game_completed(&self, _event: &GameCompletedEvent) {}
+
+        async fn on_game_abandoned(&self, event: &GameAbandonedEvent) {
+            self.0.lock().push(event.game_id);
+        }
+    }
+
+    /// Run the daily rollover as if the last check happened on the previous day
+    async fn roll_over(state: &GameState) {
+        let (today, index) = state.game_service().current_puzzle();
+        *state.last_puzzle_check.write() = (today.pred_opt().unwrap(), index);
+        assert!(
+            state
+                .check_and_update_date(|| async { Ok(()) })
+                .await
+                .unwrap()
+        );
+    }
+
+    /// Reset a state holding one completed and one unfinished game, returning
+    /// the abandoned game IDs, the IDs left behind and the unfinished game's ID
+    async fn reset_with(preserve_completed_on_reset: bool) -> (Vec<Uuid>, Vec<Uuid>, Uuid) {
+        let config = GameConfig {
+            preserve_completed_on_reset,
+            ..test_support::game_config()
+        };
+        let sink = Arc::new(AbandonedGames::default());
+        let state = GameState::new(Arc::new(InMemoryGameRepository::new()), &config)
+            .unwrap()
+            .with_event_sink(sink.clone());
+
+        let user_id = Uuid::new_v4();
+        let completed = played_game(user_id, "plant", &["plant"]);
+        let unfinished = played_game(user_id, "plant", &["plate"]);
+        let unfinished_id = unfinished.id;
+        state.save_game(completed).await.unwrap();
+        state.save_game(unfinished).await.unwrap();
+
+        roll_over(&state).await;
+
+        let remaining = state
+            .list_games_for_user(&user_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|game| game.id)
+            .collect();
+        let abandoned = sink.0.lock().clone();
+        (abandoned, remaining, unfinished_id)
+    }
+
+    #[tokio::test]
+    async fn reset_preserving_completed_games_reports_abandoned_ones() {
+        let (abandoned, _, unfinished_id) = reset_with(true).await;
+        assert_eq!(abandoned, vec![unfinished_id]);
+    }
+
+    #[tokio::test]
+    async fn completed_games_survive_a_preserving_rollover() {
+        let (_, remaining, unfinished_id) = reset_with(true).await;
+
+        assert_eq!(remaining.len(), 1);
+        assert_ne!(remaining[0], unfinished_id);
+    }
+
+    #[tokio::test]
+    async fn stale_versioned_save_conflicts() {
+        let state = GameState::new(
+            Arc::new(InMemoryGameRepository::new()),
+            &test_support::game_config(),
+        )
+        .unwrap();
+        let game = played_game(Uuid::new_v4(), "plant", &[]);
+        state.save_game(game.clone()).await.unwrap();
+
+        let mut first = state.get_game(&game.id).await.unwrap();
+        let mut second = first.clone();
+        let service = state.game_service();
+        service.reveal_green(&mut first).unwrap();
+        service.reveal_green(&mut second).unwrap();
+
+        state.save_game_if_version(first).await.unwrap();
+        assert!(matches!(
+            state.save_game_if_version(second).await,
+            Err(RepositoryError::Conflict)
+        ));
+        assert_eq!(state.get_game(&game.id).await.unwrap().powerups_used, 1);
+    }
+
+    #[tokio::test]
+    async fn full_reset_reports_abandoned_games() {
+        let (abandoned, remaining, unfinished_id) = reset_with(false).await;
+
+        assert_eq!(abandoned, vec![unfinished_id]);
+        assert!(remaining.is_empty());
+    }
+
+    #[tokio::test]
+    async fn active_players_are_counted_until_rollover() {
+        let state = GameState::new(
+            Arc::new(InMemoryGameRepository::new()),
+            &test_support::game_config(),
+        )
+        .unwrap();
+
+        let users: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for user_id in &users {
+            state.mark_active(*user_id);
+        }
+        state.mark_active(users[0]);
+        assert_eq!(state.active_today(), 3);
+
+        roll_over(&state).await;
+        assert_eq!(state.active_today(), 0);
+    }
+
+    #[tokio::test]
+    async fn persisted_prior_day_reset_triggers_a_rollover() {
+        let repository = Arc::new(InMemoryGameRepository::new());
+        let today = GameService::default().today();
+        repository
+            .set_last_reset_date(today.pred_opt().unwrap())
+            .await
+            .unwrap();
+        let unfinished = played_game(Uuid::new_v4(), "plant", &["plate"]);
+        repository.save_game(unfinished.clone()).await.unwrap();
+
+        // A freshly started server would otherwise assume today was handled
+        let state = GameState::new(repository.clone(), &test_support::game_config()).unwrap();
+        state.restore_last_reset_date().await.unwrap();
+
+        assert!(
+            state
+                .check_and_update_date(|| async { Ok(()) })
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            state.get_game(&unfinished.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert_eq!(repository.get_last_reset_date().await.unwrap(), Some(today));
+    }
+
+    #[test]
+    fn guesses_faster_than_the_minimum_interval_are_suspicious() {
+        let state = GameState::new(
+            Arc::new(InMemoryGameRepository::new()),
+            &test_support::game_config(),
+        )
+        .unwrap();
+        assert_eq!(state.config().min_guess_interval_ms, 500);
+
+        let mut game = played_game(Uuid::new_v4(), "plant", &["house", "plate"]);
+        let first = game.guesses[0].created_at;
+        game.guesses[1].created_at = first + Duration::milliseconds(50);
+        assert!(state.is_suspicious(&game));
+
+        game.guesses[1].created_at = first + Duration::seconds(5);
+        assert!(!state.is_suspicious(&game));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_checks_reset_once() {
+        let state = Arc::new(
+            GameState::new(
+                Arc::new(InMemoryGameRepository::new()),
+                &test_support::game_config(),
+            )
+            .unwrap(),
+        );
+        let (today, index) = state.game_service().current_puzzle();
+        *state.last_puzzle_check.write() = (today.pred_opt().unwrap(), index);
+
+        let resets = Arc::new(AtomicUsize::new(0));
+        let mut checks = tokio::task::JoinSet::new();
+        for _ in 0..16 {
+            let (state, resets) = (state.clone(), resets.clone());
+            checks.spawn(async move {
+                state
+                    .check_and_update_date(|| async move {
+                        resets.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                    .await
+                    .unwrap()
+            });
+        }
+
+        let mut performed = 0;
+        while let Some(check) = checks.join_next().await {
+            performed += usize::from(check.unwrap());
+        }
+        assert_eq!(performed, 1);
+        assert_eq!(resets.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,336 @@
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use serde::Serialize;
+
+use crate::game::MAX_ATTEMPTS;
+use crate::game::models::{Game, GameSummary};
+
+/// Aggregated statistics over a user's game history
+#[derive(Debug, Clone, Serialize)]
+pub struct GameStats {
+    /// Number of completed games
+    pub games_played: u32,
+
+    /// Number of games won
+    pub games_won: u32,
+
+    /// Percentage of completed games won
+    pub win_percentage: f64,
+
+    /// Consecutive daily wins ending at the most recent game
+    pub current_streak: u32,
+
+    /// Longest run of consecutive daily wins
+    pub max_streak: u32,
+
+    /// Number of wins by guess count (index 0 is a win in one guess)
+    ///
+    /// Covers at least `MAX_ATTEMPTS` guesses, or more if a game allowed more.
+    pub guess_distribution: Vec<u32>,
+}
+
+impl GameStats {
+    /// Compute statistics from summaries of a user's games as of the given date
+    ///
+    /// Games are assigned to days in `timezone`, the daily reset timezone.
+    pub fn from_summaries(games: &[GameSummary], today: NaiveDate, timezone: Tz) -> Self {
+        // Replays of a completed daily game never count toward stats
+        let mut completed: Vec<&GameSummary> = games
+            .iter()
+            .filter(|g| g.completed && g.is_daily())
+            .collect();
+        completed.sort_by_key(|g| g.created_at);
+
+        let mut games_won = 0;
+        let slots = completed
+            .iter()
+            .map(|g| g.max_attempts)
+            .fold(MAX_ATTEMPTS, u8::max);
+        let mut guess_distribution = vec![0; slots as usize];
+        let mut streak: u32 = 0;
+        let mut max_streak = 0;
+        let mut last_date: Option<NaiveDate> = None;
+
+        for game in &completed {
+            let date = game.created_at.with_timezone(&timezone).date_naive();
+
+            if game.won {
+                games_won += 1;
+                if let Some(slot) = guess_distribution.get_mut(game.guess_count.saturating_sub(1)) {
+                    *slot += 1;
+                }
+
+                // A win assisted by power-ups keeps the run going but costs a streak point
+                if game.powerups_used > 0 {
+                    let continues =
+                        last_date.is_some_and(|last| last == date || last.succ_opt() == Some(date));
+                    streak = if continues {
+                        streak.saturating_sub(1)
+                    } else {
+                        0
+                    };
+                    last_date = Some(date);
+                    continue;
+                }
+
+                // A win extends the streak only if it follows the previous day's game
+                streak = match last_date {
+                    Some(last) if last == date => streak.max(1),
+                    Some(last) if last.succ_opt() == Some(date) => streak + 1,
+                    _ => 1,
+                };
+                max_streak = max_streak.max(streak);
+            } else {
+                streak = 0;
+            }
+
+            last_date = Some(date);
+        }
+
+        // The streak is broken if the last game was before yesterday
+        let current_streak = match last_date {
+            Some(date) if today.signed_duration_since(date).num_days() <= 1 => streak,
+            _ => 0,
+        };
+
+        let games_played = completed.len() as u32;
+        let win_percentage = if games_played > 0 {
+            f64::from(games_won) * 100.0 / f64::from(games_played)
+        } else {
+            0.0
+        };
+
+        Self {
+            games_played,
+            games_won,
+            win_percentage,
+            current_streak,
+            max_streak,
+            guess_distribution,
+        }
+    }
+}
+
+/// A user's result for a single day
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarDay {
+    /// The day
+    pub date: NaiveDate,
+
+    /// Whether the user played a game that day
+    pub played: bool,
+
+    /// Whether the user won a game that day
+    pub won: bool,
+
+    /// Number of guesses made in the day's game
+    pub guesses: usize,
+}
+
+/// Build a calendar of a user's results for the month starting at `month_start`
+///
+/// Each entry corresponds to one day of the month in `timezone`, the daily
+/// reset timezone; days without a game are `None`.
+pub fn month_calendar(
+    games: &[GameSummary],
+    month_start: NaiveDate,
+    timezone: Tz,
+) -> Vec<Option<CalendarDay>> {
+    month_start
+        .iter_days()
+        .take_while(|date| date.month() == month_start.month())
+        .map(|date| {
+            let day_games: Vec<&GameSummary> = games
+                .iter()
+                .filter(|g| {
+                    g.is_daily() && g.created_at.with_timezone(&timezone).date_naive() == date
+                })
+                .collect();
+
+            // Prefer a winning game, otherwise the most recent one
+            let game = day_games
+                .iter()
+                .find(|g| g.won)
+                .or_else(|| day_games.iter().max_by_key(|g| g.created_at))?;
+
+            Some(CalendarDay {
+                date,
+                played: true,
+                won: game.won,
+                guesses: game.guess_count,
+            })
+        })
+        .collect()
+}
+
+/// Compose a shareable summary of a day's play across daily and practice games
+///
+/// `games` should contain only the games played that day. Unfinished games are
+/// summarized without their grid so no hints are revealed.
+pub fn daily_summary(games: &[Game]) -> String {
+    let daily = games
+        .iter()
+        .filter(|g| !g.practice && !g.is_replay())
+        .max_by_key(|g| g.created_at);
+
+    let daily_text = match daily {
+        Some(game) if game.is_completed() => game.share_grid(),
+        Some(game) => format!(
+            "Daily: in progress ({}/{})",
+            game.guesses.len(),
+            game.max_attempts
+        ),
+        None => "Daily: not played".to_string(),
+    };
+
+    let practice: Vec<&Game> = games.iter().filter(|g| g.practice).collect();
+    if practice.is_empty() {
+        return daily_text;
+    }
+
+    let won = practice.iter().filter(|g| g.won).count();
+    let lost = practice.iter().filter(|g| g.completed && !g.won).count();
+    let in_progress = practice.len() - won - lost;
+
+    let mut practice_text = format!(
+        "Practice: {} played, {} won, {} lost",
+        practice.len(),
+        won,
+        lost
+    );
+    if in_progress > 0 {
+        practice_text.push_str(&format!(", {} in progress", in_progress));
+    }
+
+    format!("{}\n\n{}", daily_text, practice_text)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::game::GameService;
+    use crate::test_support::played_game;
+
+    /// Summary of a won daily game created at an instant
+    fn win_at(created_at: DateTime<Utc>) -> GameSummary {
+        let mut game = played_game(Uuid::new_v4(), "plant", &["plant"]);
+        game.created_at = created_at;
+        GameSummary::from(&game)
+    }
+
+    fn utc(date: &str, hour: u32) -> DateTime<Utc> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn streaks_use_the_reset_timezone() {
+        // 16:00 UTC on the 1st is the 2nd in Tokyo; 14:00 UTC on the 3rd is still the 3rd
+        let games = [win_at(utc("2026-01-01", 16)), win_at(utc("2026-01-03", 14))];
+        let today = NaiveDate::from_ymd_opt(2026, 1, 3).unwrap();
+
+        let tokyo = GameStats::from_summaries(&games, today, chrono_tz::Asia::Tokyo);
+        assert_eq!(tokyo.current_streak, 2);
+
+        let utc = GameStats::from_summaries(&games, today, Tz::UTC);
+        assert_eq!(utc.current_streak, 1);
+    }
+
+    #[test]
+    fn power_up_wins_cost_a_streak_point() {
+        let mut assisted = played_game(Uuid::new_v4(), "plant", &[]);
+        GameService::default().reveal_green(&mut assisted).unwrap();
+        GameService::default()
+            .make_guess(&mut assisted, "plant")
+            .unwrap();
+        assisted.created_at = utc("2026-01-03", 12);
+
+        let games = [
+            win_at(utc("2026-01-01", 12)),
+            win_at(utc("2026-01-02", 12)),
+            GameSummary::from(&assisted),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 1, 3).unwrap();
+
+        let stats = GameStats::from_summaries(&games, today, Tz::UTC);
+        assert_eq!(stats.games_won, 3);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 2);
+    }
+
+    #[test]
+    fn calendar_marks_played_and_skipped_days() {
+        let win = played_game(Uuid::new_v4(), "plant", &["house", "plate", "plant"]);
+        let loss = played_game(
+            Uuid::new_v4(),
+            "plant",
+            &["house", "plate", "plane", "apple", "mouse", "place"],
+        );
+        let mut practice = played_game(Uuid::new_v4(), "plant", &["plant"]);
+        practice.practice = true;
+
+        let games: Vec<GameSummary> = [
+            (win, "2026-02-02"),
+            (loss, "2026-02-05"),
+            (practice, "2026-02-07"),
+        ]
+        .into_iter()
+        .map(|(mut game, date)| {
+            game.created_at = utc(date, 12);
+            GameSummary::from(&game)
+        })
+        .collect();
+        let month_start = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+
+        let days = month_calendar(&games, month_start, Tz::UTC);
+        assert_eq!(days.len(), 28);
+
+        let won = days[1].as_ref().unwrap();
+        assert!(won.played && won.won);
+        assert_eq!(won.guesses, 3);
+
+        let lost = days[4].as_ref().unwrap();
+        assert!(lost.played && !lost.won);
+        assert_eq!(lost.guesses, 6);
+
+        let played: Vec<usize> = (0..days.len()).filter(|&i| days[i].is_some()).collect();
+        assert_eq!(played, vec![1, 4]);
+    }
+
+    #[test]
+    fn daily_summary_combines_daily_and_practice_games() {
+        let user_id = Uuid::new_v4();
+        let daily = played_game(user_id, "plant", &["house", "plant"]);
+        let mut practice_won = played_game(user_id, "house", &["house"]);
+        practice_won.practice = true;
+        let mut practice_playing = played_game(user_id, "apple", &["plant"]);
+        practice_playing.practice = true;
+
+        let summary = daily_summary(&[daily.clone(), practice_won, practice_playing]);
+        assert_eq!(
+            summary,
+            format!(
+                "{}\n\nPractice: 2 played, 1 won, 0 lost, 1 in progress",
+                daily.share_grid()
+            )
+        );
+        assert!(summary.starts_with("Wordle "));
+        assert!(summary.contains(" 2/6\n"));
+    }
+
+    #[test]
+    fn calendar_uses_the_reset_timezone() {
+        let games = [win_at(utc("2026-01-01", 16))];
+        let month_start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+
+        let days = month_calendar(&games, month_start, chrono_tz::Asia::Tokyo);
+        assert!(days[0].is_none());
+        assert!(days[1].as_ref().is_some_and(|day| day.won));
+    }
+}
@@ -1,3 +1,4 @@
+/// Built-in 5-letter word list
 pub const WORD_LIST: &[&str] = &[
     "about", "above", "abuse", "actor", "acute", "admit", "adopt", "adult", "after", "again",
     "agent", "agree", "ahead", "alarm", "album", "alert", "alike", "alive", "allow", "alone",
@@ -51,3 +52,173 @@ pub const WORD_LIST: &[&str] = &[
     "woman", "women", "world", "worse", "worst", "worth", "would", "wound", "write", "wrong",
     "wrote", "yield", "young", "youth",
 ];
+
+/// Built-in 5-letter answer list, the common words the daily word is chosen from
+///
+/// Every answer is also accepted as a guess, along with the rest of `WORD_LIST`.
+pub const ANSWER_LIST: &[&str] = &[
+    "abuse", "actor", "acute", "admit", "adopt", "adult", "agent", "agree", "alarm", "album",
+    "alert", "alike", "alive", "allow", "alone", "alter", "anger", "angle", "angry", "apart",
+    "apple", "apply", "arena", "argue", "arise", "armor", "array", "arrow", "asset", "avoid",
+    "award", "aware", "awful", "basis", "beach", "beard", "begin", "below", "bench", "berry",
+    "birth", "black", "blade", "blame", "blank", "blast", "bleed", "blend", "bless", "blind",
+    "block", "blood", "board", "boost", "booth", "bound", "brain", "brand", "brave", "bread",
+    "break", "breed", "brief", "bring", "broad", "brown", "brush", "build", "buyer", "cable",
+    "carry", "catch", "cause", "chain", "chair", "chart", "chase", "cheap", "check", "chest",
+    "chief", "child", "claim", "class", "clean", "clear", "click", "clock", "close", "cloud",
+    "coast", "color", "comic", "count", "court", "cover", "crack", "craft", "crash", "cream",
+    "crime", "cross", "crowd", "crown", "curve", "cycle", "daily", "dance", "death", "delay",
+    "depth", "doubt", "dozen", "draft", "drama", "dream", "dress", "drill", "drink", "drive",
+    "eager", "early", "earth", "empty", "enemy", "enjoy", "enter", "equal", "error", "event",
+    "exact", "exist", "extra", "faith", "false", "fault", "fiber", "field", "fight", "final",
+    "first", "flash", "fleet", "floor", "fluid", "focus", "force", "frame", "frank", "fresh",
+    "front", "fruit", "funny", "giant", "glass", "globe", "grace", "grade", "grand", "grant",
+    "grass", "great", "green", "gross", "group", "guard", "guess", "guest", "guide", "happy",
+    "heart", "heavy", "hello", "horse", "hotel", "house", "human", "ideal", "image", "issue",
+    "joint", "judge", "large", "laugh", "layer", "learn", "leave", "level", "light", "limit",
+    "local", "logic", "loose", "lucky", "lunch", "magic", "major", "maker", "march", "match",
+    "mayor", "metal", "might", "model", "money", "month", "moral", "motor", "mount", "mouse",
+    "mouth", "movie", "music", "night", "noise", "north", "novel", "nurse", "occur", "ocean",
+    "offer", "order", "paint", "panel", "paper", "party", "peace", "phone", "photo", "piece",
+    "pilot", "pitch", "place", "plain", "plane", "plant", "plate", "point", "pound", "power",
+    "press", "price", "pride", "prime", "print", "prize", "proof", "proud", "prove", "queen",
+    "quick", "quiet", "radio", "raise", "range", "rapid", "reach", "ready", "refer", "right",
+    "rival", "river", "roman", "rough", "round", "route", "royal", "scale", "scene", "scope",
+    "score", "sense", "serve", "shape", "share", "sharp", "sheet", "shelf", "shell", "shift",
+    "shirt", "shock", "shoot", "short", "sight", "skill", "sleep", "slide", "small", "smart",
+    "smile", "smoke", "solid", "solve", "sorry", "sound", "south", "space", "spare", "speak",
+    "speed", "spend", "split", "sport", "staff", "stage", "stake", "stand", "start", "state",
+    "steam", "steel", "stick", "stock", "stone", "store", "storm", "story", "strip", "study",
+    "stuff", "style", "sugar", "super", "sweet", "table", "taste", "teach", "teeth", "thank",
+    "theme", "thick", "thing", "think", "throw", "tight", "tired", "title", "today", "topic",
+    "total", "touch", "tough", "tower", "track", "trade", "train", "treat", "trend", "trial",
+    "truck", "trust", "truth", "union", "unity", "upset", "usual", "value", "video", "virus",
+    "visit", "voice", "waste", "watch", "water", "wheel", "white", "whole", "woman", "women",
+    "world", "worth", "wound", "write", "wrong", "yield", "young", "youth",
+];
+
+/// Built-in 4-letter word list
+pub const WORD_LIST_4: &[&str] = &[
+    "able", "acid", "aged", "also", "area", "army", "away", "baby", "back", "ball", "band", "bank",
+    "base", "bath", "bear", "beat", "been", "beer", "bell", "belt", "best", "bill", "bird", "blow",
+    "blue", "boat", "body", "bomb", "bond", "bone", "book", "boom", "born", "boss", "both", "bowl",
+    "bulk", "burn", "bush", "busy", "call", "calm", "came", "camp", "card", "care", "case", "cash",
+    "cast", "cell", "chat", "chip", "city", "club", "coal", "coat", "code", "cold", "come", "cook",
+    "cool", "cope", "copy", "core", "cost", "crew", "crop", "dark", "data", "date", "dawn", "days",
+    "dead", "deal", "dean", "dear", "debt", "deep", "deny", "desk", "dial", "diet", "disc", "disk",
+    "does", "done", "door", "dose", "down", "draw", "drew", "drop", "drug", "dual", "duke", "dust",
+    "duty", "each", "earn", "ease", "east", "easy", "edge", "else", "even", "ever", "evil", "exit",
+    "face", "fact", "fail", "fair", "fall", "farm", "fast", "fate", "fear", "feed", "feel", "feet",
+    "fell", "felt", "file", "fill", "film", "find", "fine", "fire", "firm", "fish", "five", "flat",
+    "flow", "food", "foot", "ford", "form", "fort", "four", "free", "from", "fuel", "full", "fund",
+    "gain", "game", "gate", "gave", "gear", "gift", "girl", "give", "glad", "goal", "goes", "gold",
+    "golf", "gone", "good", "gray", "grew", "grey", "grow", "gulf", "hair", "half", "hall", "hand",
+    "hang", "hard", "harm", "hate", "have", "head", "hear", "heat", "held", "hell", "help", "here",
+    "hero", "high", "hill", "hire", "hold", "hole", "holy", "home", "hope", "host", "hour", "huge",
+    "hung", "hunt", "hurt", "idea", "inch", "into", "iron", "item", "jack", "jane", "jean", "john",
+    "join", "jump", "jury", "just", "keen", "keep", "kent", "kept", "kick", "kind", "king", "knee",
+    "knew", "know", "lack", "lady", "laid", "lake", "land", "lane", "last", "late", "lead", "left",
+    "less", "life", "lift", "like", "line", "link", "list", "live", "load", "loan", "lock", "logo",
+    "long", "look", "lord", "lose", "loss", "lost", "love", "luck", "made", "mail", "main", "make",
+    "male", "many", "mark", "mass", "matt", "meal", "mean", "meat", "meet", "menu", "mere", "mike",
+    "mile", "milk", "mill", "mind", "mine", "miss", "mode", "mood", "moon", "more", "most", "move",
+    "much", "must", "name", "navy", "near", "neck", "need", "news", "next", "nice", "nick", "nine",
+    "none", "nose", "note", "okay", "once", "only", "open", "oral", "over", "pace", "pack", "page",
+    "paid", "pain", "pair", "palm", "park", "part", "pass", "past", "path", "peak", "pick", "pink",
+    "pipe", "plan", "play", "plot", "plug", "plus", "poll", "pool", "poor", "port", "post", "pull",
+    "pure", "push", "race", "rail", "rain", "rank", "rare", "rate", "read", "real", "rear", "rely",
+    "rent", "rest", "rice", "rich", "ride", "ring", "rise", "risk", "road", "rock", "role", "roll",
+    "roof", "room", "root", "rose", "rule", "rush", "safe", "said", "sake", "sale", "salt", "same",
+    "sand", "save", "seat", "seed", "seek", "seem", "seen", "self", "sell", "send", "sent", "ship",
+    "shop", "shot", "show", "shut", "sick", "side", "sign", "site", "size", "skin", "slip", "slow",
+    "snow", "soft", "soil", "sold", "sole", "some", "song", "soon", "sort", "soul", "spot", "star",
+    "stay", "step", "stop", "such", "suit", "sure", "take", "tale", "talk", "tall", "tank", "tape",
+    "task", "team", "tech", "tell", "tend", "term", "test", "text", "than", "that", "them", "then",
+    "they", "thin", "this", "thus", "till", "time", "tiny", "told", "tone", "tony", "took", "tool",
+    "tour", "town", "tree", "trip", "true", "tune", "turn", "twin", "type", "unit", "upon", "used",
+    "user", "vary", "vast", "very", "vice", "view", "vote", "wage", "wait", "wake", "walk", "wall",
+    "want", "ward", "warm", "wash", "wave", "ways", "weak", "wear", "week", "well", "went", "were",
+    "west", "what", "when", "whom", "wide", "wife", "wild", "will", "wind", "wine", "wing", "wire",
+    "wise", "wish", "with", "wood", "word", "wore", "work", "yard", "yeah", "year", "your", "zero",
+    "zone",
+];
+
+/// Built-in 6-letter word list
+pub const WORD_LIST_6: &[&str] = &[
+    "abroad", "accept", "access", "across", "acting", "action", "active", "actual", "advice",
+    "advise", "affect", "afford", "afraid", "agency", "agenda", "almost", "always", "amount",
+    "animal", "annual", "answer", "anyone", "anyway", "appeal", "appear", "around", "arrive",
+    "artist", "aspect", "assess", "assist", "assume", "attack", "attend", "august", "author",
+    "avenue", "backed", "barely", "battle", "beauty", "became", "become", "before", "behalf",
+    "behind", "belief", "belong", "berlin", "better", "beyond", "bishop", "border", "bottle",
+    "bottom", "bought", "branch", "breath", "bridge", "bright", "broken", "budget", "burden",
+    "bureau", "button", "camera", "cancer", "cannot", "carbon", "career", "castle", "casual",
+    "caught", "center", "centre", "chance", "change", "charge", "choice", "choose", "chosen",
+    "church", "circle", "client", "closed", "closer", "coffee", "column", "combat", "coming",
+    "common", "comply", "copper", "corner", "costly", "county", "couple", "course", "covers",
+    "create", "credit", "crisis", "custom", "damage", "danger", "dealer", "debate", "decade",
+    "decide", "defeat", "defend", "define", "degree", "demand", "depend", "deputy", "desert",
+    "design", "desire", "detail", "detect", "device", "differ", "dinner", "direct", "doctor",
+    "dollar", "domain", "double", "driven", "driver", "during", "easily", "eating", "editor",
+    "effect", "effort", "eighth", "either", "eleven", "emerge", "empire", "employ", "enable",
+    "ending", "energy", "engage", "engine", "enough", "ensure", "entire", "entity", "equity",
+    "escape", "estate", "ethnic", "exceed", "except", "excess", "expand", "expect", "expert",
+    "export", "extend", "extent", "fabric", "facing", "factor", "failed", "fairly", "fallen",
+    "family", "famous", "father", "fellow", "female", "figure", "filing", "finger", "finish",
+    "fiscal", "flight", "flying", "follow", "forced", "forest", "forget", "formal", "format",
+    "former", "foster", "fought", "fourth", "friend", "future", "garden", "gather", "gender",
+    "german", "global", "golden", "ground", "growth", "guilty", "handed", "handle", "happen",
+    "hardly", "headed", "health", "height", "hidden", "holder", "honest", "impact", "import",
+    "income", "indeed", "injury", "inside", "intend", "intent", "invest", "island", "itself",
+    "jersey", "joseph", "junior", "killed", "labour", "latest", "latter", "launch", "lawyer",
+    "leader", "league", "leaves", "legacy", "length", "lesson", "letter", "lights", "likely",
+    "linked", "liquid", "listen", "little", "living", "losing", "luxury", "mainly", "making",
+    "manage", "manner", "manual", "margin", "marine", "marked", "market", "martin", "master",
+    "matter", "mature", "medium", "member", "memory", "mental", "merely", "merger", "method",
+    "middle", "miller", "mining", "minute", "mirror", "mobile", "modern", "modest", "module",
+    "moment", "morris", "mostly", "mother", "motion", "moving", "murder", "museum", "mutual",
+    "myself", "narrow", "nation", "native", "nature", "nearby", "nearly", "nights", "nobody",
+    "normal", "notice", "notion", "number", "object", "obtain", "office", "offset", "online",
+    "option", "orange", "origin", "output", "oxford", "packed", "palace", "parent", "partly",
+    "patent", "people", "period", "permit", "person", "phrase", "picked", "planet", "player",
+    "please", "plenty", "pocket", "police", "policy", "prefer", "pretty", "prince", "prison",
+    "profit", "proper", "proven", "public", "pursue", "raised", "random", "rarely", "rather",
+    "rating", "reader", "really", "reason", "recall", "recent", "record", "reduce", "reform",
+    "regard", "regime", "region", "relate", "relief", "remain", "remote", "remove", "repair",
+    "repeat", "replay", "report", "rescue", "resort", "result", "retail", "retain", "return",
+    "reveal", "review", "reward", "riding", "rising", "robust", "ruling", "safety", "salary",
+    "sample", "saving", "saying", "scheme", "school", "screen", "search", "season", "second",
+    "secret", "sector", "secure", "seeing", "select", "seller", "senior", "series", "server",
+    "settle", "severe", "should", "signal", "signed", "silent", "silver", "simple", "simply",
+    "single", "sister", "slight", "smooth", "social", "solely", "sought", "source", "soviet",
+    "speech", "spirit", "spoken", "spread", "spring", "square", "stable", "status", "steady",
+    "stolen", "strain", "stream", "street", "stress", "strict", "strike", "string", "strong",
+    "struck", "studio", "submit", "sudden", "suffer", "summer", "summit", "supply", "surely",
+    "survey", "switch", "symbol", "system", "taking", "talent", "target", "taught", "tenant",
+    "tender", "tennis", "thanks", "theory", "thirty", "though", "threat", "thrown", "ticket",
+    "timely", "timing", "tissue", "toward", "travel", "treaty", "trying", "twelve", "twenty",
+    "unable", "unique", "united", "unless", "unlike", "update", "useful", "valley", "varied",
+    "vendor", "versus", "victim", "vision", "visual", "volume", "walker", "wealth", "weekly",
+    "weight", "wholly", "window", "winner", "winter", "within", "wonder", "worker", "wright",
+    "writer", "yellow",
+];
+
+/// Get the built-in answer list for a word length, if one exists
+///
+/// Lengths without a separate answer list draw answers from the whole word list.
+pub fn answer_list_for(len: usize) -> Option<&'static [&'static str]> {
+    match len {
+        5 => Some(ANSWER_LIST),
+        _ => None,
+    }
+}
+
+/// Get the built-in word list for a word length, if one exists
+pub fn word_list_for(len: usize) -> Option<&'static [&'static str]> {
+    match len {
+        4 => Some(WORD_LIST_4),
+        5 => Some(WORD_LIST),
+        6 => Some(WORD_LIST_6),
+        _ => None,
+    }
+}
@@ -4,6 +4,9 @@ pub mod common;
 pub mod game;
 pub mod models;
 pub mod repository;
+pub mod server;
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Re-export common traits and types
 pub use anyhow::{Error, Result};
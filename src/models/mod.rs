@@ -4,5 +4,5 @@ pub mod game;
 pub mod user;
 
 // Re-export common types
-pub use game::Game;
+pub use game::{Game, GameSummary};
 pub use user::User;
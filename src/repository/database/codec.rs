@@ -0,0 +1,178 @@
+use std::io::{Read, Write};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::common::config::DatabaseConfig;
+use crate::game::models::Guess;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// Default cap on the serialized size of a game's guesses in bytes
+pub const DEFAULT_MAX_GUESSES_BYTES: usize = 1024 * 1024;
+
+/// How a game's guesses are written to the database
+#[derive(Debug, Clone, Copy)]
+pub struct GuessStorage {
+    /// Whether guesses are gzip-compressed into a `bytea` column
+    compress: bool,
+
+    /// Maximum serialized size of the guesses in bytes
+    max_bytes: usize,
+}
+
+/// Guesses encoded for the `guesses`, `guesses_gz` and `guesses_compressed` columns
+#[derive(Debug, Clone)]
+pub enum StoredGuesses {
+    /// Plain JSON stored in the `guesses` column
+    Json(serde_json::Value),
+
+    /// Gzip-compressed JSON stored in the `guesses_gz` column
+    Compressed(Vec<u8>),
+}
+
+impl Default for GuessStorage {
+    fn default() -> Self {
+        Self {
+            compress: false,
+            max_bytes: DEFAULT_MAX_GUESSES_BYTES,
+        }
+    }
+}
+
+impl GuessStorage {
+    /// Create storage options from the database configuration
+    pub fn from_config(config: &DatabaseConfig) -> Self {
+        Self {
+            compress: config.compress_guesses,
+            max_bytes: config.max_guesses_bytes,
+        }
+    }
+
+    /// Encode guesses for storage, rejecting them if they exceed the size cap
+    pub fn encode(&self, guesses: &[Guess]) -> RepositoryResult<StoredGuesses> {
+        let json = serde_json::to_vec(guesses)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+
+        if json.len() > self.max_bytes {
+            return Err(RepositoryError::SerializationError(format!(
+                "Guesses are {} bytes, exceeding the {} byte limit",
+                json.len(),
+                self.max_bytes
+            )));
+        }
+
+        if !self.compress {
+            let value = serde_json::from_slice(&json)
+                .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+            return Ok(StoredGuesses::Json(value));
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&json)
+            .and_then(|_| encoder.finish())
+            .map(StoredGuesses::Compressed)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))
+    }
+}
+
+impl StoredGuesses {
+    /// Rebuild stored guesses from their database columns
+    pub fn from_columns(json: serde_json::Value, gz: Option<Vec<u8>>, compressed: bool) -> Self {
+        match gz {
+            Some(bytes) if compressed => Self::Compressed(bytes),
+            _ => Self::Json(json),
+        }
+    }
+
+    /// Value for the `guesses` column (empty when compressed)
+    pub fn json(&self) -> serde_json::Value {
+        match self {
+            Self::Json(value) => value.clone(),
+            Self::Compressed(_) => serde_json::Value::Array(Vec::new()),
+        }
+    }
+
+    /// Value for the `guesses_gz` column
+    pub fn gz(&self) -> Option<&[u8]> {
+        match self {
+            Self::Json(_) => None,
+            Self::Compressed(bytes) => Some(bytes),
+        }
+    }
+
+    /// Value for the `guesses_compressed` column
+    pub fn is_compressed(&self) -> bool {
+        matches!(self, Self::Compressed(_))
+    }
+
+    /// Decode the guesses, decompressing them if needed
+    pub fn decode(self) -> RepositoryResult<Vec<Guess>> {
+        match self {
+            Self::Json(value) => serde_json::from_value(value)
+                .map_err(|e| RepositoryError::SerializationError(e.to_string())),
+            Self::Compressed(bytes) => {
+                let mut json = Vec::new();
+                GzDecoder::new(bytes.as_slice())
+                    .read_to_end(&mut json)
+                    .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+                serde_json::from_slice(&json)
+                    .map_err(|e| RepositoryError::SerializationError(e.to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::game::models::LetterResult;
+
+    fn guesses(count: usize) -> Vec<Guess> {
+        (0..count)
+            .map(|_| Guess {
+                word: "house".to_string(),
+                results: vec![LetterResult::Wrong; 5],
+                score: None,
+                created_at: Utc::now(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn large_games_round_trip_compressed() {
+        let storage = GuessStorage {
+            compress: true,
+            max_bytes: DEFAULT_MAX_GUESSES_BYTES,
+        };
+        let original = guesses(500);
+        let json_len = serde_json::to_vec(&original).unwrap().len();
+
+        let stored = storage.encode(&original).unwrap();
+        assert!(stored.is_compressed());
+        assert!(stored.gz().unwrap().len() < json_len / 4);
+
+        let decoded = stored.decode().unwrap();
+        assert_eq!(decoded.len(), original.len());
+        assert!(decoded.iter().zip(&original).all(|(a, b)| {
+            a.word == b.word && a.results == b.results && a.created_at == b.created_at
+        }));
+    }
+
+    #[test]
+    fn games_over_the_cap_are_rejected() {
+        let storage = GuessStorage {
+            compress: true,
+            max_bytes: 1024,
+        };
+
+        assert!(storage.encode(&guesses(2)).is_ok());
+        assert!(matches!(
+            storage.encode(&guesses(500)),
+            Err(RepositoryError::SerializationError(_))
+        ));
+    }
+}
@@ -3,8 +3,24 @@
 // PostgreSQL implementation
 pub mod postgres;
 
+// SQLite implementation
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+// Encoding of stored game data
+#[cfg(feature = "database")]
+pub mod codec;
+
 // Re-export the concrete repository implementations
 #[cfg(feature = "database")]
+pub use self::codec::GuessStorage;
+#[cfg(feature = "database")]
 pub use self::postgres::game::PostgresGameRepository;
 #[cfg(feature = "database")]
+pub use self::postgres::row::GameRow;
+#[cfg(feature = "database")]
 pub use self::postgres::user::PostgresUserRepository;
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::game::SqliteGameRepository;
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::user::SqliteUserRepository;
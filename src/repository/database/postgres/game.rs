@@ -1,29 +1,47 @@
 #[cfg(feature = "database")]
 use async_trait::async_trait;
 #[cfg(feature = "database")]
+use chrono::{DateTime, NaiveDate, Utc};
+#[cfg(feature = "database")]
 use uuid::Uuid;
 
 #[cfg(feature = "database")]
 use super::PostgresConnection;
 #[cfg(feature = "database")]
-use crate::game::models::Game;
+use super::row::{GameRow, SUMMARY_COLUMNS, SummaryRow};
+#[cfg(feature = "database")]
+use crate::game::models::{Game, GameSummary};
 #[cfg(feature = "database")]
-use crate::repository::GameRepositoryTrait;
+use crate::repository::database::codec::GuessStorage;
 #[cfg(feature = "database")]
 use crate::repository::error::{RepositoryError, RepositoryResult};
+#[cfg(feature = "database")]
+use crate::repository::{GameRepositoryTrait, PoolStats};
 
 /// PostgreSQL implementation of game repository
 #[cfg(feature = "database")]
 pub struct PostgresGameRepository {
     /// Database connection
     conn: PostgresConnection,
+
+    /// How guesses are encoded in the `games` table
+    storage: GuessStorage,
 }
 
 #[cfg(feature = "database")]
 impl PostgresGameRepository {
     /// Create a new PostgreSQL game repository
     pub fn new(conn: PostgresConnection) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            storage: GuessStorage::default(),
+        }
+    }
+
+    /// Set how guesses are encoded in the `games` table
+    pub fn with_storage(mut self, storage: GuessStorage) -> Self {
+        self.storage = storage;
+        self
     }
 }
 
@@ -31,38 +49,40 @@ impl PostgresGameRepository {
 #[async_trait]
 impl GameRepositoryTrait for PostgresGameRepository {
     async fn get_game(&self, id: &Uuid) -> RepositoryResult<Game> {
-        // Implementation would use sqlx to query the database
-        // For example:
-        // sqlx::query_as!(
-        //     Game,
-        //     "SELECT * FROM games WHERE id = $1",
-        //     id
-        // )
-        // .fetch_optional(&*self.conn.pool)
-        // .await
-        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
-        // .ok_or(RepositoryError::NotFound)
+        let row = sqlx::query_as::<_, GameRow>("SELECT * FROM games WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
 
-        // This is a placeholder implementation
-        Err(RepositoryError::Unsupported(
-            "PostgreSQL game repository is not yet implemented".to_string(),
-        ))
+        Game::try_from(row)
     }
 
-    async fn save_game(&self, _game: Game) -> RepositoryResult<()> {
+    async fn save_game(&self, game: Game) -> RepositoryResult<()> {
+        // Encode guesses first so oversized games are rejected
+        let _guesses = self.storage.encode(&game.guesses)?;
+
         // Implementation would use sqlx to insert or update a game
         // For example:
         // sqlx::query!(
-        //     "INSERT INTO games (id, user_id, word, guesses, created_at, updated_at)
-        //     VALUES ($1, $2, $3, $4, $5, $6)
+        //     "INSERT INTO games (id, user_id, word, guesses, guesses_gz, guesses_compressed,
+        //         guess_count, created_at, updated_at)
+        //     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         //     ON CONFLICT (id) DO UPDATE SET
         //         word = EXCLUDED.word,
         //         guesses = EXCLUDED.guesses,
+        //         guesses_gz = EXCLUDED.guesses_gz,
+        //         guesses_compressed = EXCLUDED.guesses_compressed,
+        //         guess_count = EXCLUDED.guess_count,
         //         updated_at = EXCLUDED.updated_at",
         //     game.id,
         //     game.user_id,
         //     game.word,
-        //     &game.guesses,
+        //     guesses.json(),
+        //     guesses.gz(),
+        //     guesses.is_compressed(),
+        //     game.guesses.len() as i16,
         //     game.created_at,
         //     game.updated_at
         // )
@@ -77,6 +97,210 @@ impl GameRepositoryTrait for PostgresGameRepository {
         ))
     }
 
+    async fn save_game_if_version(
+        &self,
+        game: Game,
+        expected_version: u64,
+    ) -> RepositoryResult<()> {
+        // Encode guesses first so oversized games are rejected
+        let _guesses = self.storage.encode(&game.guesses)?;
+
+        // Implementation would compare and swap the version in the WHERE clause
+        // For example:
+        // let updated = sqlx::query!(
+        //     "UPDATE games SET guesses = $3, guesses_gz = $4, guesses_compressed = $5,
+        //         guess_count = $6, completed = $7, won = $8, version = $9, updated_at = $10
+        //     WHERE id = $1 AND version = $2",
+        //     game.id,
+        //     expected_version as i64,
+        //     guesses.json(),
+        //     guesses.gz(),
+        //     guesses.is_compressed(),
+        //     game.guesses.len() as i16,
+        //     game.completed,
+        //     game.won,
+        //     game.version as i64,
+        //     game.updated_at
+        // )
+        // .execute(&*self.conn.pool)
+        // .await
+        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        // if updated.rows_affected() == 0 {
+        //     // Tell a missing game apart from one saved by another request
+        //     self.get_game(&game.id).await?;
+        //     return Err(RepositoryError::Conflict);
+        // }
+        // Ok(())
+        let _ = expected_version;
+
+        // This is a placeholder implementation
+        Err(RepositoryError::Unsupported(
+            "PostgreSQL game repository is not yet implemented".to_string(),
+        ))
+    }
+
+    async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game> {
+        // Encode guesses first so oversized games are rejected
+        let _guesses = self.storage.encode(&game.guesses)?;
+
+        // Implementation would rely on the partial unique indexes on daily games
+        // and archive games, selecting by (user_id, archive_date) for the latter
+        // For example:
+        // let inserted = sqlx::query!(
+        //     "INSERT INTO games (id, user_id, word, max_attempts, guesses, created_at, updated_at)
+        //     VALUES ($1, $2, $3, $4, $5, $6, $7)
+        //     ON CONFLICT (user_id, word, replay_count) WHERE NOT practice AND seed IS NULL DO NOTHING",
+        //     game.id,
+        //     game.user_id,
+        //     game.word,
+        //     game.max_attempts as i16,
+        //     guesses.json(),
+        //     game.created_at,
+        //     game.updated_at
+        // )
+        // .execute(&*self.conn.pool)
+        // .await
+        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        // if inserted.rows_affected() == 0 {
+        //     return sqlx::query_as!(
+        //         Game,
+        //         "SELECT * FROM games WHERE user_id = $1 AND word = $2
+        //         AND replay_count = $3 AND NOT practice AND seed IS NULL",
+        //         game.user_id,
+        //         game.word,
+        //         game.replay_count as i32
+        //     )
+        //     .fetch_one(&*self.conn.pool)
+        //     .await
+        //     .map_err(|e| RepositoryError::DatabaseError(e.to_string()));
+        // }
+        // Ok(game)
+
+        // This is a placeholder implementation
+        Err(RepositoryError::Unsupported(
+            "PostgreSQL game repository is not yet implemented".to_string(),
+        ))
+    }
+
+    async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM games WHERE id = $1")
+            .bind(id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize> {
+        let result = sqlx::query("DELETE FROM games WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn list_games_for_user(&self, _user_id: &Uuid) -> RepositoryResult<Vec<Game>> {
+        // Implementation would use sqlx to query the user's games
+        // For example:
+        // sqlx::query_as!(
+        //     Game,
+        //     "SELECT * FROM games WHERE user_id = $1 ORDER BY created_at",
+        //     user_id
+        // )
+        // .fetch_all(&*self.conn.pool)
+        // .await
+        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+
+        // This is a placeholder implementation
+        Err(RepositoryError::Unsupported(
+            "PostgreSQL game repository is not yet implemented".to_string(),
+        ))
+    }
+
+    async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE user_id = $1 ORDER BY created_at",
+            SUMMARY_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, SummaryRow>(&sql)
+            .bind(user_id)
+            .fetch_all(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(GameSummary::try_from).collect()
+    }
+
+    async fn list_games_paginated(
+        &self,
+        _limit: usize,
+        _offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)> {
+        // Implementation would use sqlx to fetch a page and the total count
+        // For example:
+        // let games = sqlx::query_as!(
+        //     Game,
+        //     "SELECT * FROM games ORDER BY created_at, id LIMIT $1 OFFSET $2",
+        //     limit as i64,
+        //     offset as i64
+        // )
+        // .fetch_all(&*self.conn.pool)
+        // .await
+        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        // let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM games")
+        //     .fetch_one(&*self.conn.pool)
+        //     .await
+        //     .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        // Ok((games, total as usize))
+
+        // This is a placeholder implementation
+        Err(RepositoryError::Unsupported(
+            "PostgreSQL game repository is not yet implemented".to_string(),
+        ))
+    }
+
+    async fn list_games_since(&self, _since: DateTime<Utc>) -> RepositoryResult<Vec<Game>> {
+        // Implementation would use sqlx to query recent games
+        // For example:
+        // sqlx::query_as!(
+        //     Game,
+        //     "SELECT * FROM games WHERE created_at >= $1",
+        //     since
+        // )
+        // .fetch_all(&*self.conn.pool)
+        // .await
+        // .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+
+        // This is a placeholder implementation
+        Err(RepositoryError::Unsupported(
+            "PostgreSQL game repository is not yet implemented".to_string(),
+        ))
+    }
+
+    async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE created_at >= $1 ORDER BY created_at",
+            SUMMARY_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, SummaryRow>(&sql)
+            .bind(since)
+            .fetch_all(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(GameSummary::try_from).collect()
+    }
+
     async fn clear_all_games(&self) -> RepositoryResult<usize> {
         // Implementation would use sqlx to delete all games
         // For example:
@@ -91,4 +315,69 @@ impl GameRepositoryTrait for PostgresGameRepository {
             "PostgreSQL game repository is not yet implemented".to_string(),
         ))
     }
+
+    async fn drain_all_games(&self) -> RepositoryResult<Vec<Game>> {
+        let rows = sqlx::query_as::<_, GameRow>("DELETE FROM games RETURNING *")
+            .fetch_all(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(Game::try_from).collect()
+    }
+
+    async fn drain_incomplete_games(&self) -> RepositoryResult<Vec<Game>> {
+        let rows =
+            sqlx::query_as::<_, GameRow>("DELETE FROM games WHERE completed = FALSE RETURNING *")
+                .fetch_all(&*self.conn.pool)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(Game::try_from).collect()
+    }
+
+    async fn remove_games_older_than(&self, cutoff: DateTime<Utc>) -> RepositoryResult<usize> {
+        let result = sqlx::query("DELETE FROM games WHERE created_at < $1")
+            .bind(cutoff)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn get_last_reset_date(&self) -> RepositoryResult<Option<NaiveDate>> {
+        sqlx::query_scalar("SELECT last_date FROM daily_reset")
+            .fetch_optional(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+    }
+
+    async fn set_last_reset_date(&self, date: NaiveDate) -> RepositoryResult<()> {
+        sqlx::query(
+            "INSERT INTO daily_reset (id, last_date) VALUES (TRUE, $1)
+            ON CONFLICT (id) DO UPDATE SET last_date = EXCLUDED.last_date",
+        )
+        .bind(date)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn health_check(&self) -> RepositoryResult<()> {
+        sqlx::query("SELECT 1")
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn pool_stats(&self) -> Option<PoolStats> {
+        Some(PoolStats {
+            size: self.conn.pool.size(),
+            idle: self.conn.pool.num_idle(),
+        })
+    }
 }
@@ -1,11 +1,17 @@
 /// PostgreSQL repository implementations
 pub mod game;
+pub mod row;
 pub mod user;
 
 #[cfg(feature = "database")]
 use sqlx::PgPool;
 #[cfg(feature = "database")]
 use std::sync::Arc;
+#[cfg(feature = "database")]
+use std::time::Duration;
+
+#[cfg(feature = "database")]
+use crate::common::config::DatabaseConfig;
 
 /// Database connection configuration for PostgreSQL
 #[cfg(feature = "database")]
@@ -16,8 +22,14 @@ pub struct PostgresConfig {
     /// Maximum number of connections in the pool
     pub max_connections: u32,
 
+    /// Number of idle connections the pool keeps open
+    pub min_connections: u32,
+
     /// Connection timeout in seconds
     pub connection_timeout: u64,
+
+    /// Seconds after which an idle connection is closed (never if unset)
+    pub idle_timeout: Option<u64>,
 }
 
 #[cfg(feature = "database")]
@@ -27,7 +39,20 @@ impl PostgresConfig {
         Self {
             connection_url: connection_url.into(),
             max_connections: 5,
+            min_connections: 0,
             connection_timeout: 30,
+            idle_timeout: Some(600),
+        }
+    }
+
+    /// Create a PostgreSQL configuration from the database settings
+    pub fn from_config(config: &DatabaseConfig) -> Self {
+        Self {
+            connection_url: config.url.clone(),
+            max_connections: config.max_connections,
+            min_connections: config.min_connections,
+            connection_timeout: config.acquire_timeout_secs,
+            idle_timeout: (config.idle_timeout_secs > 0).then_some(config.idle_timeout_secs),
         }
     }
 
@@ -35,7 +60,9 @@ impl PostgresConfig {
     pub async fn create_pool(&self) -> Result<PgPool, sqlx::Error> {
         let pool = sqlx::postgres::PgPoolOptions::new()
             .max_connections(self.max_connections)
-            .acquire_timeout(std::time::Duration::from_secs(self.connection_timeout))
+            .min_connections(self.min_connections)
+            .acquire_timeout(Duration::from_secs(self.connection_timeout))
+            .idle_timeout(self.idle_timeout.map(Duration::from_secs))
             .connect(&self.connection_url)
             .await?;
 
@@ -60,3 +87,32 @@ impl PostgresConnection {
         }
     }
 }
+
+#[cfg(all(test, feature = "database"))]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn pool_settings_come_from_the_database_config() {
+        let mut database = test_support::config().database;
+        database.url = "postgres://localhost/wordle".to_string();
+        database.max_connections = 12;
+        database.min_connections = 2;
+        database.acquire_timeout_secs = 7;
+        database.idle_timeout_secs = 0;
+
+        let config = PostgresConfig::from_config(&database);
+        assert_eq!(config.connection_url, "postgres://localhost/wordle");
+        assert_eq!(config.max_connections, 12);
+        assert_eq!(config.min_connections, 2);
+        assert_eq!(config.connection_timeout, 7);
+        assert_eq!(config.idle_timeout, None);
+
+        database.idle_timeout_secs = 90;
+        assert_eq!(
+            PostgresConfig::from_config(&database).idle_timeout,
+            Some(90)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+#[cfg(feature = "database")]
+use chrono::{DateTime, NaiveDate, Utc};
+#[cfg(feature = "database")]
+use uuid::Uuid;
+
+#[cfg(feature = "database")]
+use crate::game::models::{Game, GameSummary};
+#[cfg(feature = "database")]
+use crate::repository::database::codec::StoredGuesses;
+#[cfg(feature = "database")]
+use crate::repository::error::RepositoryError;
+
+/// Row of the `games` table
+///
+/// Mirrors the column types so `sqlx::query_as` maps it directly; convert it
+/// to a `Game` with `Game::try_from`.
+#[cfg(feature = "database")]
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct GameRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub word: String,
+    pub max_attempts: i16,
+    #[sqlx(rename = "guesses")]
+    pub guesses_json: serde_json::Value,
+    pub guesses_gz: Option<Vec<u8>>,
+    pub guesses_compressed: bool,
+    pub guess_count: i16,
+    pub completed: bool,
+    pub won: bool,
+    pub surrendered: bool,
+    pub hard_mode: bool,
+    pub blind_batch_size: Option<i16>,
+    pub practice: bool,
+    pub seed: Option<i64>,
+    pub archive_date: Option<NaiveDate>,
+    pub replay_count: i32,
+    pub language: Option<String>,
+    pub scoring: String,
+    pub powerups_used: i16,
+    pub revealed_positions: serde_json::Value,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "database")]
+impl GameRow {
+    /// Replace the guess columns with guesses encoded by `GuessStorage`
+    pub fn with_guesses(mut self, guesses: StoredGuesses) -> Self {
+        self.guesses_json = guesses.json();
+        self.guesses_gz = guesses.gz().map(<[u8]>::to_vec);
+        self.guesses_compressed = guesses.is_compressed();
+        self
+    }
+}
+
+#[cfg(feature = "database")]
+impl From<Game> for GameRow {
+    fn from(game: Game) -> Self {
+        // Guesses and positions hold only strings, enums and numbers, so they
+        // always serialize
+        let guesses_json = serde_json::to_value(&game.guesses).unwrap_or_default();
+        let revealed_positions = serde_json::to_value(&game.revealed_positions).unwrap_or_default();
+        let scoring = match serde_json::to_value(game.scoring) {
+            Ok(serde_json::Value::String(name)) => name,
+            _ => "wordle".to_string(),
+        };
+
+        Self {
+            id: game.id,
+            user_id: game.user_id,
+            word: game.word,
+            max_attempts: game.max_attempts.into(),
+            guesses_json,
+            guesses_gz: None,
+            guesses_compressed: false,
+            guess_count: game.guesses.len() as i16,
+            completed: game.completed,
+            won: game.won,
+            surrendered: game.surrendered,
+            hard_mode: game.hard_mode,
+            blind_batch_size: game.blind_batch_size.map(i16::from),
+            practice: game.practice,
+            // Stored bit for bit so seeds above i64::MAX survive
+            seed: game.seed.map(|seed| seed as i64),
+            archive_date: game.archive_date,
+            replay_count: game.replay_count as i32,
+            language: game.language.clone(),
+            scoring,
+            powerups_used: game.powerups_used.into(),
+            revealed_positions,
+            version: game.version as i64,
+            created_at: game.created_at,
+            updated_at: game.updated_at,
+        }
+    }
+}
+
+#[cfg(feature = "database")]
+impl TryFrom<GameRow> for Game {
+    type Error = RepositoryError;
+
+    fn try_from(row: GameRow) -> Result<Self, Self::Error> {
+        let guesses =
+            StoredGuesses::from_columns(row.guesses_json, row.guesses_gz, row.guesses_compressed)
+                .decode()?;
+        let revealed_positions = serde_json::from_value(row.revealed_positions)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        let scoring = serde_json::from_value(serde_json::Value::String(row.scoring))
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        let small = |value: i16, column: &str| {
+            u8::try_from(value).map_err(|_| {
+                RepositoryError::SerializationError(format!("{} out of range: {}", column, value))
+            })
+        };
+
+        Ok(Game {
+            id: row.id,
+            user_id: row.user_id,
+            word: row.word,
+            max_attempts: small(row.max_attempts, "max_attempts")?,
+            guesses,
+            completed: row.completed,
+            won: row.won,
+            surrendered: row.surrendered,
+            hard_mode: row.hard_mode,
+            blind_batch_size: row
+                .blind_batch_size
+                .map(|size| small(size, "blind_batch_size"))
+                .transpose()?,
+            practice: row.practice,
+            seed: row.seed.map(|seed| seed as u64),
+            archive_date: row.archive_date,
+            replay_count: row.replay_count as u32,
+            language: row.language,
+            scoring,
+            powerups_used: small(row.powerups_used, "powerups_used")?,
+            revealed_positions,
+            version: row.version as u64,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Summary columns of a row of the `games` table
+///
+/// Selected with `SUMMARY_COLUMNS` so guesses are never loaded; convert it
+/// to a `GameSummary` with `GameSummary::try_from`.
+#[cfg(feature = "database")]
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SummaryRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub won: bool,
+    pub guess_count: i16,
+    pub max_attempts: i16,
+    pub practice: bool,
+    pub replay_count: i32,
+    pub powerups_used: i16,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed: bool,
+}
+
+/// Columns selected into a `SummaryRow`
+#[cfg(feature = "database")]
+pub const SUMMARY_COLUMNS: &str = "id, user_id, won, guess_count, max_attempts, practice, \
+     replay_count, powerups_used, created_at, updated_at, completed";
+
+#[cfg(feature = "database")]
+impl TryFrom<SummaryRow> for GameSummary {
+    type Error = RepositoryError;
+
+    fn try_from(row: SummaryRow) -> Result<Self, Self::Error> {
+        let out_of_range = |column: &str, value: i64| {
+            RepositoryError::SerializationError(format!("{} out of range: {}", column, value))
+        };
+
+        Ok(GameSummary {
+            id: row.id,
+            user_id: row.user_id,
+            won: row.won,
+            guess_count: usize::try_from(row.guess_count)
+                .map_err(|_| out_of_range("guess_count", row.guess_count.into()))?,
+            max_attempts: u8::try_from(row.max_attempts)
+                .map_err(|_| out_of_range("max_attempts", row.max_attempts.into()))?,
+            practice: row.practice,
+            replay_count: u32::try_from(row.replay_count)
+                .map_err(|_| out_of_range("replay_count", row.replay_count.into()))?,
+            powerups_used: u8::try_from(row.powerups_used)
+                .map_err(|_| out_of_range("powerups_used", row.powerups_used.into()))?,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            completed: row.completed,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "database"))]
+mod tests {
+    use super::*;
+    use crate::game::models::ScoringMode;
+    use crate::test_support::played_game;
+
+    #[test]
+    fn games_round_trip_through_rows() {
+        let mut game = played_game(Uuid::new_v4(), "plant", &["house", "plate"]);
+        game.seed = Some(u64::MAX);
+        game.scoring = ScoringMode::Mastermind;
+        game.revealed_positions = vec![1, 3];
+
+        let row = GameRow::from(game.clone());
+        assert_eq!(row.guesses_json[1]["results"][0], "correct");
+        assert_eq!(row.guesses_json[0]["results"][0], "wrong");
+
+        let restored = Game::try_from(row).unwrap();
+        assert_eq!(
+            serde_json::to_value(&restored).unwrap(),
+            serde_json::to_value(&game).unwrap()
+        );
+    }
+}
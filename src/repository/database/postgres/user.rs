@@ -30,7 +30,7 @@ impl PostgresUserRepository {
 #[cfg(feature = "database")]
 #[async_trait]
 impl UserRepositoryTrait for PostgresUserRepository {
-    async fn get_user(&self, id: &Uuid) -> RepositoryResult<User> {
+    async fn get_user(&self, _id: &Uuid) -> RepositoryResult<User> {
         // Implementation would use sqlx to query the database
         // For example:
         // sqlx::query_as!(
@@ -53,17 +53,19 @@ impl UserRepositoryTrait for PostgresUserRepository {
         // Implementation would use sqlx to insert or update a user
         // For example:
         // sqlx::query!(
-        //     "INSERT INTO users (id, username, email, current_game_id, created_at, updated_at)
-        //     VALUES ($1, $2, $3, $4, $5, $6)
+        //     "INSERT INTO users (id, username, email, current_game_ids, password_hash, created_at, updated_at)
+        //     VALUES ($1, $2, $3, $4, $5, $6, $7)
         //     ON CONFLICT (id) DO UPDATE SET
         //         username = EXCLUDED.username,
         //         email = EXCLUDED.email,
-        //         current_game_id = EXCLUDED.current_game_id,
+        //         current_game_ids = EXCLUDED.current_game_ids,
+        //         password_hash = EXCLUDED.password_hash,
         //         updated_at = EXCLUDED.updated_at",
         //     user.id,
         //     user.username,
         //     user.email,
-        //     user.current_game_id,
+        //     serde_json::to_value(&user.current_game_ids)?,
+        //     user.password_hash,
         //     user.created_at,
         //     user.updated_at
         // )
@@ -78,12 +80,29 @@ impl UserRepositoryTrait for PostgresUserRepository {
         ))
     }
 
-    async fn update_user_game(&self, _user_id: &Uuid, _game_id: Uuid) -> RepositoryResult<bool> {
+    async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_user_game(
+        &self,
+        _user_id: &Uuid,
+        _word_length: usize,
+        _game_id: Uuid,
+    ) -> RepositoryResult<bool> {
         // Implementation would use sqlx to update a user's current game ID
         // For example:
         // let result = sqlx::query!(
-        //     "UPDATE users SET current_game_id = $1, updated_at = NOW() WHERE id = $2",
-        //     Some(game_id),
+        //     "UPDATE users SET current_game_ids = current_game_ids || jsonb_build_object($1::text, $2::uuid),
+        //         updated_at = NOW() WHERE id = $3",
+        //     word_length.to_string(),
+        //     game_id,
         //     user_id
         // )
         // .execute(&*self.conn.pool)
@@ -97,11 +116,24 @@ impl UserRepositoryTrait for PostgresUserRepository {
         ))
     }
 
+    async fn clear_user_game(&self, user_id: &Uuid, word_length: usize) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "UPDATE users SET current_game_ids = current_game_ids - $1, updated_at = NOW() WHERE id = $2",
+        )
+        .bind(word_length.to_string())
+        .bind(user_id)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn reset_all_users_current_game(&self) -> RepositoryResult<usize> {
         // Implementation would use sqlx to reset all users' current game IDs
         // For example:
         // let result = sqlx::query!(
-        //     "UPDATE users SET current_game_id = NULL, updated_at = NOW()"
+        //     "UPDATE users SET current_game_ids = '{}'::jsonb, updated_at = NOW()"
         // )
         // .execute(&*self.conn.pool)
         // .await
@@ -113,4 +145,27 @@ impl UserRepositoryTrait for PostgresUserRepository {
             "PostgreSQL user repository is not yet implemented".to_string(),
         ))
     }
+
+    async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32> {
+        let count: i32 = sqlx::query_scalar(
+            "INSERT INTO daily_game_creates (user_id, count) VALUES ($1, 1)
+            ON CONFLICT (user_id) DO UPDATE SET count = daily_game_creates.count + 1
+            RETURNING count",
+        )
+        .bind(user_id)
+        .fetch_one(&*self.conn.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(count as u32)
+    }
+
+    async fn reset_daily_creates(&self) -> RepositoryResult<()> {
+        sqlx::query("DELETE FROM daily_game_creates")
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
 }
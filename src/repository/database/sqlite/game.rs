@@ -0,0 +1,434 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
+use sqlx::{Row, Sqlite};
+use uuid::Uuid;
+
+use super::{SqliteConnection, db_error, decode_timestamp, encode_timestamp, from_json, to_json};
+use crate::game::models::{Game, GameSummary, ScoringMode};
+use crate::repository::error::{RepositoryError, RepositoryResult};
+use crate::repository::{GameRepositoryTrait, PoolStats};
+
+/// Query with arguments bound for SQLite
+type SqliteQuery<'q> = Query<'q, Sqlite, SqliteArguments<'q>>;
+
+/// Columns of the `games` table, in the order `bind_game` binds them
+const GAME_COLUMNS: &str = "id, user_id, word, max_attempts, guesses, completed, won, surrendered, \
+     hard_mode, blind_batch_size, practice, seed, archive_date, replay_count, language, scoring, \
+     powerups_used, revealed_positions, version, created_at, updated_at";
+
+/// Columns selected for a `GameSummary`, counting guesses without decoding them
+const SUMMARY_COLUMNS: &str = "id, user_id, won, json_array_length(guesses) AS guess_count, \
+     max_attempts, practice, replay_count, powerups_used, created_at, updated_at, completed";
+
+/// Placeholders matching `GAME_COLUMNS`
+const GAME_PLACEHOLDERS: &str = "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?";
+
+/// SQLite implementation of game repository
+pub struct SqliteGameRepository {
+    /// Database connection
+    conn: SqliteConnection,
+}
+
+impl SqliteGameRepository {
+    /// Create a new SQLite game repository
+    pub fn new(conn: SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Fetch games matching a query
+    async fn fetch_games<'q>(&self, query: SqliteQuery<'q>) -> RepositoryResult<Vec<Game>> {
+        let rows = query.fetch_all(&*self.conn.pool).await.map_err(db_error)?;
+        rows.iter().map(game_from_row).collect()
+    }
+
+    /// Fetch game summaries matching a query over `SUMMARY_COLUMNS`
+    async fn fetch_summaries<'q>(
+        &self,
+        query: SqliteQuery<'q>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        let rows = query.fetch_all(&*self.conn.pool).await.map_err(db_error)?;
+        rows.iter().map(summary_from_row).collect()
+    }
+}
+
+/// Bind every column of a game, in the order of `GAME_COLUMNS`
+fn bind_game<'q>(query: SqliteQuery<'q>, game: &Game) -> RepositoryResult<SqliteQuery<'q>> {
+    Ok(query
+        .bind(game.id)
+        .bind(game.user_id)
+        .bind(game.word.clone())
+        .bind(i64::from(game.max_attempts))
+        .bind(to_json(&game.guesses)?)
+        .bind(game.completed)
+        .bind(game.won)
+        .bind(game.surrendered)
+        .bind(game.hard_mode)
+        .bind(game.blind_batch_size.map(i64::from))
+        .bind(game.practice)
+        // Seeds are stored bit for bit in SQLite's signed 64-bit integers
+        .bind(game.seed.map(|seed| seed as i64))
+        .bind(game.archive_date)
+        .bind(i64::from(game.replay_count))
+        .bind(game.language.clone())
+        .bind(scoring_name(game.scoring))
+        .bind(i64::from(game.powerups_used))
+        .bind(to_json(&game.revealed_positions)?)
+        .bind(game.version as i64)
+        .bind(encode_timestamp(&game.created_at))
+        .bind(encode_timestamp(&game.updated_at)))
+}
+
+/// Build a game from a row of the `games` table
+fn game_from_row(row: &SqliteRow) -> RepositoryResult<Game> {
+    let small = |column: &str| -> RepositoryResult<u8> {
+        let value: i64 = row.try_get(column).map_err(db_error)?;
+        u8::try_from(value).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+    };
+    let blind_batch_size: Option<i64> = row.try_get("blind_batch_size").map_err(db_error)?;
+    let seed: Option<i64> = row.try_get("seed").map_err(db_error)?;
+    let replay_count: i64 = row.try_get("replay_count").map_err(db_error)?;
+    let version: i64 = row.try_get("version").map_err(db_error)?;
+
+    Ok(Game {
+        id: row.try_get("id").map_err(db_error)?,
+        user_id: row.try_get("user_id").map_err(db_error)?,
+        word: row.try_get("word").map_err(db_error)?,
+        max_attempts: small("max_attempts")?,
+        guesses: from_json(row.try_get("guesses").map_err(db_error)?)?,
+        completed: row.try_get("completed").map_err(db_error)?,
+        won: row.try_get("won").map_err(db_error)?,
+        surrendered: row.try_get("surrendered").map_err(db_error)?,
+        hard_mode: row.try_get("hard_mode").map_err(db_error)?,
+        blind_batch_size: blind_batch_size
+            .map(u8::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?,
+        practice: row.try_get("practice").map_err(db_error)?,
+        seed: seed.map(|seed| seed as u64),
+        archive_date: row.try_get("archive_date").map_err(db_error)?,
+        replay_count: u32::try_from(replay_count)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?,
+        language: row.try_get("language").map_err(db_error)?,
+        scoring: parse_scoring(row.try_get("scoring").map_err(db_error)?)?,
+        powerups_used: small("powerups_used")?,
+        revealed_positions: from_json(row.try_get("revealed_positions").map_err(db_error)?)?,
+        version: version as u64,
+        created_at: decode_timestamp(row.try_get("created_at").map_err(db_error)?)?,
+        updated_at: decode_timestamp(row.try_get("updated_at").map_err(db_error)?)?,
+    })
+}
+
+/// Build a game summary from a row selecting `SUMMARY_COLUMNS`
+fn summary_from_row(row: &SqliteRow) -> RepositoryResult<GameSummary> {
+    let integer = |column: &str| -> RepositoryResult<i64> { row.try_get(column).map_err(db_error) };
+    let out_of_range =
+        |e: std::num::TryFromIntError| RepositoryError::SerializationError(e.to_string());
+
+    Ok(GameSummary {
+        id: row.try_get("id").map_err(db_error)?,
+        user_id: row.try_get("user_id").map_err(db_error)?,
+        won: row.try_get("won").map_err(db_error)?,
+        guess_count: usize::try_from(integer("guess_count")?).map_err(out_of_range)?,
+        max_attempts: u8::try_from(integer("max_attempts")?).map_err(out_of_range)?,
+        practice: row.try_get("practice").map_err(db_error)?,
+        replay_count: u32::try_from(integer("replay_count")?).map_err(out_of_range)?,
+        powerups_used: u8::try_from(integer("powerups_used")?).map_err(out_of_range)?,
+        created_at: decode_timestamp(row.try_get("created_at").map_err(db_error)?)?,
+        updated_at: decode_timestamp(row.try_get("updated_at").map_err(db_error)?)?,
+        completed: row.try_get("completed").map_err(db_error)?,
+    })
+}
+
+/// Get the stored name of a scoring mode
+fn scoring_name(scoring: ScoringMode) -> &'static str {
+    match scoring {
+        ScoringMode::Wordle => "wordle",
+        ScoringMode::Mastermind => "mastermind",
+    }
+}
+
+/// Parse a stored scoring mode name
+fn parse_scoring(name: &str) -> RepositoryResult<ScoringMode> {
+    match name {
+        "wordle" => Ok(ScoringMode::Wordle),
+        "mastermind" => Ok(ScoringMode::Mastermind),
+        _ => Err(RepositoryError::SerializationError(format!(
+            "Unknown scoring mode: {}",
+            name
+        ))),
+    }
+}
+
+#[async_trait]
+impl GameRepositoryTrait for SqliteGameRepository {
+    async fn get_game(&self, id: &Uuid) -> RepositoryResult<Game> {
+        let row = sqlx::query(&format!("SELECT {} FROM games WHERE id = ?", GAME_COLUMNS))
+            .bind(id)
+            .fetch_optional(&*self.conn.pool)
+            .await
+            .map_err(db_error)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        game_from_row(&row)
+    }
+
+    async fn save_game(&self, game: Game) -> RepositoryResult<()> {
+        let sql = format!(
+            "INSERT INTO games ({}) VALUES ({})
+            ON CONFLICT (id) DO UPDATE SET
+                word = excluded.word,
+                guesses = excluded.guesses,
+                completed = excluded.completed,
+                won = excluded.won,
+                surrendered = excluded.surrendered,
+                powerups_used = excluded.powerups_used,
+                revealed_positions = excluded.revealed_positions,
+                version = excluded.version,
+                updated_at = excluded.updated_at",
+            GAME_COLUMNS, GAME_PLACEHOLDERS
+        );
+
+        bind_game(sqlx::query(&sql), &game)?
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn save_game_if_version(
+        &self,
+        game: Game,
+        expected_version: u64,
+    ) -> RepositoryResult<()> {
+        let updated = sqlx::query(
+            "UPDATE games SET
+                word = ?, guesses = ?, completed = ?, won = ?, surrendered = ?,
+                powerups_used = ?, revealed_positions = ?, version = ?, updated_at = ?
+            WHERE id = ? AND version = ?",
+        )
+        .bind(&game.word)
+        .bind(to_json(&game.guesses)?)
+        .bind(game.completed)
+        .bind(game.won)
+        .bind(game.surrendered)
+        .bind(i64::from(game.powerups_used))
+        .bind(to_json(&game.revealed_positions)?)
+        .bind(game.version as i64)
+        .bind(encode_timestamp(&game.updated_at))
+        .bind(game.id)
+        .bind(expected_version as i64)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        if updated.rows_affected() > 0 {
+            return Ok(());
+        }
+
+        // Nothing matched: the game is either gone or was saved by someone else
+        match self.get_game(&game.id).await {
+            Ok(_) => Err(RepositoryError::Conflict),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game> {
+        // The partial unique indexes on daily and archive games turn a duplicate into a no-op
+        let sql = format!(
+            "INSERT INTO games ({}) VALUES ({}) ON CONFLICT DO NOTHING",
+            GAME_COLUMNS, GAME_PLACEHOLDERS
+        );
+        let inserted = bind_game(sqlx::query(&sql), &game)?
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        if inserted.rows_affected() > 0 {
+            return Ok(game);
+        }
+
+        let sql = if game.archive_date.is_some() {
+            format!(
+                "SELECT {} FROM games WHERE user_id = ? AND archive_date = ?",
+                GAME_COLUMNS
+            )
+        } else {
+            format!(
+                "SELECT {} FROM games
+                WHERE user_id = ? AND word = ? AND replay_count = ? AND practice = 0 AND seed IS NULL",
+                GAME_COLUMNS
+            )
+        };
+        let existing = match game.archive_date {
+            Some(date) => sqlx::query(&sql).bind(game.user_id).bind(date),
+            None => sqlx::query(&sql)
+                .bind(game.user_id)
+                .bind(&game.word)
+                .bind(i64::from(game.replay_count)),
+        };
+        let row = existing
+            .fetch_optional(&*self.conn.pool)
+            .await
+            .map_err(db_error)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        game_from_row(&row)
+    }
+
+    async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM games WHERE id = ?")
+            .bind(id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize> {
+        let result = sqlx::query("DELETE FROM games WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn list_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<Vec<Game>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE user_id = ? ORDER BY created_at",
+            GAME_COLUMNS
+        );
+
+        self.fetch_games(sqlx::query(&sql).bind(user_id)).await
+    }
+
+    async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE user_id = ? ORDER BY created_at",
+            SUMMARY_COLUMNS
+        );
+
+        self.fetch_summaries(sqlx::query(&sql).bind(user_id)).await
+    }
+
+    async fn list_games_paginated(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)> {
+        let sql = format!(
+            "SELECT {} FROM games ORDER BY created_at, id LIMIT ? OFFSET ?",
+            GAME_COLUMNS
+        );
+        let games = self
+            .fetch_games(sqlx::query(&sql).bind(limit as i64).bind(offset as i64))
+            .await?;
+
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM games")
+            .fetch_one(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok((games, total as usize))
+    }
+
+    async fn list_games_since(&self, since: DateTime<Utc>) -> RepositoryResult<Vec<Game>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE created_at >= ? ORDER BY created_at",
+            GAME_COLUMNS
+        );
+
+        self.fetch_games(sqlx::query(&sql).bind(encode_timestamp(&since)))
+            .await
+    }
+
+    async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        let sql = format!(
+            "SELECT {} FROM games WHERE created_at >= ? ORDER BY created_at",
+            SUMMARY_COLUMNS
+        );
+
+        self.fetch_summaries(sqlx::query(&sql).bind(encode_timestamp(&since)))
+            .await
+    }
+
+    async fn clear_all_games(&self) -> RepositoryResult<usize> {
+        let result = sqlx::query("DELETE FROM games")
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn drain_all_games(&self) -> RepositoryResult<Vec<Game>> {
+        let sql = format!("DELETE FROM games RETURNING {}", GAME_COLUMNS);
+        self.fetch_games(sqlx::query(&sql)).await
+    }
+
+    async fn drain_incomplete_games(&self) -> RepositoryResult<Vec<Game>> {
+        let sql = format!(
+            "DELETE FROM games WHERE completed = 0 RETURNING {}",
+            GAME_COLUMNS
+        );
+        self.fetch_games(sqlx::query(&sql)).await
+    }
+
+    async fn remove_games_older_than(&self, cutoff: DateTime<Utc>) -> RepositoryResult<usize> {
+        let result = sqlx::query("DELETE FROM games WHERE created_at < ?")
+            .bind(encode_timestamp(&cutoff))
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn get_last_reset_date(&self) -> RepositoryResult<Option<NaiveDate>> {
+        sqlx::query_scalar("SELECT last_date FROM daily_reset WHERE id = 1")
+            .fetch_optional(&*self.conn.pool)
+            .await
+            .map_err(db_error)
+    }
+
+    async fn set_last_reset_date(&self, date: NaiveDate) -> RepositoryResult<()> {
+        sqlx::query(
+            "INSERT INTO daily_reset (id, last_date) VALUES (1, ?)
+            ON CONFLICT (id) DO UPDATE SET last_date = excluded.last_date",
+        )
+        .bind(date)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn health_check(&self) -> RepositoryResult<()> {
+        sqlx::query("SELECT 1")
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    fn pool_stats(&self) -> Option<PoolStats> {
+        Some(PoolStats {
+            size: self.conn.pool.size(),
+            idle: self.conn.pool.num_idle(),
+        })
+    }
+}
@@ -0,0 +1,175 @@
+/// SQLite repository implementations
+///
+/// Guesses and other nested data are stored as JSON text, and timestamps as
+/// RFC 3339 text with a fixed precision so they sort chronologically.
+pub mod game;
+pub mod user;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::SqlitePool;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::common::config::DatabaseConfig;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// Database connection configuration for SQLite
+pub struct SqliteConfig {
+    /// Database connection URL, such as `sqlite://wordle.db` or `sqlite::memory:`
+    pub connection_url: String,
+
+    /// Maximum number of connections in the pool
+    pub max_connections: u32,
+
+    /// Number of idle connections the pool keeps open
+    pub min_connections: u32,
+
+    /// Seconds to wait for a pooled connection before failing
+    pub acquire_timeout: u64,
+
+    /// Seconds after which an idle connection is closed (never if unset)
+    pub idle_timeout: Option<u64>,
+}
+
+impl SqliteConfig {
+    /// Create a new SQLite configuration with sensible defaults
+    pub fn new(connection_url: impl Into<String>) -> Self {
+        Self {
+            connection_url: connection_url.into(),
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout: 30,
+            idle_timeout: Some(600),
+        }
+    }
+
+    /// Create a SQLite configuration from the database settings
+    pub fn from_config(config: &DatabaseConfig) -> Self {
+        Self {
+            connection_url: config.url.clone(),
+            max_connections: config.max_connections,
+            min_connections: config.min_connections,
+            acquire_timeout: config.acquire_timeout_secs,
+            idle_timeout: (config.idle_timeout_secs > 0).then_some(config.idle_timeout_secs),
+        }
+    }
+
+    /// Create a connection pool, creating the database file if it is missing
+    pub async fn create_pool(&self) -> Result<SqlitePool, sqlx::Error> {
+        let options = SqliteConnectOptions::from_str(&self.connection_url)?.create_if_missing(true);
+
+        // Each connection to an in-memory database gets its own database,
+        // so keep a single connection open for the lifetime of the pool
+        let pool_options = if self.connection_url.contains(":memory:") {
+            SqlitePoolOptions::new()
+                .max_connections(1)
+                .idle_timeout(None)
+                .max_lifetime(None)
+        } else {
+            SqlitePoolOptions::new()
+                .max_connections(self.max_connections)
+                .min_connections(self.min_connections)
+                .idle_timeout(self.idle_timeout.map(Duration::from_secs))
+        };
+
+        pool_options
+            .acquire_timeout(Duration::from_secs(self.acquire_timeout))
+            .connect_with(options)
+            .await
+    }
+}
+
+/// Shared database connection for repository implementations
+#[derive(Clone)]
+pub struct SqliteConnection {
+    /// Connection pool to the SQLite database
+    pub pool: Arc<SqlitePool>,
+}
+
+impl SqliteConnection {
+    /// Create a new SQLite connection with the given pool
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+}
+
+/// Convert a sqlx error into a repository error
+fn db_error(e: sqlx::Error) -> RepositoryError {
+    RepositoryError::DatabaseError(e.to_string())
+}
+
+/// Serialize a value into a JSON text column
+fn to_json<T: Serialize>(value: &T) -> RepositoryResult<String> {
+    serde_json::to_string(value).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
+
+/// Deserialize a value from a JSON text column
+fn from_json<T: DeserializeOwned>(value: &str) -> RepositoryResult<T> {
+    serde_json::from_str(value).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
+
+/// Format a timestamp for a text column
+fn encode_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+/// Parse a timestamp from a text column
+fn decode_timestamp(value: &str) -> RepositoryResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::auth::models::User;
+    use crate::repository::{
+        GameRepositoryTrait, SqliteGameRepository, SqliteUserRepository, UserRepositoryTrait,
+    };
+    use crate::test_support::played_game;
+
+    async fn connection() -> SqliteConnection {
+        let pool = SqliteConfig::new("sqlite::memory:")
+            .create_pool()
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations/sqlite")
+            .run(&pool)
+            .await
+            .unwrap();
+        SqliteConnection::new(pool)
+    }
+
+    #[tokio::test]
+    async fn games_and_users_round_trip() {
+        let connection = connection().await;
+        let games = SqliteGameRepository::new(connection.clone());
+        let users = SqliteUserRepository::new(connection);
+
+        let user = User::try_new(Uuid::new_v4(), "tester".to_string()).unwrap();
+        let game = played_game(user.id, "plant", &["house", "plate"]);
+        users.save_user(user.clone()).await.unwrap();
+        users.update_user_game(&user.id, 5, game.id).await.unwrap();
+        games.save_game(game.clone()).await.unwrap();
+
+        let stored = games.get_game(&game.id).await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&stored).unwrap(),
+            serde_json::to_value(&game).unwrap()
+        );
+
+        let stored = users.get_user(&user.id).await.unwrap();
+        assert_eq!(stored.username, user.username);
+        assert_eq!(stored.created_at, user.created_at);
+        assert_eq!(stored.current_game_ids.get(&5), Some(&game.id));
+    }
+}
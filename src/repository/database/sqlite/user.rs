@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use std::collections::HashMap;
+use std::num::ParseIntError;
+use uuid::Uuid;
+
+use super::{SqliteConnection, db_error, decode_timestamp, encode_timestamp, from_json, to_json};
+use crate::auth::models::User;
+use crate::repository::UserRepositoryTrait;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// SQLite implementation of user repository
+pub struct SqliteUserRepository {
+    /// Database connection
+    conn: SqliteConnection,
+}
+
+impl SqliteUserRepository {
+    /// Create a new SQLite user repository
+    pub fn new(conn: SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+/// Build a user from a row of the `users` table
+fn user_from_row(row: &SqliteRow) -> RepositoryResult<User> {
+    // JSON object keys are strings, so parse them back into word lengths
+    let current_game_ids: HashMap<String, Uuid> =
+        from_json(row.try_get("current_game_ids").map_err(db_error)?)?;
+    let current_game_ids = current_game_ids
+        .into_iter()
+        .map(|(length, id)| {
+            length
+                .parse()
+                .map(|length| (length, id))
+                .map_err(|e: ParseIntError| RepositoryError::SerializationError(e.to_string()))
+        })
+        .collect::<RepositoryResult<_>>()?;
+
+    Ok(User {
+        id: row.try_get("id").map_err(db_error)?,
+        username: row.try_get("username").map_err(db_error)?,
+        created_at: decode_timestamp(row.try_get("created_at").map_err(db_error)?)?,
+        updated_at: decode_timestamp(row.try_get("updated_at").map_err(db_error)?)?,
+        current_game_ids,
+        password_hash: row.try_get("password_hash").map_err(db_error)?,
+    })
+}
+
+/// JSON path of a word length's entry in `current_game_ids`
+fn game_id_path(word_length: usize) -> String {
+    format!("$.\"{}\"", word_length)
+}
+
+#[async_trait]
+impl UserRepositoryTrait for SqliteUserRepository {
+    async fn get_user(&self, id: &Uuid) -> RepositoryResult<User> {
+        let row = sqlx::query(
+            "SELECT id, username, current_game_ids, password_hash, created_at, updated_at
+            FROM users WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&*self.conn.pool)
+        .await
+        .map_err(db_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        user_from_row(&row)
+    }
+
+    async fn save_user(&self, user: User) -> RepositoryResult<()> {
+        sqlx::query(
+            "INSERT INTO users (id, username, current_game_ids, password_hash, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (id) DO UPDATE SET
+                username = excluded.username,
+                current_game_ids = excluded.current_game_ids,
+                password_hash = excluded.password_hash,
+                updated_at = excluded.updated_at",
+        )
+        .bind(user.id)
+        .bind(&user.username)
+        .bind(to_json(&user.current_game_ids)?)
+        .bind(&user.password_hash)
+        .bind(encode_timestamp(&user.created_at))
+        .bind(encode_timestamp(&user.updated_at))
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM users WHERE id = ?")
+            .bind(id)
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "UPDATE users SET current_game_ids = json_set(current_game_ids, ?, ?), updated_at = ?
+            WHERE id = ?",
+        )
+        .bind(game_id_path(word_length))
+        .bind(game_id.to_string())
+        .bind(encode_timestamp(&Utc::now()))
+        .bind(user_id)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn clear_user_game(&self, user_id: &Uuid, word_length: usize) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "UPDATE users SET current_game_ids = json_remove(current_game_ids, ?), updated_at = ?
+            WHERE id = ?",
+        )
+        .bind(game_id_path(word_length))
+        .bind(encode_timestamp(&Utc::now()))
+        .bind(user_id)
+        .execute(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn reset_all_users_current_game(&self) -> RepositoryResult<usize> {
+        let result = sqlx::query("UPDATE users SET current_game_ids = '{}', updated_at = ?")
+            .bind(encode_timestamp(&Utc::now()))
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32> {
+        let count: i64 = sqlx::query_scalar(
+            "INSERT INTO daily_game_creates (user_id, count) VALUES (?, 1)
+            ON CONFLICT (user_id) DO UPDATE SET count = count + 1
+            RETURNING count",
+        )
+        .bind(user_id)
+        .fetch_one(&*self.conn.pool)
+        .await
+        .map_err(db_error)?;
+
+        u32::try_from(count).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+    }
+
+    async fn reset_daily_creates(&self) -> RepositoryResult<()> {
+        sqlx::query("DELETE FROM daily_game_creates")
+            .execute(&*self.conn.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use std::future::Future;
+use tokio::time::{Instant, timeout_at};
+
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+tokio::task_local! {
+    /// Deadline of the request being handled on the current task
+    static REQUEST_DEADLINE: Instant;
+}
+
+/// Run a future with a deadline observed by repository calls made within it
+pub async fn scope<F: Future>(deadline: Instant, future: F) -> F::Output {
+    REQUEST_DEADLINE.scope(deadline, future).await
+}
+
+/// Run a repository call, aborting it once the current request's deadline passes
+///
+/// Calls made outside a request scope run without a deadline.
+pub async fn with_deadline<T>(
+    future: impl Future<Output = RepositoryResult<T>>,
+) -> RepositoryResult<T> {
+    let Ok(deadline) = REQUEST_DEADLINE.try_with(|deadline| *deadline) else {
+        return future.await;
+    };
+
+    timeout_at(deadline, future).await.unwrap_or_else(|_| {
+        tracing::warn!("Repository call aborted at the request deadline");
+        Err(RepositoryError::ConnectionError(
+            "Request deadline exceeded".to_string(),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stalled_calls_abort_at_the_deadline() {
+        let start = Instant::now();
+        let stalled = std::future::pending::<RepositoryResult<()>>();
+
+        let result = scope(start + Duration::from_millis(50), with_deadline(stalled)).await;
+
+        assert!(matches!(result, Err(RepositoryError::ConnectionError(_))));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn calls_outside_a_request_have_no_deadline() {
+        let slow = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(1)
+        };
+
+        assert_eq!(with_deadline(slow).await.unwrap(), 1);
+    }
+}
@@ -18,6 +18,12 @@ pub enum RepositoryError {
 
     #[error("Operation not supported: {0}")]
     Unsupported(String),
+
+    #[error("Item was modified concurrently")]
+    Conflict,
+
+    #[error("Daily reset in progress")]
+    ResetInProgress,
 }
 
 /// Shorthand for repository operation results
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::game::models::Game;
+use crate::game::models::{Game, GameSummary};
 use crate::repository::GameRepositoryTrait;
 use crate::repository::error::{RepositoryError, RepositoryResult};
+use crate::repository::memory::wal::{WalRecord, WriteAheadLog};
 
 /// In-memory implementation of game repository
 /// Useful for testing and development environments
@@ -13,6 +16,12 @@ use crate::repository::error::{RepositoryError, RepositoryResult};
 pub struct InMemoryGameRepository {
     /// In-memory game storage, keyed by game ID
     games: RwLock<HashMap<Uuid, Game>>,
+
+    /// Date of the last daily reset
+    last_reset_date: RwLock<Option<NaiveDate>>,
+
+    /// Optional log that changes are appended to before being applied
+    wal: Option<Arc<WriteAheadLog>>,
 }
 
 impl InMemoryGameRepository {
@@ -20,6 +29,27 @@ impl InMemoryGameRepository {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create a repository with games replayed from a log, logging further changes to it
+    pub fn with_wal(
+        wal: Arc<WriteAheadLog>,
+        games: HashMap<Uuid, Game>,
+        last_reset_date: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            games: RwLock::new(games),
+            last_reset_date: RwLock::new(last_reset_date),
+            wal: Some(wal),
+        }
+    }
+
+    /// Append a record to the log if one is configured
+    fn log(&self, record: impl FnOnce() -> WalRecord) -> RepositoryResult<()> {
+        match &self.wal {
+            Some(wal) => wal.append(&record()),
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -34,16 +64,268 @@ impl GameRepositoryTrait for InMemoryGameRepository {
     /// Save a game
     async fn save_game(&self, game: Game) -> RepositoryResult<()> {
         let mut games = self.games.write();
+        self.log(|| WalRecord::SaveGame { game: game.clone() })?;
+        games.insert(game.id, game);
+        Ok(())
+    }
+
+    /// Save a game if its stored version matches, checked under the write lock
+    async fn save_game_if_version(
+        &self,
+        game: Game,
+        expected_version: u64,
+    ) -> RepositoryResult<()> {
+        let mut games = self.games.write();
+        let stored = games.get(&game.id).ok_or(RepositoryError::NotFound)?;
+        if stored.version != expected_version {
+            return Err(RepositoryError::Conflict);
+        }
+
+        self.log(|| WalRecord::SaveGame { game: game.clone() })?;
         games.insert(game.id, game);
         Ok(())
     }
 
+    /// Save a daily or archive game unless the user already has it, checked under the write lock
+    async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game> {
+        let mut games = self.games.write();
+        if let Some(existing) = games
+            .values()
+            .find(|other| other.is_same_daily_game(&game) || other.is_same_archive_game(&game))
+        {
+            return Ok(existing.clone());
+        }
+
+        self.log(|| WalRecord::SaveGame { game: game.clone() })?;
+        games.insert(game.id, game.clone());
+        Ok(game)
+    }
+
+    /// Delete a game by ID
+    async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()> {
+        let mut games = self.games.write();
+        if !games.contains_key(id) {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.log(|| WalRecord::DeleteGame { id: *id })?;
+        games.remove(id);
+        Ok(())
+    }
+
+    /// Delete all games owned by a user and return the count of deleted games
+    async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize> {
+        let mut games = self.games.write();
+        self.log(|| WalRecord::DeleteUserGames { user_id: *user_id })?;
+
+        let before = games.len();
+        games.retain(|_, game| game.user_id != *user_id);
+        Ok(before - games.len())
+    }
+
+    /// List all games owned by a user
+    async fn list_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<Vec<Game>> {
+        let games = self.games.read();
+
+        Ok(games
+            .values()
+            .filter(|game| game.user_id == *user_id)
+            .cloned()
+            .collect())
+    }
+
+    /// List summaries of all games for a user
+    async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>> {
+        let games = self.games.read();
+
+        Ok(games
+            .values()
+            .filter(|game| game.user_id == *user_id)
+            .map(GameSummary::from)
+            .collect())
+    }
+
+    /// List a page of all games ordered by creation time, with the total game count
+    async fn list_games_paginated(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)> {
+        let games = self.games.read();
+
+        let mut sorted: Vec<&Game> = games.values().collect();
+        sorted.sort_by_key(|game| (game.created_at, game.id));
+
+        let page = sorted
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect();
+        Ok((page, games.len()))
+    }
+
+    /// List all games created at or after the given time
+    async fn list_games_since(&self, since: DateTime<Utc>) -> RepositoryResult<Vec<Game>> {
+        let games = self.games.read();
+
+        Ok(games
+            .values()
+            .filter(|game| game.created_at >= since)
+            .cloned()
+            .collect())
+    }
+
+    /// List summaries of all games created at or after the given time
+    async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        let games = self.games.read();
+
+        Ok(games
+            .values()
+            .filter(|game| game.created_at >= since)
+            .map(GameSummary::from)
+            .collect())
+    }
+
     /// Clear all games and return the count of cleared games
     async fn clear_all_games(&self) -> RepositoryResult<usize> {
         let mut games = self.games.write();
+        self.log(|| WalRecord::ClearGames)?;
         let cleared_count = games.len();
         games.clear();
 
         Ok(cleared_count)
     }
+
+    /// Clear all games and return the cleared games
+    async fn drain_all_games(&self) -> RepositoryResult<Vec<Game>> {
+        let mut games = self.games.write();
+        self.log(|| WalRecord::ClearGames)?;
+
+        Ok(games.drain().map(|(_, game)| game).collect())
+    }
+
+    /// Clear games that are not completed and return the cleared games
+    async fn drain_incomplete_games(&self) -> RepositoryResult<Vec<Game>> {
+        let mut games = self.games.write();
+        self.log(|| WalRecord::ClearIncompleteGames)?;
+        let (kept, cleared): (HashMap<_, _>, HashMap<_, _>) =
+            games.drain().partition(|(_, game)| game.completed);
+        *games = kept;
+
+        Ok(cleared.into_values().collect())
+    }
+
+    /// Remove games created before the cutoff and return the count of removed games
+    async fn remove_games_older_than(&self, cutoff: DateTime<Utc>) -> RepositoryResult<usize> {
+        let mut games = self.games.write();
+        self.log(|| WalRecord::RemoveGamesOlderThan { cutoff })?;
+        let before = games.len();
+        games.retain(|_, game| game.created_at >= cutoff);
+
+        Ok(before - games.len())
+    }
+
+    /// Get the date of the last daily reset, if one was recorded
+    async fn get_last_reset_date(&self) -> RepositoryResult<Option<NaiveDate>> {
+        Ok(*self.last_reset_date.read())
+    }
+
+    /// Record the date of the last daily reset
+    async fn set_last_reset_date(&self, date: NaiveDate) -> RepositoryResult<()> {
+        let mut last_reset_date = self.last_reset_date.write();
+        self.log(|| WalRecord::SetResetDate { date })?;
+        *last_reset_date = Some(date);
+        Ok(())
+    }
+
+    /// Games live in this process, so there is nothing to check
+    fn is_in_memory(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::played_game;
+
+    #[tokio::test]
+    async fn summaries_match_full_games() {
+        let repo = InMemoryGameRepository::new();
+        let user_id = Uuid::new_v4();
+        let mut practice = played_game(user_id, "plant", &["plate"]);
+        practice.practice = true;
+        for game in [
+            played_game(user_id, "plant", &["plate", "plant"]),
+            played_game(user_id, "apple", &["plate", "plane", "house"]),
+            practice,
+            played_game(Uuid::new_v4(), "plant", &["plant"]),
+        ] {
+            repo.save_game(game).await.unwrap();
+        }
+
+        let games = repo.list_games_for_user(&user_id).await.unwrap();
+        let summaries = repo.list_game_summaries(&user_id).await.unwrap();
+        assert_eq!(summaries.len(), 3);
+        for summary in &summaries {
+            let game = games.iter().find(|g| g.id == summary.id).unwrap();
+            assert_eq!(summary.user_id, game.user_id);
+            assert_eq!(summary.won, game.won);
+            assert_eq!(summary.completed, game.completed);
+            assert_eq!(summary.guess_count, game.guesses.len());
+            assert_eq!(summary.practice, game.practice);
+            assert_eq!(summary.created_at, game.created_at);
+        }
+
+        let since = repo
+            .list_game_summaries_since(DateTime::<Utc>::MIN_UTC)
+            .await
+            .unwrap();
+        assert_eq!(since.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn only_games_before_the_cutoff_are_removed() {
+        let repo = InMemoryGameRepository::new();
+        let mut old = played_game(Uuid::new_v4(), "plant", &[]);
+        old.created_at -= chrono::Duration::days(10);
+        let fresh = played_game(Uuid::new_v4(), "plant", &[]);
+        repo.save_game(old.clone()).await.unwrap();
+        repo.save_game(fresh.clone()).await.unwrap();
+
+        let cutoff = Utc::now() - chrono::Duration::days(1);
+        assert_eq!(repo.remove_games_older_than(cutoff).await.unwrap(), 1);
+        assert!(matches!(
+            repo.get_game(&old.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(repo.get_game(&fresh.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn draining_returns_every_stored_game() {
+        let repo = InMemoryGameRepository::new();
+        let mut ids: Vec<Uuid> = Vec::new();
+        for guesses in [&["plant"][..], &["house"], &[]] {
+            let game = played_game(Uuid::new_v4(), "plant", guesses);
+            ids.push(game.id);
+            repo.save_game(game).await.unwrap();
+        }
+
+        let mut drained: Vec<Uuid> = repo
+            .drain_all_games()
+            .await
+            .unwrap()
+            .iter()
+            .map(|game| game.id)
+            .collect();
+        drained.sort();
+        ids.sort();
+        assert_eq!(drained, ids);
+        assert_eq!(repo.clear_all_games().await.unwrap(), 0);
+    }
 }
@@ -1,5 +1,7 @@
 pub mod game;
 pub mod user;
+pub mod wal;
 
 pub use game::InMemoryGameRepository;
 pub use user::InMemoryUserRepository;
+pub use wal::WriteAheadLog;
@@ -2,11 +2,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::models::User;
 use crate::repository::UserRepositoryTrait;
 use crate::repository::error::{RepositoryError, RepositoryResult};
+use crate::repository::memory::wal::{WalRecord, WriteAheadLog};
 
 /// In-memory implementation of user repository
 /// Useful for testing and development environments
@@ -14,6 +16,13 @@ use crate::repository::error::{RepositoryError, RepositoryResult};
 pub struct InMemoryUserRepository {
     /// In-memory user storage, keyed by user ID
     users: RwLock<HashMap<Uuid, User>>,
+
+    /// Number of games each user created today, not logged as it only
+    /// matters until the next daily reset
+    daily_creates: RwLock<HashMap<Uuid, u32>>,
+
+    /// Optional log that changes are appended to before being applied
+    wal: Option<Arc<WriteAheadLog>>,
 }
 
 impl InMemoryUserRepository {
@@ -21,6 +30,50 @@ impl InMemoryUserRepository {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create a repository with users replayed from a log, logging further changes to it
+    pub fn with_wal(wal: Arc<WriteAheadLog>, users: HashMap<Uuid, User>) -> Self {
+        Self {
+            users: RwLock::new(users),
+            daily_creates: RwLock::default(),
+            wal: Some(wal),
+        }
+    }
+
+    /// Append a record to the log if one is configured
+    fn log(&self, record: impl FnOnce() -> WalRecord) -> RepositoryResult<()> {
+        match &self.wal {
+            Some(wal) => wal.append(&record()),
+            None => Ok(()),
+        }
+    }
+
+    /// Set a user's current game ID for a word length, returning whether the user exists
+    fn set_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Option<Uuid>,
+    ) -> RepositoryResult<bool> {
+        let mut users = self.users.write();
+
+        if let Some(user) = users.get_mut(user_id) {
+            let mut updated = user.clone();
+            match game_id {
+                Some(game_id) => updated.current_game_ids.insert(word_length, game_id),
+                None => updated.current_game_ids.remove(&word_length),
+            };
+            updated.updated_at = Utc::now();
+
+            self.log(|| WalRecord::SaveUser {
+                user: updated.clone(),
+            })?;
+            *user = updated;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
 }
 
 #[async_trait]
@@ -34,34 +87,64 @@ impl UserRepositoryTrait for InMemoryUserRepository {
     /// Save a user
     async fn save_user(&self, user: User) -> RepositoryResult<()> {
         let mut users = self.users.write();
+        self.log(|| WalRecord::SaveUser { user: user.clone() })?;
         users.insert(user.id, user);
         Ok(())
     }
 
-    /// Update a user's current game ID
-    async fn update_user_game(&self, user_id: &Uuid, game_id: Uuid) -> RepositoryResult<bool> {
+    /// Delete a user, returning whether the user existed
+    async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool> {
         let mut users = self.users.write();
-
-        if let Some(user) = users.get_mut(user_id) {
-            user.current_game_id = Some(game_id);
-            user.updated_at = Utc::now();
-            Ok(true)
-        } else {
-            Ok(false)
+        if !users.contains_key(id) {
+            return Ok(false);
         }
+
+        self.log(|| WalRecord::DeleteUser { id: *id })?;
+        users.remove(id);
+        Ok(true)
+    }
+
+    /// Update a user's current game ID for a word length
+    async fn update_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.set_user_game(user_id, word_length, Some(game_id))
+    }
+
+    /// Clear a user's current game ID for a word length
+    async fn clear_user_game(&self, user_id: &Uuid, word_length: usize) -> RepositoryResult<bool> {
+        self.set_user_game(user_id, word_length, None)
     }
 
     /// Reset all users' current game IDs and return the count of updated users
     async fn reset_all_users_current_game(&self) -> RepositoryResult<usize> {
         let mut users = self.users.write();
+        self.log(|| WalRecord::ResetUserGames)?;
         let updated_count = users.len();
 
-        // Clear the current game ID for all users
+        // Clear the current game IDs for all users
         for user in users.values_mut() {
-            user.current_game_id = None;
+            user.current_game_ids.clear();
             user.updated_at = Utc::now();
         }
 
         Ok(updated_count)
     }
+
+    /// Count a game created by a user today, returning the user's count so far
+    async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32> {
+        let mut daily_creates = self.daily_creates.write();
+        let count = daily_creates.entry(*user_id).or_insert(0);
+        *count = count.saturating_add(1);
+        Ok(*count)
+    }
+
+    /// Reset every user's count of games created today
+    async fn reset_daily_creates(&self) -> RepositoryResult<()> {
+        self.daily_creates.write().clear();
+        Ok(())
+    }
 }
@@ -0,0 +1,211 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use uuid::Uuid;
+
+use crate::auth::models::User;
+use crate::game::models::Game;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// A single change to the in-memory repositories
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum WalRecord {
+    /// A game was inserted or replaced
+    SaveGame { game: Game },
+
+    /// A game was deleted
+    DeleteGame { id: Uuid },
+
+    /// All games of a user were deleted
+    DeleteUserGames { user_id: Uuid },
+
+    /// All games were cleared
+    ClearGames,
+
+    /// Incomplete games were cleared
+    ClearIncompleteGames,
+
+    /// Games created before the cutoff were removed
+    RemoveGamesOlderThan { cutoff: DateTime<Utc> },
+
+    /// A daily reset happened on the given date
+    SetResetDate { date: NaiveDate },
+
+    /// A user was inserted or replaced
+    SaveUser { user: User },
+
+    /// A user was deleted
+    DeleteUser { id: Uuid },
+
+    /// All users' current games were reset
+    ResetUserGames,
+}
+
+/// Repository contents rebuilt by replaying a log
+#[derive(Debug, Default)]
+pub struct WalState {
+    /// Games keyed by ID
+    pub games: HashMap<Uuid, Game>,
+
+    /// Users keyed by ID
+    pub users: HashMap<Uuid, User>,
+
+    /// Date of the last daily reset
+    pub last_reset_date: Option<NaiveDate>,
+}
+
+impl WalState {
+    /// Apply a record to the state
+    fn apply(&mut self, record: WalRecord) {
+        match record {
+            WalRecord::SaveGame { game } => {
+                self.games.insert(game.id, game);
+            }
+            WalRecord::DeleteGame { id } => {
+                self.games.remove(&id);
+            }
+            WalRecord::DeleteUserGames { user_id } => {
+                self.games.retain(|_, game| game.user_id != user_id);
+            }
+            WalRecord::ClearGames => self.games.clear(),
+            WalRecord::ClearIncompleteGames => self.games.retain(|_, game| game.completed),
+            WalRecord::RemoveGamesOlderThan { cutoff } => {
+                self.games.retain(|_, game| game.created_at >= cutoff)
+            }
+            WalRecord::SetResetDate { date } => self.last_reset_date = Some(date),
+            WalRecord::SaveUser { user } => {
+                self.users.insert(user.id, user);
+            }
+            WalRecord::DeleteUser { id } => {
+                self.users.remove(&id);
+            }
+            WalRecord::ResetUserGames => {
+                for user in self.users.values_mut() {
+                    user.current_game_ids.clear();
+                }
+            }
+        }
+    }
+}
+
+/// Append-only log of repository changes, one JSON record per line
+#[derive(Debug)]
+pub struct WriteAheadLog {
+    file: Mutex<File>,
+}
+
+impl WriteAheadLog {
+    /// Open the log at `path`, replaying existing records into a state
+    ///
+    /// Unreadable lines (e.g. a record cut short by a crash) are skipped.
+    pub fn open(path: &Path) -> io::Result<(Self, WalState)> {
+        let mut state = WalState::default();
+
+        if path.exists() {
+            let reader = BufReader::new(File::open(path)?);
+            for (number, line) in reader.lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                match serde_json::from_str(&line) {
+                    Ok(record) => state.apply(record),
+                    Err(e) => tracing::warn!(
+                        "Skipping unreadable WAL record at {}:{}: {}",
+                        path.display(),
+                        number + 1,
+                        e
+                    ),
+                }
+            }
+        }
+
+        tracing::info!(
+            "Replayed WAL {}: {} games, {} users",
+            path.display(),
+            state.games.len(),
+            state.users.len()
+        );
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok((
+            Self {
+                file: Mutex::new(file),
+            },
+            state,
+        ))
+    }
+
+    /// Append a record to the log
+    pub fn append(&self, record: &WalRecord) -> RepositoryResult<()> {
+        let mut line = serde_json::to_string(record)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        line.push('\n');
+
+        self.file
+            .lock()
+            .write_all(line.as_bytes())
+            .map_err(|e| RepositoryError::DatabaseError(format!("WAL write failed: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::repository::memory::{InMemoryGameRepository, InMemoryUserRepository};
+    use crate::repository::{GameRepositoryTrait, UserRepositoryTrait};
+    use crate::test_support::played_game;
+
+    /// Open the log, returning repositories over its replayed state
+    fn open(path: &Path) -> (InMemoryGameRepository, InMemoryUserRepository) {
+        let (wal, state) = WriteAheadLog::open(path).unwrap();
+        let wal = Arc::new(wal);
+        (
+            InMemoryGameRepository::with_wal(wal.clone(), state.games, state.last_reset_date),
+            InMemoryUserRepository::with_wal(wal, state.users),
+        )
+    }
+
+    #[tokio::test]
+    async fn restart_restores_saved_games_and_users() {
+        let path = std::env::temp_dir().join(format!("wordle-wal-{}.jsonl", Uuid::new_v4()));
+        let user = User::new(Uuid::new_v4(), "tester".to_string());
+        let kept = played_game(user.id, "plant", &["house"]);
+        let deleted = played_game(user.id, "house", &[]);
+        let date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+
+        {
+            let (games, users) = open(&path);
+            users.save_user(user.clone()).await.unwrap();
+            games.save_game(kept.clone()).await.unwrap();
+            games.save_game(deleted.clone()).await.unwrap();
+            games.delete_game(&deleted.id).await.unwrap();
+            games.set_last_reset_date(date).await.unwrap();
+        }
+
+        // A record cut short by a crash is skipped
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"op\":\"save_game\",\"game\":").unwrap();
+        drop(file);
+
+        let (games, users) = open(&path);
+        let restored = games.get_game(&kept.id).await.unwrap();
+        assert_eq!(restored.guesses.len(), 1);
+        assert!(matches!(
+            games.get_game(&deleted.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert_eq!(games.get_last_reset_date().await.unwrap(), Some(date));
+        assert_eq!(users.get_user(&user.id).await.unwrap().username, "tester");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
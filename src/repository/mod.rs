@@ -1,16 +1,31 @@
 pub mod database;
+pub mod deadline;
 pub mod error;
 pub mod memory;
+#[cfg(feature = "redis")]
+pub mod redis;
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::models::User;
 use crate::common::config::Config;
-use crate::game::models::Game;
+use crate::game::models::{Game, GameSummary};
 use error::RepositoryResult;
 
+/// Current usage of a database connection pool
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PoolStats {
+    /// Connections currently open, idle or in use
+    pub size: u32,
+
+    /// Open connections not in use
+    pub idle: usize,
+}
+
 /// Repository trait for game data access
 #[async_trait]
 pub trait GameRepositoryTrait: Send + Sync {
@@ -20,8 +35,76 @@ pub trait GameRepositoryTrait: Send + Sync {
     /// Save a game
     async fn save_game(&self, game: Game) -> RepositoryResult<()>;
 
+    /// Save a game only if the stored copy is still at `expected_version`,
+    /// failing with `RepositoryError::Conflict` otherwise
+    async fn save_game_if_version(&self, game: Game, expected_version: u64)
+    -> RepositoryResult<()>;
+
+    /// Save a daily game unless the user already has one for the same word,
+    /// or an archive game unless the user already has one for the same date,
+    /// returning whichever game is stored
+    async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game>;
+
+    /// Delete a game by ID
+    async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()>;
+
+    /// Delete all games owned by a user and return the count of deleted games
+    async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize>;
+
+    /// List all games owned by a user
+    async fn list_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<Vec<Game>>;
+
+    /// List summaries of all games for a user
+    async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>>;
+
+    /// List a page of all games ordered by creation time, with the total game count
+    async fn list_games_paginated(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)>;
+
+    /// List all games created at or after the given time
+    async fn list_games_since(&self, since: DateTime<Utc>) -> RepositoryResult<Vec<Game>>;
+
+    /// List summaries of all games created at or after the given time
+    async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>>;
+
     /// Clear all games and return the count of cleared games
     async fn clear_all_games(&self) -> RepositoryResult<usize>;
+
+    /// Clear all games and return the cleared games
+    async fn drain_all_games(&self) -> RepositoryResult<Vec<Game>>;
+
+    /// Clear games that are not completed and return the cleared games
+    async fn drain_incomplete_games(&self) -> RepositoryResult<Vec<Game>>;
+
+    /// Remove games created before the cutoff and return the count of removed games
+    async fn remove_games_older_than(&self, cutoff: DateTime<Utc>) -> RepositoryResult<usize>;
+
+    /// Get the date of the last daily reset, if one was recorded
+    async fn get_last_reset_date(&self) -> RepositoryResult<Option<NaiveDate>>;
+
+    /// Record the date of the last daily reset
+    async fn set_last_reset_date(&self, date: NaiveDate) -> RepositoryResult<()>;
+
+    /// Check that the backing store is reachable
+    async fn health_check(&self) -> RepositoryResult<()> {
+        Ok(())
+    }
+
+    /// Whether games are held only in process memory, with nothing to check
+    fn is_in_memory(&self) -> bool {
+        false
+    }
+
+    /// Current usage of the connection pool, for backends that have one
+    fn pool_stats(&self) -> Option<PoolStats> {
+        None
+    }
 }
 
 /// Repository trait for user data access
@@ -33,11 +116,28 @@ pub trait UserRepositoryTrait: Send + Sync {
     /// Save a user
     async fn save_user(&self, user: User) -> RepositoryResult<()>;
 
-    /// Update a user's current game ID
-    async fn update_user_game(&self, user_id: &Uuid, game_id: Uuid) -> RepositoryResult<bool>;
+    /// Delete a user, returning whether the user existed
+    async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool>;
+
+    /// Update a user's current game ID for a word length
+    async fn update_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Uuid,
+    ) -> RepositoryResult<bool>;
+
+    /// Clear a user's current game ID for a word length
+    async fn clear_user_game(&self, user_id: &Uuid, word_length: usize) -> RepositoryResult<bool>;
 
     /// Reset all users' current game IDs and return the count of updated users
     async fn reset_all_users_current_game(&self) -> RepositoryResult<usize>;
+
+    /// Count a game created by a user today, returning the user's count so far
+    async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32>;
+
+    /// Reset every user's count of games created today
+    async fn reset_daily_creates(&self) -> RepositoryResult<()>;
 }
 
 // Re-export database implementations
@@ -45,15 +145,106 @@ pub trait UserRepositoryTrait: Send + Sync {
 pub use database::postgres::{
     PostgresConfig, PostgresConnection, game::PostgresGameRepository, user::PostgresUserRepository,
 };
+#[cfg(feature = "sqlite")]
+pub use database::sqlite::{
+    SqliteConfig, SqliteConnection, game::SqliteGameRepository, user::SqliteUserRepository,
+};
 
 /// Initialize repositories based on configuration
-#[cfg(feature = "database")]
+///
+/// Redis is used when the `redis` feature is enabled and `REDIS_URL` is set.
 pub async fn init_repositories(
     config: &Config,
 ) -> anyhow::Result<(
     Arc<dyn GameRepositoryTrait + Send + Sync>,
     Arc<dyn UserRepositoryTrait + Send + Sync>,
+)> {
+    #[cfg(feature = "redis")]
+    if let Some(url) = config.redis.url.as_deref() {
+        use crate::repository::redis::{RedisConnection, RedisGameRepository, RedisUserRepository};
+
+        tracing::info!("Using Redis storage");
+
+        let connection = RedisConnection::connect(url).await?;
+        let game_repo = Arc::new(RedisGameRepository::new(connection.clone(), &config.game))
+            as Arc<dyn GameRepositoryTrait + Send + Sync>;
+        let user_repo = Arc::new(RedisUserRepository::new(connection))
+            as Arc<dyn UserRepositoryTrait + Send + Sync>;
+
+        return Ok((game_repo, user_repo));
+    }
+
+    init_default_repositories(config).await
+}
+
+/// Initialize repositories for the `DATABASE_URL` scheme, if its backend is built in
+///
+/// `sqlite:` URLs need the `sqlite` feature and `postgres:` URLs the `database`
+/// feature; anything else falls back to in-memory storage.
+async fn init_default_repositories(
+    config: &Config,
+) -> anyhow::Result<(
+    Arc<dyn GameRepositoryTrait + Send + Sync>,
+    Arc<dyn UserRepositoryTrait + Send + Sync>,
+)> {
+    let url = config.database.url.as_str();
+
+    #[cfg(feature = "sqlite")]
+    if url.starts_with("sqlite:") {
+        return init_sqlite_repositories(config).await;
+    }
+
+    #[cfg(feature = "database")]
+    if url.starts_with("postgres:") || url.starts_with("postgresql:") {
+        return init_postgres_repositories(config).await;
+    }
+
+    if !url.starts_with("sqlite:") {
+        tracing::warn!("DATABASE_URL is not supported by this build, ignoring it");
+    }
+
+    init_memory_repositories(config).await
+}
+
+/// Initialize SQLite repositories
+#[cfg(feature = "sqlite")]
+async fn init_sqlite_repositories(
+    config: &Config,
+) -> anyhow::Result<(
+    Arc<dyn GameRepositoryTrait + Send + Sync>,
+    Arc<dyn UserRepositoryTrait + Send + Sync>,
 )> {
+    use crate::repository::{
+        SqliteConfig, SqliteConnection, SqliteGameRepository, SqliteUserRepository,
+    };
+
+    tracing::info!("Using SQLite database");
+
+    let db_config = SqliteConfig::from_config(&config.database);
+    let pool = db_config.create_pool().await?;
+
+    // Apply the SQLite schema, which is kept apart from the PostgreSQL migrations
+    tracing::info!("Running database migrations");
+    sqlx::migrate!("./migrations/sqlite").run(&pool).await?;
+    let connection = SqliteConnection::new(pool);
+
+    let game_repo = Arc::new(SqliteGameRepository::new(connection.clone()))
+        as Arc<dyn GameRepositoryTrait + Send + Sync>;
+    let user_repo = Arc::new(SqliteUserRepository::new(connection))
+        as Arc<dyn UserRepositoryTrait + Send + Sync>;
+
+    Ok((game_repo, user_repo))
+}
+
+/// Initialize PostgreSQL repositories
+#[cfg(feature = "database")]
+async fn init_postgres_repositories(
+    config: &Config,
+) -> anyhow::Result<(
+    Arc<dyn GameRepositoryTrait + Send + Sync>,
+    Arc<dyn UserRepositoryTrait + Send + Sync>,
+)> {
+    use crate::repository::database::GuessStorage;
     use crate::repository::{
         PostgresConfig, PostgresConnection, PostgresGameRepository, PostgresUserRepository,
     };
@@ -61,31 +252,55 @@ pub async fn init_repositories(
     tracing::info!("Using PostgreSQL database");
 
     // Initialize PostgreSQL connection
-    let db_config = PostgresConfig::new(&config.database.url);
+    let db_config = PostgresConfig::from_config(&config.database);
     let pool = db_config.create_pool().await?;
+
+    // Apply embedded migrations so the schema matches the repositories
+    tracing::info!("Running database migrations");
+    sqlx::migrate!("./migrations").run(&pool).await?;
     let connection = PostgresConnection::new(pool);
 
     // Create repositories
-    let game_repo = Arc::new(PostgresGameRepository::new(connection.clone()))
-        as Arc<dyn GameRepositoryTrait + Send + Sync>;
+    let game_repo = Arc::new(
+        PostgresGameRepository::new(connection.clone())
+            .with_storage(GuessStorage::from_config(&config.database)),
+    ) as Arc<dyn GameRepositoryTrait + Send + Sync>;
     let user_repo = Arc::new(PostgresUserRepository::new(connection))
         as Arc<dyn UserRepositoryTrait + Send + Sync>;
 
     Ok((game_repo, user_repo))
 }
 
-/// Initialize repositories (in-memory fallback when database feature is not enabled)
-#[cfg(not(feature = "database"))]
-pub async fn init_repositories(
-    _config: &Config,
+/// Initialize in-memory repositories, made durable by the WAL file if configured
+async fn init_memory_repositories(
+    config: &Config,
 ) -> anyhow::Result<(
     Arc<dyn GameRepositoryTrait + Send + Sync>,
     Arc<dyn UserRepositoryTrait + Send + Sync>,
 )> {
-    use crate::repository::memory::{InMemoryGameRepository, InMemoryUserRepository};
+    use crate::repository::memory::{
+        InMemoryGameRepository, InMemoryUserRepository, WriteAheadLog,
+    };
 
     tracing::info!("Using in-memory storage");
 
+    // Restore state from the write-ahead log when one is configured
+    if let Some(path) = &config.wal_file {
+        let (wal, state) = WriteAheadLog::open(path)
+            .map_err(|e| anyhow::anyhow!("Failed to open WAL file {}: {}", path.display(), e))?;
+        let wal = Arc::new(wal);
+
+        let game_repo = Arc::new(InMemoryGameRepository::with_wal(
+            wal.clone(),
+            state.games,
+            state.last_reset_date,
+        )) as Arc<dyn GameRepositoryTrait + Send + Sync>;
+        let user_repo = Arc::new(InMemoryUserRepository::with_wal(wal, state.users))
+            as Arc<dyn UserRepositoryTrait + Send + Sync>;
+
+        return Ok((game_repo, user_repo));
+    }
+
     let game_repo =
         Arc::new(InMemoryGameRepository::new()) as Arc<dyn GameRepositoryTrait + Send + Sync>;
     let user_repo =
@@ -0,0 +1,350 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use redis::AsyncCommands;
+use uuid::Uuid;
+
+use super::{RedisConnection, from_json, redis_error, to_json};
+use crate::common::config::GameConfig;
+use crate::game::models::{Game, GameSummary};
+use crate::repository::GameRepositoryTrait;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// Key the date of the last daily reset is stored under
+const LAST_RESET_DATE_KEY: &str = "daily_reset:last_date";
+
+/// Replace a stored game only if its version matches, returning 1 when saved,
+/// 0 on a version mismatch and -1 if the game is missing
+///
+/// A TTL of 0 stores the game without expiry.
+const SAVE_IF_VERSION_SCRIPT: &str = r#"
+local stored = redis.call('GET', KEYS[1])
+if not stored then
+    return -1
+end
+if (cjson.decode(stored)['version'] or 0) ~= tonumber(ARGV[2]) then
+    return 0
+end
+if tonumber(ARGV[3]) > 0 then
+    redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[3])
+else
+    redis.call('SET', KEYS[1], ARGV[1])
+end
+return 1
+"#;
+
+/// Redis implementation of game repository
+///
+/// Games expire at the next daily reset so stale games never outlive their day.
+pub struct RedisGameRepository {
+    /// Redis connection
+    conn: RedisConnection,
+
+    /// Timezone in which the daily reset happens
+    timezone: Tz,
+
+    /// Whether completed games are kept past the daily reset
+    preserve_completed: bool,
+}
+
+impl RedisGameRepository {
+    /// Create a new Redis game repository
+    pub fn new(conn: RedisConnection, config: &GameConfig) -> Self {
+        Self {
+            conn,
+            timezone: config.daily_reset_timezone,
+            preserve_completed: config.preserve_completed_on_reset,
+        }
+    }
+
+    /// Get the key a game is stored under
+    fn key(id: &Uuid) -> String {
+        format!("game:{}", id)
+    }
+
+    /// Get the key claiming a play of a user's daily game for a word
+    fn daily_claim_key(game: &Game) -> String {
+        if game.is_replay() {
+            format!(
+                "daily_game:{}:{}:{}",
+                game.user_id, game.word, game.replay_count
+            )
+        } else {
+            format!("daily_game:{}:{}", game.user_id, game.word)
+        }
+    }
+
+    /// Get the number of seconds until the next daily reset
+    fn seconds_until_reset(&self) -> u64 {
+        let now = Utc::now();
+        let tomorrow = now.with_timezone(&self.timezone).date_naive().succ_opt();
+
+        tomorrow
+            .and_then(|date| {
+                date.and_time(NaiveTime::MIN)
+                    .and_local_timezone(self.timezone)
+                    .earliest()
+            })
+            .map(|reset| (reset.with_timezone(&Utc) - now).num_seconds().max(1) as u64)
+            .unwrap_or(24 * 60 * 60)
+    }
+
+    /// Get every stored game
+    async fn all_games(&self) -> RepositoryResult<Vec<Game>> {
+        let keys = self.conn.scan_keys("game:*").await?;
+        self.conn.get_all(&keys).await
+    }
+
+    /// Delete the given games and return the number deleted
+    async fn delete_games(&self, games: &[Game]) -> RepositoryResult<usize> {
+        if games.is_empty() {
+            return Ok(0);
+        }
+
+        let keys: Vec<String> = games.iter().map(|game| Self::key(&game.id)).collect();
+        self.conn.manager().del(keys).await.map_err(redis_error)
+    }
+}
+
+#[async_trait]
+impl GameRepositoryTrait for RedisGameRepository {
+    /// Get a game by ID
+    async fn get_game(&self, id: &Uuid) -> RepositoryResult<Game> {
+        let value: Option<String> = self
+            .conn
+            .manager()
+            .get(Self::key(id))
+            .await
+            .map_err(redis_error)?;
+
+        from_json(&value.ok_or(RepositoryError::NotFound)?)
+    }
+
+    /// Save a game, expiring it at the next daily reset unless it is preserved
+    async fn save_game(&self, game: Game) -> RepositoryResult<()> {
+        let key = Self::key(&game.id);
+        let value = to_json(&game)?;
+        let mut conn = self.conn.manager();
+
+        if self.preserve_completed && game.completed {
+            conn.set::<_, _, ()>(key, value).await
+        } else {
+            conn.set_ex::<_, _, ()>(key, value, self.seconds_until_reset())
+                .await
+        }
+        .map_err(redis_error)
+    }
+
+    /// Save a game if its stored version matches, checked atomically by a script
+    async fn save_game_if_version(
+        &self,
+        game: Game,
+        expected_version: u64,
+    ) -> RepositoryResult<()> {
+        let ttl = if self.preserve_completed && game.completed {
+            0
+        } else {
+            self.seconds_until_reset()
+        };
+
+        let saved: i64 = redis::Script::new(SAVE_IF_VERSION_SCRIPT)
+            .key(Self::key(&game.id))
+            .arg(to_json(&game)?)
+            .arg(expected_version)
+            .arg(ttl)
+            .invoke_async(&mut self.conn.manager())
+            .await
+            .map_err(redis_error)?;
+
+        match saved {
+            1 => Ok(()),
+            0 => Err(RepositoryError::Conflict),
+            _ => Err(RepositoryError::NotFound),
+        }
+    }
+
+    /// Save a daily or archive game unless the user already has it
+    ///
+    /// The first request claims the user's game with `SET NX`; a daily claim
+    /// expires at the next daily reset, an archive claim lives as long as its
+    /// game, and either is replaced if its game was deleted.
+    async fn create_game_if_absent_for_user(&self, game: Game) -> RepositoryResult<Game> {
+        let (claim_key, ttl) = match game.archive_date {
+            Some(date) => (format!("archive_game:{}:{}", game.user_id, date), None),
+            None if game.is_daily() => (
+                Self::daily_claim_key(&game),
+                Some(self.seconds_until_reset()),
+            ),
+            None => {
+                self.save_game(game.clone()).await?;
+                return Ok(game);
+            }
+        };
+        let mut conn = self.conn.manager();
+
+        let mut options = redis::SetOptions::default().conditional_set(redis::ExistenceCheck::NX);
+        if let Some(ttl) = ttl {
+            options = options.with_expiration(redis::SetExpiry::EX(ttl));
+        }
+        let claimed: Option<String> = conn
+            .set_options(&claim_key, game.id.to_string(), options)
+            .await
+            .map_err(redis_error)?;
+
+        if claimed.is_none() {
+            let existing_id: Option<String> = conn.get(&claim_key).await.map_err(redis_error)?;
+            if let Some(id) = existing_id.and_then(|id| id.parse::<Uuid>().ok()) {
+                match self.get_game(&id).await {
+                    Ok(existing) => return Ok(existing),
+                    Err(RepositoryError::NotFound) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+
+            match ttl {
+                Some(ttl) => conn
+                    .set_ex::<_, _, ()>(&claim_key, game.id.to_string(), ttl)
+                    .await
+                    .map_err(redis_error)?,
+                None => conn
+                    .set::<_, _, ()>(&claim_key, game.id.to_string())
+                    .await
+                    .map_err(redis_error)?,
+            }
+        }
+
+        self.save_game(game.clone()).await?;
+        Ok(game)
+    }
+
+    /// Delete a game by ID
+    async fn delete_game(&self, id: &Uuid) -> RepositoryResult<()> {
+        let deleted: usize = self
+            .conn
+            .manager()
+            .del(Self::key(id))
+            .await
+            .map_err(redis_error)?;
+
+        if deleted == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    /// Delete all games owned by a user and return the count of deleted games
+    async fn delete_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<usize> {
+        let games = self.list_games_for_user(user_id).await?;
+        self.delete_games(&games).await
+    }
+
+    /// List all games owned by a user
+    async fn list_games_for_user(&self, user_id: &Uuid) -> RepositoryResult<Vec<Game>> {
+        let mut games = self.all_games().await?;
+        games.retain(|game| game.user_id == *user_id);
+        Ok(games)
+    }
+
+    /// List summaries of all games for a user
+    async fn list_game_summaries(&self, user_id: &Uuid) -> RepositoryResult<Vec<GameSummary>> {
+        let games = self.list_games_for_user(user_id).await?;
+        Ok(games.iter().map(GameSummary::from).collect())
+    }
+
+    /// List a page of all games ordered by creation time, with the total game count
+    async fn list_games_paginated(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> RepositoryResult<(Vec<Game>, usize)> {
+        let mut games = self.all_games().await?;
+        games.sort_by_key(|game| (game.created_at, game.id));
+
+        let total = games.len();
+        let page = games.into_iter().skip(offset).take(limit).collect();
+        Ok((page, total))
+    }
+
+    /// List all games created at or after the given time
+    async fn list_games_since(&self, since: DateTime<Utc>) -> RepositoryResult<Vec<Game>> {
+        let mut games = self.all_games().await?;
+        games.retain(|game| game.created_at >= since);
+        Ok(games)
+    }
+
+    /// List summaries of all games created at or after the given time
+    async fn list_game_summaries_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<GameSummary>> {
+        let games = self.list_games_since(since).await?;
+        Ok(games.iter().map(GameSummary::from).collect())
+    }
+
+    /// Clear all games and return the count of cleared games
+    async fn clear_all_games(&self) -> RepositoryResult<usize> {
+        let keys = self.conn.scan_keys("game:*").await?;
+        if keys.is_empty() {
+            return Ok(0);
+        }
+
+        self.conn.manager().del(keys).await.map_err(redis_error)
+    }
+
+    /// Clear all games and return the cleared games
+    async fn drain_all_games(&self) -> RepositoryResult<Vec<Game>> {
+        let games = self.all_games().await?;
+        self.delete_games(&games).await?;
+        Ok(games)
+    }
+
+    /// Clear games that are not completed and return the cleared games
+    async fn drain_incomplete_games(&self) -> RepositoryResult<Vec<Game>> {
+        let mut games = self.all_games().await?;
+        games.retain(|game| !game.completed);
+        self.delete_games(&games).await?;
+        Ok(games)
+    }
+
+    /// Remove games created before the cutoff and return the count of removed games
+    async fn remove_games_older_than(&self, cutoff: DateTime<Utc>) -> RepositoryResult<usize> {
+        let mut games = self.all_games().await?;
+        games.retain(|game| game.created_at < cutoff);
+        self.delete_games(&games).await
+    }
+
+    /// Get the date of the last daily reset, if one was recorded
+    async fn get_last_reset_date(&self) -> RepositoryResult<Option<NaiveDate>> {
+        let value: Option<String> = self
+            .conn
+            .manager()
+            .get(LAST_RESET_DATE_KEY)
+            .await
+            .map_err(redis_error)?;
+
+        value
+            .map(|value| {
+                value.parse().map_err(|e: chrono::ParseError| {
+                    RepositoryError::SerializationError(e.to_string())
+                })
+            })
+            .transpose()
+    }
+
+    /// Record the date of the last daily reset
+    async fn set_last_reset_date(&self, date: NaiveDate) -> RepositoryResult<()> {
+        self.conn
+            .manager()
+            .set::<_, _, ()>(LAST_RESET_DATE_KEY, date.to_string())
+            .await
+            .map_err(redis_error)
+    }
+
+    /// Check that Redis answers a ping
+    async fn health_check(&self) -> RepositoryResult<()> {
+        redis::cmd("PING")
+            .query_async::<()>(&mut self.conn.manager())
+            .await
+            .map_err(redis_error)
+    }
+}
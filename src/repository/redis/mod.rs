@@ -0,0 +1,86 @@
+/// Redis-backed repository implementations
+///
+/// Games and users are stored as JSON strings under `game:{id}` and `user:{id}`.
+pub mod game;
+pub mod user;
+
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, RedisError};
+use serde::de::DeserializeOwned;
+
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+pub use game::RedisGameRepository;
+pub use user::RedisUserRepository;
+
+/// Shared Redis connection
+#[derive(Clone)]
+pub struct RedisConnection {
+    /// Multiplexed connection that reconnects automatically
+    manager: ConnectionManager,
+}
+
+impl RedisConnection {
+    /// Connect to the Redis server at the given URL
+    pub async fn connect(url: &str) -> RepositoryResult<Self> {
+        let client = redis::Client::open(url)
+            .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+        let manager = client
+            .get_connection_manager()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+
+        Ok(Self { manager })
+    }
+
+    /// Get a handle to the connection
+    pub fn manager(&self) -> ConnectionManager {
+        self.manager.clone()
+    }
+
+    /// Get all keys matching a pattern using SCAN
+    pub async fn scan_keys(&self, pattern: &str) -> RepositoryResult<Vec<String>> {
+        let mut conn = self.manager();
+        let mut iter = conn
+            .scan_match::<_, String>(pattern)
+            .await
+            .map_err(redis_error)?;
+
+        let mut keys = Vec::new();
+        while let Some(key) = iter.next_item().await {
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    /// Get and deserialize the JSON values stored at the given keys
+    pub async fn get_all<T: DeserializeOwned>(&self, keys: &[String]) -> RepositoryResult<Vec<T>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let values: Vec<Option<String>> = self.manager().mget(keys).await.map_err(redis_error)?;
+
+        // Keys may expire between SCAN and MGET
+        values
+            .into_iter()
+            .flatten()
+            .map(|value| from_json(&value))
+            .collect()
+    }
+}
+
+/// Convert a Redis error to a repository error
+pub(crate) fn redis_error(e: RedisError) -> RepositoryError {
+    RepositoryError::DatabaseError(e.to_string())
+}
+
+/// Deserialize a stored JSON value
+pub(crate) fn from_json<T: DeserializeOwned>(value: &str) -> RepositoryResult<T> {
+    serde_json::from_str(value).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
+
+/// Serialize a value for storage
+pub(crate) fn to_json<T: serde::Serialize>(value: &T) -> RepositoryResult<String> {
+    serde_json::to_string(value).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
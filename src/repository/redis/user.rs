@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use redis::AsyncCommands;
+use uuid::Uuid;
+
+use super::{RedisConnection, from_json, redis_error, to_json};
+use crate::auth::models::User;
+use crate::repository::UserRepositoryTrait;
+use crate::repository::error::{RepositoryError, RepositoryResult};
+
+/// Redis implementation of user repository
+pub struct RedisUserRepository {
+    /// Redis connection
+    conn: RedisConnection,
+}
+
+impl RedisUserRepository {
+    /// Create a new Redis user repository
+    pub fn new(conn: RedisConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get the key a user is stored under
+    fn key(id: &Uuid) -> String {
+        format!("user:{}", id)
+    }
+
+    /// Get the key counting the games a user created today
+    fn daily_creates_key(id: &Uuid) -> String {
+        format!("daily_creates:{}", id)
+    }
+
+    /// Get a user if stored
+    async fn find_user(&self, id: &Uuid) -> RepositoryResult<Option<User>> {
+        let value: Option<String> = self
+            .conn
+            .manager()
+            .get(Self::key(id))
+            .await
+            .map_err(redis_error)?;
+
+        value.map(|value| from_json(&value)).transpose()
+    }
+
+    /// Set a user's current game ID for a word length, returning whether the user exists
+    async fn set_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Option<Uuid>,
+    ) -> RepositoryResult<bool> {
+        match self.find_user(user_id).await? {
+            Some(mut user) => {
+                match game_id {
+                    Some(game_id) => user.current_game_ids.insert(word_length, game_id),
+                    None => user.current_game_ids.remove(&word_length),
+                };
+                user.updated_at = Utc::now();
+                self.save_user(user).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepositoryTrait for RedisUserRepository {
+    /// Get a user by ID
+    async fn get_user(&self, id: &Uuid) -> RepositoryResult<User> {
+        self.find_user(id).await?.ok_or(RepositoryError::NotFound)
+    }
+
+    /// Save a user
+    async fn save_user(&self, user: User) -> RepositoryResult<()> {
+        self.conn
+            .manager()
+            .set::<_, _, ()>(Self::key(&user.id), to_json(&user)?)
+            .await
+            .map_err(redis_error)
+    }
+
+    /// Delete a user, returning whether the user existed
+    async fn delete_user(&self, id: &Uuid) -> RepositoryResult<bool> {
+        let deleted: usize = self
+            .conn
+            .manager()
+            .del(Self::key(id))
+            .await
+            .map_err(redis_error)?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Update a user's current game ID for a word length
+    async fn update_user_game(
+        &self,
+        user_id: &Uuid,
+        word_length: usize,
+        game_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.set_user_game(user_id, word_length, Some(game_id))
+            .await
+    }
+
+    /// Clear a user's current game ID for a word length
+    async fn clear_user_game(&self, user_id: &Uuid, word_length: usize) -> RepositoryResult<bool> {
+        self.set_user_game(user_id, word_length, None).await
+    }
+
+    /// Reset all users' current game IDs and return the count of updated users
+    async fn reset_all_users_current_game(&self) -> RepositoryResult<usize> {
+        let keys = self.conn.scan_keys("user:*").await?;
+        let users: Vec<User> = self.conn.get_all(&keys).await?;
+        let updated_count = users.len();
+
+        for mut user in users {
+            user.current_game_ids.clear();
+            user.updated_at = Utc::now();
+            self.save_user(user).await?;
+        }
+
+        Ok(updated_count)
+    }
+
+    /// Count a game created by a user today, returning the user's count so far
+    async fn increment_and_get_daily_creates(&self, user_id: &Uuid) -> RepositoryResult<u32> {
+        self.conn
+            .manager()
+            .incr(Self::daily_creates_key(user_id), 1)
+            .await
+            .map_err(redis_error)
+    }
+
+    /// Reset every user's count of games created today
+    async fn reset_daily_creates(&self) -> RepositoryResult<()> {
+        let keys = self.conn.scan_keys("daily_creates:*").await?;
+        if !keys.is_empty() {
+            self.conn
+                .manager()
+                .del::<_, ()>(keys)
+                .await
+                .map_err(redis_error)?;
+        }
+
+        Ok(())
+    }
+}
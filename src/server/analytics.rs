@@ -0,0 +1,110 @@
+//! Structured analytics events, logged under their own tracing target
+//!
+//! Operators route or filter these events with the `wordle::analytics`
+//! target, e.g. `RUST_LOG=wordle::analytics=info`.
+
+use async_trait::async_trait;
+
+use crate::game::events::{GameAbandonedEvent, GameCompletedEvent, GameEventSink};
+
+/// Tracing target analytics events are logged under
+pub const ANALYTICS_TARGET: &str = "wordle::analytics";
+
+/// Game event sink logging each completed or abandoned game for analytics
+pub struct AnalyticsSink;
+
+#[async_trait]
+impl GameEventSink for AnalyticsSink {
+    async fn on_game_completed(&self, event: &GameCompletedEvent) {
+        tracing::info!(
+            target: ANALYTICS_TARGET,
+            user_id = %event.user_id,
+            won = event.won,
+            attempts = event.guess_count,
+            word_length = event.word_length,
+            hard_mode = event.hard_mode,
+            "Game completed"
+        );
+    }
+
+    async fn on_game_abandoned(&self, event: &GameAbandonedEvent) {
+        tracing::info!(
+            target: ANALYTICS_TARGET,
+            user_id = %event.user_id,
+            attempts = event.guess_count,
+            word_length = event.word_length,
+            practice = event.practice,
+            "Game abandoned"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::{Layer, Registry};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::api::AppState;
+    use crate::auth::AuthState;
+    use crate::game::GameState;
+    use crate::repository::memory::{InMemoryGameRepository, InMemoryUserRepository};
+    use crate::test_support::{self, played_game, send};
+
+    /// Layer counting the events logged under the analytics target
+    struct CountAnalytics(Arc<AtomicUsize>);
+
+    impl<S: tracing::Subscriber> Layer<S> for CountAnalytics {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            if event.metadata().target() == ANALYTICS_TARGET {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn completion_is_logged_once() {
+        let events = Arc::new(AtomicUsize::new(0));
+        let subscriber = Registry::default().with(CountAnalytics(events.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let config = test_support::config();
+        let game = GameState::new(Arc::new(InMemoryGameRepository::new()), &config.game)
+            .unwrap()
+            .with_event_sink(Arc::new(AnalyticsSink));
+        let auth = AuthState::new(Arc::new(InMemoryUserRepository::new()), &config.jwt).unwrap();
+        let state = AppState::new(Arc::new(game), Arc::new(auth), Arc::new(config));
+        let router = test_support::router(&state);
+
+        let user_id = Uuid::new_v4();
+        let token = test_support::token(&test_support::claims(user_id));
+        let game = played_game(user_id, "plant", &["house"]);
+        let uri = format!("/api/v1/game/{}", game.id);
+        let guess_uri = format!("{}/guess", uri);
+        state.game.save_game(game).await.unwrap();
+
+        let guess = |word: &str| {
+            send(
+                &router,
+                Method::POST,
+                &guess_uri,
+                Some(&token),
+                Some(json!({ "word": word })),
+            )
+        };
+        assert_eq!(guess("plant").await.0, StatusCode::OK);
+        for _ in 0..2 {
+            let (status, _) = send(&router, Method::GET, &uri, Some(&token), None).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        assert_ne!(guess("plate").await.0, StatusCode::OK);
+
+        assert_eq!(events.load(Ordering::SeqCst), 1);
+    }
+}
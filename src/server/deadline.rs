@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tokio::time::Instant;
+
+use crate::repository::deadline;
+
+/// Middleware giving each request a deadline that repository calls observe
+pub async fn enforce_deadline(
+    State(timeout): State<Duration>,
+    request: Request,
+    next: Next,
+) -> Response {
+    deadline::scope(Instant::now() + timeout, next.run(request)).await
+}
@@ -0,0 +1,174 @@
+//! Prometheus metrics, recorded only when the `metrics` feature is enabled
+//!
+//! The recording functions are no-ops without the feature so call sites
+//! don't need to be feature-gated.
+
+use async_trait::async_trait;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+use crate::game::events::{GameCompletedEvent, GameEventSink};
+use crate::repository::PoolStats;
+
+#[cfg(feature = "metrics")]
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+#[cfg(feature = "metrics")]
+use once_cell::sync::OnceCell;
+
+/// Histogram buckets for request latency in seconds
+#[cfg(feature = "metrics")]
+const LATENCY_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5];
+
+/// Handle to the installed Prometheus recorder
+#[cfg(feature = "metrics")]
+static HANDLE: OnceCell<PrometheusHandle> = OnceCell::new();
+
+/// Install the global Prometheus recorder
+///
+/// Must be called from within a Tokio runtime. Calling it again is a no-op.
+#[cfg(feature = "metrics")]
+pub fn install() -> anyhow::Result<()> {
+    if HANDLE.get().is_some() {
+        return Ok(());
+    }
+
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full("wordle_request_duration_seconds".to_string()),
+            LATENCY_BUCKETS,
+        )?
+        .install_recorder()?;
+
+    // Histograms need periodic upkeep to drain their buffers
+    let upkeep = handle.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            upkeep.run_upkeep();
+        }
+    });
+
+    let _ = HANDLE.set(handle);
+    tracing::info!("Prometheus metrics enabled at /api/metrics");
+    Ok(())
+}
+
+/// Render all metrics in the Prometheus text exposition format
+#[cfg(feature = "metrics")]
+pub fn render() -> Option<String> {
+    HANDLE.get().map(PrometheusHandle::render)
+}
+
+/// Record that a game was created
+pub fn record_game_created() {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("wordle_games_created_total").increment(1);
+}
+
+/// Record that a guess was accepted
+pub fn record_guess() {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("wordle_guesses_total").increment(1);
+}
+
+/// Record the current size and idle count of the database connection pool
+pub fn record_pool_stats(stats: &PoolStats) {
+    #[cfg(feature = "metrics")]
+    {
+        metrics::gauge!("wordle_db_pool_connections").set(f64::from(stats.size));
+        metrics::gauge!("wordle_db_pool_idle_connections").set(stats.idle as f64);
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    let _ = stats;
+}
+
+/// Event sink counting completed games
+pub struct MetricsSink;
+
+#[async_trait]
+impl GameEventSink for MetricsSink {
+    async fn on_game_completed(&self, event: &GameCompletedEvent) {
+        #[cfg(feature = "metrics")]
+        if event.won {
+            metrics::counter!("wordle_games_won_total").increment(1);
+        }
+
+        #[cfg(not(feature = "metrics"))]
+        let _ = event;
+    }
+}
+
+/// Middleware recording the latency of each request
+pub async fn track_latency(request: Request, next: Next) -> Response {
+    #[cfg(feature = "metrics")]
+    {
+        let method = request.method().to_string();
+        let path = request
+            .extensions()
+            .get::<axum::extract::MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_else(|| "unmatched".to_string());
+        let start = std::time::Instant::now();
+
+        let response = next.run(request).await;
+
+        metrics::histogram!(
+            "wordle_request_duration_seconds",
+            "method" => method,
+            "path" => path,
+            "status" => response.status().as_u16().to_string(),
+        )
+        .record(start.elapsed().as_secs_f64());
+
+        response
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    next.run(request).await
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::test_support::{self, played_game, send};
+
+    /// Current value of a counter, zero if it was never incremented
+    fn counter(name: &str) -> u64 {
+        render()
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(' ')?.parse().ok())
+            .unwrap_or(0)
+    }
+
+    #[tokio::test]
+    async fn guesses_increment_the_guess_counter() {
+        install().unwrap();
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let user_id = Uuid::new_v4();
+        let game = played_game(user_id, "plant", &[]);
+        let uri = format!("/api/v1/game/{}/guess", game.id);
+        state.game.save_game(game).await.unwrap();
+
+        let before = counter("wordle_guesses_total");
+        let token = test_support::token(&test_support::claims(user_id));
+        let (status, _) = send(
+            &router,
+            Method::POST,
+            &uri,
+            Some(&token),
+            Some(json!({ "word": "house" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Other tests may record guesses concurrently, so only a lower bound holds
+        assert!(counter("wordle_guesses_total") > before);
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use axum::{
+    Json,
+    extract::{ConnectInfo, Request},
+    http::{HeaderName, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use parking_lot::Mutex;
+use serde_json::json;
+use tower::{Layer, Service};
+use tower_http::cors::{AllowHeaders, AllowMethods, Any, CorsLayer};
+
+pub mod analytics;
+pub mod deadline;
+pub mod metrics;
+pub mod request_id;
+pub mod reset;
+pub mod subscribers;
+
+/// Length of a rate limiting window
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Number of tracked clients above which expired windows are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Header flagging responses from deprecated routes
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+
+/// Fixed-window request counter keyed by client IP, or any other client key
+#[derive(Debug)]
+pub struct RateLimiter<K = IpAddr> {
+    /// Maximum requests per client per window
+    limit: u32,
+
+    /// Start of each client's current window and its request count
+    clients: Mutex<HashMap<K, (Instant, u32)>>,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    /// Create a limiter allowing `limit` requests per client per minute
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get how long a client must wait before its next request is allowed
+    ///
+    /// Returns `None` if the client is under its limit. Nothing is recorded.
+    pub fn retry_after(&self, key: &K) -> Option<Duration> {
+        let clients = self.clients.lock();
+        let (start, count) = clients.get(key)?;
+        let elapsed = start.elapsed();
+
+        (*count >= self.limit && elapsed < RATE_LIMIT_WINDOW).then(|| RATE_LIMIT_WINDOW - elapsed)
+    }
+
+    /// Record a request from a client, returning whether it is allowed
+    pub fn check(&self, key: K) -> bool {
+        let now = Instant::now();
+        let mut clients = self.clients.lock();
+
+        if clients.len() >= PRUNE_THRESHOLD {
+            clients.retain(|_, (start, _)| now.duration_since(*start) < RATE_LIMIT_WINDOW);
+        }
+
+        let (start, count) = clients.entry(key).or_insert((now, 0));
+        if now.duration_since(*start) >= RATE_LIMIT_WINDOW {
+            *start = now;
+            *count = 0;
+        }
+
+        if *count >= self.limit {
+            return false;
+        }
+
+        *count += 1;
+        true
+    }
+}
+
+/// Layer applying per-IP rate limiting to a service
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    /// Create a layer allowing `limit` requests per client per minute
+    pub fn per_minute(limit: u32) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(limit)),
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Service rejecting requests with 429 once a client exceeds its limit
+#[derive(Debug, Clone)]
+pub struct RateLimit<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<Request> for RateLimit<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // Requests without connection info share a single bucket
+        let ip = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip())
+            .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+        if !self.limiter.check(ip) {
+            tracing::warn!("Rate limit exceeded for {}", ip);
+            return Box::pin(async { Ok(too_many_requests()) });
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
+
+/// Build the CORS layer, restricting credentialed requests to `allowed_origins`
+///
+/// Any origin is allowed without credentials when the list is empty.
+pub fn cors_layer(allowed_origins: &[String]) -> CorsLayer {
+    if allowed_origins.is_empty() {
+        return CorsLayer::new()
+            .allow_origin(Any)
+            .allow_methods(Any)
+            .allow_headers(Any)
+            .expose_headers([request_id::X_REQUEST_ID]);
+    }
+
+    // Wildcards can't be combined with credentials, so mirror the request instead
+    let origins: Vec<HeaderValue> = allowed_origins
+        .iter()
+        .filter_map(|origin| HeaderValue::from_str(origin).ok())
+        .collect();
+    CorsLayer::new()
+        .allow_origin(origins)
+        .allow_methods(AllowMethods::mirror_request())
+        .allow_headers(AllowHeaders::mirror_request())
+        .expose_headers([request_id::X_REQUEST_ID])
+        .allow_credentials(true)
+}
+
+/// Replace the plain-text body of a `413 Payload Too Large` response with a JSON error
+pub async fn payload_too_large(response: Response) -> Response {
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE {
+        return response;
+    }
+
+    (
+        StatusCode::PAYLOAD_TOO_LARGE,
+        Json(json!({ "error": "payload too large" })),
+    )
+        .into_response()
+}
+
+/// Mark a response as coming from a deprecated route
+pub async fn mark_deprecated(mut response: Response) -> Response {
+    response
+        .headers_mut()
+        .insert(DEPRECATION, HeaderValue::from_static("true"));
+    response
+}
+
+/// Build the response for a rate limited request
+fn too_many_requests() -> Response {
+    let mut response = (
+        StatusCode::TOO_MANY_REQUESTS,
+        Json(json!({ "error": "Too many requests, try again later" })),
+    )
+        .into_response();
+    response.headers_mut().insert(
+        header::RETRY_AFTER,
+        HeaderValue::from(RATE_LIMIT_WINDOW.as_secs()),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::post;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn guess_from(router: &Router, ip: [u8; 4]) -> StatusCode {
+        let mut request = Request::post("/guess").body(Body::empty()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from((ip, 4000))));
+
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn guesses_beyond_the_limit_are_refused() {
+        let router = Router::new().route(
+            "/guess",
+            post(|| async { "ok" }).layer(RateLimitLayer::per_minute(5)),
+        );
+
+        for _ in 0..5 {
+            assert_eq!(guess_from(&router, [10, 0, 0, 1]).await, StatusCode::OK);
+        }
+        assert_eq!(
+            guess_from(&router, [10, 0, 0, 1]).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // Other clients keep their own allowance
+        assert_eq!(guess_from(&router, [10, 0, 0, 2]).await, StatusCode::OK);
+    }
+}
@@ -0,0 +1,111 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Header carrying the ID of a request
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request ID that is reused rather than replaced
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    /// ID of the request being handled on the current task
+    static REQUEST_ID: String;
+}
+
+/// ID of a request, stored in its extensions
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+/// Get the ID of the request being handled on the current task, if any
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Add the current request's ID to a JSON error body
+pub fn attach(body: &mut Value) {
+    if let (Some(id), Value::Object(fields)) = (current(), body) {
+        fields.insert("request_id".to_string(), Value::String(id));
+    }
+}
+
+/// Middleware giving each request an ID, echoed in the `X-Request-Id` header
+///
+/// A client-supplied ID is kept if it is short printable ASCII, otherwise a
+/// new one is generated. Logs emitted while handling the request carry the ID.
+pub async fn propagate_request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(&X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    request.extensions_mut().insert(RequestId(id.clone()));
+
+    let span = tracing::info_span!("request", request_id = %id);
+    let mut response = REQUEST_ID
+        .scope(id.clone(), next.run(request))
+        .instrument(span)
+        .await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+    response
+}
+
+/// Check that a client-supplied request ID is safe to reuse
+fn is_valid(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{StatusCode, header};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn missing_games_report_the_request_id() {
+        let state = test_support::app_state(test_support::config());
+        let router = test_support::router(&state);
+        let token = test_support::token(&test_support::claims(Uuid::new_v4()));
+
+        for supplied in [Some("client-request-1"), None] {
+            let mut request = Request::get(format!("/api/v1/game/{}", Uuid::new_v4()))
+                .header(header::AUTHORIZATION, format!("Bearer {}", token));
+            if let Some(id) = supplied {
+                request = request.header(&X_REQUEST_ID, id);
+            }
+            let response = router
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+            let echoed = response.headers()[&X_REQUEST_ID]
+                .to_str()
+                .unwrap()
+                .to_string();
+            if let Some(id) = supplied {
+                assert_eq!(echoed, id);
+            }
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["request_id"], echoed);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+
+use crate::api::AppState;
+
+/// Shortest wait between reset checks, guarding against clock skew around midnight
+const MIN_RESET_WAIT: Duration = Duration::from_secs(1);
+
+/// Spawn a task that selects the daily word and resets games at each puzzle boundary
+///
+/// The first check runs immediately, catching up on a reset missed while the
+/// server was down. Requests still check the date themselves, so a missed
+/// wake-up only delays the reset until the next request. Abort the returned
+/// handle to stop the task.
+pub fn spawn_daily_reset(state: AppState) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = state.check_and_update_date().await {
+                tracing::error!("Scheduled daily reset failed: {}", e);
+            }
+
+            // Bound storage by dropping games past the retention period
+            match state.game.remove_expired_games().await {
+                Ok(0) => {}
+                Ok(removed) => tracing::info!("Removed {} expired games", removed),
+                Err(e) => tracing::error!("Failed to remove expired games: {}", e),
+            }
+
+            let service = state.game.game_service();
+            let (today, index) = service.current_puzzle();
+
+            // Warm the daily word cache so the first request of the puzzle doesn't pay for it
+            let word = service.select_daily_word();
+            tracing::debug!(
+                "Daily word for {} puzzle {} selected: {}",
+                today,
+                index,
+                word
+            );
+
+            // Sleep until the next puzzle starts in the daily reset timezone
+            let next_reset = service.puzzle_start(today, index + 1);
+            let wait = (next_reset - Utc::now())
+                .to_std()
+                .unwrap_or_default()
+                .max(MIN_RESET_WAIT);
+            tokio::time::sleep(wait).await;
+        }
+    })
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Server-wide cap on live update subscribers across all games
+#[derive(Debug)]
+pub struct SubscriberLimit {
+    /// Maximum number of concurrent subscribers (0 disables the cap)
+    max: usize,
+
+    /// Number of subscribers currently connected
+    active: AtomicUsize,
+}
+
+impl SubscriberLimit {
+    /// Create a limit allowing `max` concurrent subscribers (0 disables the cap)
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reserve a subscriber slot, returning `None` once the cap is reached
+    ///
+    /// The slot is released when the returned guard is dropped.
+    pub fn try_acquire(self: &Arc<Self>) -> Option<SubscriberGuard> {
+        self.active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |active| {
+                (self.max == 0 || active < self.max).then_some(active + 1)
+            })
+            .ok()?;
+
+        Some(SubscriberGuard {
+            limit: self.clone(),
+        })
+    }
+
+    /// Number of subscribers currently connected
+    pub fn active(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+}
+
+/// Subscriber slot held for the lifetime of a live connection
+#[derive(Debug)]
+pub struct SubscriberGuard {
+    limit: Arc<SubscriberLimit>,
+}
+
+impl Drop for SubscriberGuard {
+    fn drop(&mut self) {
+        self.limit.active.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribers_beyond_the_cap_are_refused_until_one_leaves() {
+        let limit = Arc::new(SubscriberLimit::new(2));
+
+        // Subscribers to different games draw on the same slots
+        let first_game = limit.try_acquire().unwrap();
+        let _second_game = limit.try_acquire().unwrap();
+        assert!(limit.try_acquire().is_none());
+        assert_eq!(limit.active(), 2);
+
+        drop(first_game);
+        assert_eq!(limit.active(), 1);
+        assert!(limit.try_acquire().is_some());
+    }
+
+    #[test]
+    fn zero_disables_the_cap() {
+        let limit = Arc::new(SubscriberLimit::new(0));
+        let guards: Vec<_> = (0..100).map(|_| limit.try_acquire().unwrap()).collect();
+        assert_eq!(limit.active(), guards.len());
+    }
+}
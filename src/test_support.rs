@@ -0,0 +1,190 @@
+//! Fixtures shared by the unit tests
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode, header};
+use chrono_tz::Tz;
+use jsonwebtoken::{EncodingKey, Header, encode};
+use serde_json::Value;
+use time::OffsetDateTime;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::api::{self, AppState};
+use crate::auth::AuthState;
+use crate::auth::models::Claims;
+use crate::common::config::{
+    Config, DatabaseConfig, GameConfig, JwtConfig, RedisConfig, ScoringConfig, TlsConfig,
+    TlsVersion,
+};
+use crate::game::models::Game;
+use crate::game::{DEFAULT_WORD_LENGTH, GameService, GameState, MAX_ATTEMPTS};
+use crate::repository::memory::{InMemoryGameRepository, InMemoryUserRepository};
+
+/// HS256 secret the test configuration signs and verifies tokens with
+pub const TEST_SECRET: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Configuration with the defaults `Config::load` uses for an empty environment
+pub fn config() -> Config {
+    Config {
+        port: 0,
+        guess_rate_limit: 0,
+        request_timeout_secs: 10,
+        max_live_subscribers: 1000,
+        cors_allowed_origins: Vec::new(),
+        legacy_api_routes: true,
+        analytics_enabled: false,
+        jwt: JwtConfig {
+            auth_type: "secret".to_string(),
+            public_key: TEST_SECRET.to_string(),
+            issuer: "wordle".to_string(),
+            audience: "users".to_string(),
+            allow_local_issuance: false,
+            signing_key: None,
+            max_header_bytes: 8192,
+            debug_errors: false,
+            read_grace_secs: 0,
+            reject_future_iat: true,
+            leeway_secs: 30,
+            jwks_url: None,
+        },
+        tls: TlsConfig {
+            enabled: false,
+            cert_file: PathBuf::from("keys/cert.pem"),
+            key_file: PathBuf::from("keys/key.pem"),
+            min_version: TlsVersion::Tls12,
+        },
+        database: DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            compress_guesses: false,
+            max_guesses_bytes: 1024 * 1024,
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout_secs: 30,
+            idle_timeout_secs: 600,
+        },
+        redis: RedisConfig { url: None },
+        wal_file: None,
+        game: game_config(),
+    }
+}
+
+/// Game configuration with the defaults `Config::load` uses
+pub fn game_config() -> GameConfig {
+    GameConfig {
+        word_length: DEFAULT_WORD_LENGTH,
+        max_attempts: MAX_ATTEMPTS,
+        practice_mode: true,
+        preserve_completed_on_reset: false,
+        allow_daily_replay: false,
+        reveal_answer_on_complete: true,
+        max_daily_games_per_user: 50,
+        game_retention_days: 0,
+        word_list_file: None,
+        require_word_file: false,
+        language_word_lists: Vec::new(),
+        result_signing_key: None,
+        daily_reset_timezone: Tz::UTC,
+        leaderboard_anonymize: false,
+        live_stats: false,
+        min_guess_interval_ms: 500,
+        puzzles_per_day: 1,
+        scoring: ScoringConfig::default(),
+    }
+}
+
+/// Route state over empty in-memory repositories
+pub fn app_state(config: Config) -> AppState {
+    let game = GameState::new(Arc::new(InMemoryGameRepository::new()), &config.game)
+        .expect("test game configuration is valid");
+    let auth = AuthState::new(Arc::new(InMemoryUserRepository::new()), &config.jwt)
+        .expect("test JWT configuration is valid");
+
+    AppState::new(Arc::new(game), Arc::new(auth), Arc::new(config))
+}
+
+/// Daily game for `word` with `guesses` already made by the default game service
+pub fn played_game(user_id: Uuid, word: &str, guesses: &[&str]) -> Game {
+    let service = GameService::default();
+    let mut game = Game::new(word.to_string(), MAX_ATTEMPTS, user_id);
+    for guess in guesses {
+        service
+            .make_guess(&mut game, guess)
+            .expect("test guesses are valid");
+    }
+    game
+}
+
+/// API router over an app state's game, auth and configuration
+pub fn router(state: &AppState) -> Router {
+    api::router(state.game.clone(), state.auth.clone(), state.config.clone())
+}
+
+/// Claims for a user, valid for an hour from now
+pub fn claims(user_id: Uuid) -> Claims {
+    let now = OffsetDateTime::now_utc().unix_timestamp() as usize;
+
+    Claims {
+        sub: user_id.to_string(),
+        username: "tester".to_string(),
+        iat: now,
+        exp: now + 3600,
+        nbf: None,
+        iss: Some("wordle".to_string()),
+        aud: Some(vec!["users".to_string()]),
+        roles: Some(vec!["user".to_string()]),
+        email: None,
+        name: None,
+    }
+}
+
+/// Sign claims with the test secret
+pub fn token(claims: &Claims) -> String {
+    encode(
+        &Header::default(),
+        claims,
+        &EncodingKey::from_secret(TEST_SECRET.as_bytes()),
+    )
+    .expect("claims serialize")
+}
+
+/// Send a request through a router, returning the status and JSON body
+///
+/// An empty or non-JSON body is returned as `Value::Null`.
+pub async fn send(
+    router: &Router,
+    method: Method,
+    uri: &str,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let request = match body {
+        Some(body) => request
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .expect("request is valid");
+
+    let response = router
+        .clone()
+        .oneshot(request)
+        .await
+        .expect("router is infallible");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body is readable");
+
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}